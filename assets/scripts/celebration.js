@@ -0,0 +1,67 @@
+// Lightweight canvas confetti for the round-end celebration.
+// Expects a <canvas id="celebration-canvas"> in the page and a `style`
+// of either "civilians" or "imposter".
+(function (style) {
+    var canvas = document.getElementById("celebration-canvas");
+    if (!canvas || !canvas.getContext) return;
+    if (window.matchMedia && window.matchMedia("(prefers-reduced-motion: reduce)").matches) return;
+
+    var ctx = canvas.getContext("2d");
+    var width = canvas.width = window.innerWidth;
+    var height = canvas.height = window.innerHeight;
+
+    // Keep particle counts low so low-end phones stay smooth
+    var count = width < 600 ? 60 : 110;
+    var palettes = {
+        civilians: ["#10b981", "#34d399", "#667eea", "#a7f3d0", "#fde68a"],
+        imposter: ["#ef4444", "#f5576c", "#764ba2", "#1f2937", "#fa709a"]
+    };
+    var colors = palettes[style] || palettes.civilians;
+    var particles = [];
+    for (var i = 0; i < count; i++) {
+        particles.push({
+            // Civilians: confetti rains from the top. Imposter: a burst from the centre.
+            x: style === "imposter" ? width / 2 : Math.random() * width,
+            y: style === "imposter" ? height / 2 : -20 - Math.random() * height * 0.5,
+            vx: style === "imposter" ? (Math.random() - 0.5) * 12 : (Math.random() - 0.5) * 2,
+            vy: style === "imposter" ? (Math.random() - 0.7) * 12 : 2 + Math.random() * 3,
+            size: 4 + Math.random() * 6,
+            spin: Math.random() * Math.PI,
+            color: colors[i % colors.length]
+        });
+    }
+
+    var start = null;
+    var duration = 3500;
+    function frame(ts) {
+        if (start === null) start = ts;
+        var elapsed = ts - start;
+        ctx.clearRect(0, 0, width, height);
+        ctx.globalAlpha = Math.max(0, 1 - elapsed / duration);
+        for (var i = 0; i < particles.length; i++) {
+            var p = particles[i];
+            p.x += p.vx;
+            p.y += p.vy;
+            p.vy += style === "imposter" ? 0.25 : 0.05;
+            p.spin += 0.1;
+            ctx.save();
+            ctx.translate(p.x, p.y);
+            ctx.rotate(p.spin);
+            ctx.fillStyle = p.color;
+            if (style === "imposter") {
+                ctx.beginPath();
+                ctx.arc(0, 0, p.size / 2, 0, Math.PI * 2);
+                ctx.fill();
+            } else {
+                ctx.fillRect(-p.size / 2, -p.size / 4, p.size, p.size / 2);
+            }
+            ctx.restore();
+        }
+        if (elapsed < duration && document.body.contains(canvas)) {
+            window.requestAnimationFrame(frame);
+        } else {
+            ctx.clearRect(0, 0, width, height);
+        }
+    }
+    window.requestAnimationFrame(frame);
+})
@@ -0,0 +1,204 @@
+//! Play rounds headlessly with the game's own rules and print how often each side wins.
+//!
+//! ```text
+//! cargo run --bin simulate -- --players 3-10 --rounds 1000 --seed 7 --accuracy 0.4 --guess 0.25
+//! ```
+//!
+//! The table is modelled as a single vote per eviction: with `--accuracy` set, the group
+//! evicts the imposter with that chance and otherwise a random civilian; without it, every
+//! active player is equally likely to go. `--guess` turns on the comeback rule, and an
+//! evicted imposter names the civilian word with that chance. Everything else (dealing,
+//! scoring, the imposter win threshold) is the app's code, so the numbers move when the
+//! rules or defaults do.
+
+use std::ops::RangeInclusive;
+use std::process::ExitCode;
+
+use ultimate_imposter::views::game::game_engine::{deal_round, resolve_eviction, resolve_imposter_guess, EvictionOutcome};
+use ultimate_imposter::views::game::utils::{
+    get_random_starting_index, player_color, random_category_index, random_u64, seed_random, MAX_PLAYERS, MIN_PLAYERS,
+};
+use ultimate_imposter::views::game::{GameScreen, GameState, Player, Settings};
+
+const USAGE: &str = "usage: simulate [--players N | N-M] [--rounds N] [--seed N] [--accuracy 0..1] [--guess 0..1]";
+
+/// What to simulate, from the command line
+#[derive(Debug, PartialEq)]
+struct Options {
+    players: RangeInclusive<usize>,
+    rounds: usize,
+    seed: Option<u64>,
+    accuracy: Option<f64>, // Chance a vote finds the imposter; None votes out anyone at random
+    guess: Option<f64>, // Chance an evicted imposter names the word; None plays without the comeback rule
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { players: MIN_PLAYERS..=10, rounds: 1000, seed: None, accuracy: None, guess: None }
+    }
+}
+
+/// Parse `--flag value` pairs; anything unknown or out of range is an error
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter.next().ok_or_else(|| format!("{flag} needs a value"))?;
+        let bad = || format!("bad value for {flag}: {value}");
+        match flag.as_str() {
+            "--players" => options.players = parse_players(value).ok_or_else(bad)?,
+            "--rounds" => options.rounds = value.parse().ok().filter(|&n| n > 0).ok_or_else(bad)?,
+            "--seed" => options.seed = Some(value.parse().map_err(|_| bad())?),
+            "--accuracy" => options.accuracy = Some(parse_chance(value).ok_or_else(bad)?),
+            "--guess" => options.guess = Some(parse_chance(value).ok_or_else(bad)?),
+            _ => return Err(format!("unknown option {flag}")),
+        }
+    }
+    Ok(options)
+}
+
+/// A probability from 0 to 1
+fn parse_chance(value: &str) -> Option<f64> {
+    value.parse().ok().filter(|p| (0.0..=1.0).contains(p))
+}
+
+/// Whether something with chance `p` happens this time
+fn chance(p: f64) -> bool {
+    (random_u64() as f64 / u64::MAX as f64) < p
+}
+
+/// A player count (`5`) or an inclusive range (`3-10`) within the supported sizes
+fn parse_players(value: &str) -> Option<RangeInclusive<usize>> {
+    let (low, high) = match value.split_once('-') {
+        Some((low, high)) => (low.trim().parse().ok()?, high.trim().parse().ok()?),
+        None => {
+            let count = value.trim().parse().ok()?;
+            (count, count)
+        }
+    };
+    let supported = MIN_PLAYERS..=MAX_PLAYERS;
+    (low <= high && supported.contains(&low) && supported.contains(&high)).then_some(low..=high)
+}
+
+/// Outcomes for one player count
+#[derive(Default)]
+struct Tally {
+    civilian_wins: usize,
+    imposter_wins: usize,
+    votes: usize,
+}
+
+/// Play one round to the end and record who won
+fn play_round(player_count: usize, accuracy: Option<f64>, guess: Option<f64>, tally: &mut Tally) {
+    let mut state = GameState {
+        players: (0..player_count)
+            .map(|i| Player { name: format!("P{}", i + 1), score: 0, is_eliminated: false, color: player_color(i), last_round_delta: 0 })
+            .collect(),
+        selected_category_index: Some(random_category_index(None)),
+        round_number: 1,
+        settings: Settings { comeback_enabled: guess.is_some(), ..Settings::default() },
+        ..GameState::default()
+    };
+    deal_round(&mut state);
+
+    loop {
+        tally.votes += 1;
+        let active: Vec<usize> = (0..player_count).filter(|&i| !state.players[i].is_eliminated).collect();
+        let civilians: Vec<usize> = active.iter().copied().filter(|&i| i != state.imposter_index).collect();
+        let evicted = match accuracy {
+            Some(p) if chance(p) => state.imposter_index,
+            Some(_) => civilians[get_random_starting_index(civilians.len())],
+            None => active[get_random_starting_index(active.len())],
+        };
+        let mut outcome = resolve_eviction(&mut state, evicted);
+        if let GameScreen::ImposterGuess { civilian_word } = state.game_screen.clone() {
+            // A wrong guess is as good as none, so only the right word is ever named
+            let named = if chance(guess.unwrap_or(0.0)) { civilian_word.clone() } else { String::new() };
+            outcome = resolve_imposter_guess(&mut state, &civilian_word, &named);
+        }
+        match outcome {
+            EvictionOutcome::ImposterCaught => return tally.civilian_wins += 1,
+            EvictionOutcome::ImposterWins => return tally.imposter_wins += 1,
+            EvictionOutcome::Continue | EvictionOutcome::ImposterGuessing => {}
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    seed_random(options.seed);
+
+    println!("{:>7}  {:>7}  {:>9}  {:>8}  {:>9}", "players", "rounds", "civilians", "imposter", "avg votes");
+    for player_count in options.players.clone() {
+        let mut tally = Tally::default();
+        for _ in 0..options.rounds {
+            play_round(player_count, options.accuracy, options.guess, &mut tally);
+        }
+        let percent = |wins: usize| 100.0 * wins as f64 / options.rounds as f64;
+        println!(
+            "{:>7}  {:>7}  {:>8.1}%  {:>7.1}%  {:>9.2}",
+            player_count,
+            options.rounds,
+            percent(tally.civilian_wins),
+            percent(tally.imposter_wins),
+            tally.votes as f64 / options.rounds as f64,
+        );
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_ranges_and_rejects_nonsense() {
+        let options = parse_args(&args(&["--players", "4-6", "--rounds", "50", "--seed", "9", "--accuracy", "0.5", "--guess", "0.2"])).unwrap();
+        assert_eq!(options, Options { players: 4..=6, rounds: 50, seed: Some(9), accuracy: Some(0.5), guess: Some(0.2) });
+        assert_eq!(parse_args(&args(&["--players", "5"])).unwrap().players, 5..=5);
+        assert!(parse_args(&args(&["--players", "2-5"])).is_err());
+        assert!(parse_args(&args(&["--players", "8-4"])).is_err());
+        assert!(parse_args(&args(&["--accuracy", "1.5"])).is_err());
+        assert!(parse_args(&args(&["--guess", "-1"])).is_err());
+        assert!(parse_args(&args(&["--rounds"])).is_err());
+        assert!(parse_args(&args(&["--verbose", "1"])).is_err());
+    }
+
+    #[test]
+    fn every_round_ends_with_a_winner() {
+        seed_random(Some(1));
+        let mut tally = Tally::default();
+        for _ in 0..20 {
+            play_round(5, None, None, &mut tally);
+        }
+        seed_random(None);
+        assert_eq!(tally.civilian_wins + tally.imposter_wins, 20);
+        assert!(tally.votes >= 20);
+    }
+
+    #[test]
+    fn a_caught_imposter_who_names_the_word_wins() {
+        seed_random(Some(3));
+        // Every vote finds the imposter, so only the guess decides the round
+        let mut always_guessed = Tally::default();
+        let mut never_guessed = Tally::default();
+        for _ in 0..20 {
+            play_round(5, Some(1.0), Some(1.0), &mut always_guessed);
+            play_round(5, Some(1.0), Some(0.0), &mut never_guessed);
+        }
+        seed_random(None);
+        assert_eq!((always_guessed.imposter_wins, always_guessed.votes), (20, 20));
+        assert_eq!((never_guessed.civilian_wins, never_guessed.votes), (20, 20));
+    }
+}
@@ -0,0 +1,9 @@
+//! Ultimate Imposter: the game's screens and rules, shared by the web app and
+//! the headless tools in `src/bin`.
+
+/// Define a views module that contains the UI for our app.
+pub mod views;
+
+/// Server-side functions for disk persistence
+#[cfg(feature = "server")]
+pub mod server;
@@ -1,71 +1,232 @@
 use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
+use crate::views::game::game_engine::EVENT_LOG_CAP;
+use crate::views::game::{GameEvent, RejoinError};
 
-/// Get the directory for storing game saves
+/// Environment variable that overrides where game saves are written
+const SAVES_DIR_ENV: &str = "IMPOSTER_SAVES_DIR";
+
+/// A game that hasn't been saved for this long can no longer be loaded from the server,
+/// whether by its rejoin code, a join link or a reload
+pub const REJOIN_EXPIRY: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Get the directory for storing game saves (`IMPOSTER_SAVES_DIR`, or `game_saves` by default)
 fn get_saves_dir() -> PathBuf {
-    let mut path = PathBuf::from("game_saves");
+    let path = std::env::var_os(SAVES_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("game_saves"));
     if !path.exists() {
         let _ = fs::create_dir_all(&path);
     }
     path
 }
 
-/// Save game state to disk with session ID
-pub fn save_game_to_disk(session_id: &str, game_state_json: &str) -> Result<(), String> {
-    let saves_dir = get_saves_dir();
-    let file_path = saves_dir.join(format!("{}.json", session_id));
-    
-    fs::write(&file_path, game_state_json)
-        .map_err(|e| format!("Failed to save game: {}", e))?;
-    
-    Ok(())
+/// Path of the save file for a session, rejecting ids that could escape the saves directory
+fn save_file_path(session_id: &str) -> Result<PathBuf, String> {
+    let valid = !session_id.is_empty()
+        && session_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err("Invalid session id".to_string());
+    }
+    Ok(get_saves_dir().join(format!("{}.json", session_id)))
+}
+
+/// Path of a session's event log, next to its save
+fn events_file_path(session_id: &str) -> Result<PathBuf, String> {
+    let save = save_file_path(session_id)?;
+    Ok(save.with_extension("events.ndjson"))
 }
 
-/// Load game state from disk with session ID
-pub fn load_game_from_disk(session_id: &str) -> Result<String, String> {
-    let saves_dir = get_saves_dir();
-    let file_path = saves_dir.join(format!("{}.json", session_id));
-    
-    if !file_path.exists() {
+/// Longest event line the log accepts; a screen change is far shorter
+const MAX_EVENT_LEN: usize = 4096;
+
+/// Append one event as a line of the session's NDJSON event log. Only a `GameEvent`
+/// of a session with a save is taken, and like the client's log the file keeps the
+/// newest `EVENT_LOG_CAP` lines, so no client can grow it without limit.
+pub fn append_event_to_disk(session_id: &str, event_json: &str) -> Result<(), String> {
+    use std::io::Write;
+    if event_json.len() > MAX_EVENT_LEN {
+        return Err("Event is too long".to_string());
+    }
+    let event: GameEvent = serde_json::from_str(event_json)
+        .map_err(|e| format!("Not a game event: {}", e))?;
+    if event.session_id != session_id {
+        return Err("Event belongs to another session".to_string());
+    }
+    if !save_file_path(session_id)?.exists() {
         return Err("Game save not found".to_string());
     }
-    
-    fs::read_to_string(&file_path)
-        .map_err(|e| format!("Failed to load game: {}", e))
+    // Written back from the parsed event, so each line is exactly one event
+    let line = serde_json::to_string(&event).map_err(|e| format!("Failed to write event: {}", e))?;
+    let file_path = events_file_path(session_id)?;
+
+    let logged = fs::read_to_string(&file_path).unwrap_or_default();
+    let count = logged.lines().count();
+    if count >= EVENT_LOG_CAP {
+        let mut kept: Vec<&str> = logged.lines().skip(count + 1 - EVENT_LOG_CAP).collect();
+        kept.push(&line);
+        return write_replacing(&file_path, &(kept.join("\n") + "\n"))
+            .map_err(|e| format!("Failed to write event: {}", e));
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&file_path)
+        .map_err(|e| format!("Failed to open event log: {}", e))?;
+    writeln!(file, "{}", line)
+        .map_err(|e| format!("Failed to write event: {}", e))
 }
 
-/// List all saved game sessions
-pub fn list_saved_games() -> Result<Vec<String>, String> {
-    let saves_dir = get_saves_dir();
-    
-    let entries = fs::read_dir(&saves_dir)
-        .map_err(|e| format!("Failed to read saves directory: {}", e))?;
-    
-    let mut sessions = Vec::new();
-    for entry in entries {
-        if let Ok(entry) = entry {
-            if let Some(filename) = entry.file_name().to_str() {
-                if filename.ends_with(".json") {
-                    let session_id = filename.trim_end_matches(".json");
-                    sessions.push(session_id.to_string());
-                }
-            }
-        }
+/// Replace a file's contents in one step: the text goes to a temporary file first and
+/// is renamed over the original, so readers see one whole write or another
+fn write_replacing(file_path: &std::path::Path, contents: &str) -> std::io::Result<()> {
+    static NEXT_TEMP: AtomicU64 = AtomicU64::new(0);
+    // Each write gets its own temp file, so writes overlapping in time can't mix
+    let temp_path = file_path.with_extension(format!("{}.tmp", NEXT_TEMP.fetch_add(1, Ordering::Relaxed)));
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, file_path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+    })
+}
+
+/// Save game state to disk with session ID, replacing any earlier save in one step
+pub fn save_game_to_disk(session_id: &str, game_state_json: &str) -> Result<(), String> {
+    let file_path = save_file_path(session_id)?;
+    write_replacing(&file_path, game_state_json)
+        .map_err(|e| format!("Failed to save game: {}", e))
+}
+
+/// Load a session's save, unless it has gone untouched past `REJOIN_EXPIRY`
+pub fn load_game_from_disk(session_id: &str) -> Result<String, RejoinError> {
+    let file_path = save_file_path(session_id).map_err(|_| RejoinError::Unknown)?;
+    let Ok(metadata) = fs::metadata(&file_path) else {
+        return Err(RejoinError::Unknown);
+    };
+    let age = metadata.modified()
+        .ok()
+        .and_then(|saved| SystemTime::now().duration_since(saved).ok())
+        .unwrap_or_default();
+    if age > REJOIN_EXPIRY {
+        return Err(RejoinError::Expired);
     }
-    
-    Ok(sessions)
+    fs::read_to_string(&file_path).map_err(|_| RejoinError::Unreadable)
 }
 
-/// Delete a saved game
+/// Delete a saved game and its event log. Either may already be gone; a game that
+/// went private should leave nothing of itself behind either way.
 pub fn delete_saved_game(session_id: &str) -> Result<(), String> {
-    let saves_dir = get_saves_dir();
-    let file_path = saves_dir.join(format!("{}.json", session_id));
-    
-    if !file_path.exists() {
-        return Err("Game save not found".to_string());
+    for file_path in [save_file_path(session_id)?, events_file_path(session_id)?] {
+        match fs::remove_file(&file_path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(format!("Failed to delete game: {}", e));
+            }
+            _ => {}
+        }
     }
-    
-    fs::remove_file(&file_path)
-        .map_err(|e| format!("Failed to delete game: {}", e))
+    Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::GameScreen;
+    use std::sync::Mutex;
+
+    /// Tests that point `SAVES_DIR_ENV` somewhere take turns
+    static SAVES_DIR_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn saves_round_trip_through_configured_directory() {
+        let _guard = SAVES_DIR_LOCK.lock().unwrap();
+        let dir = std::env::temp_dir().join(format!("imposter-saves-{}", std::process::id()));
+        std::env::set_var(SAVES_DIR_ENV, &dir);
+
+        save_game_to_disk("abc-123", "{\"round_number\":1}").unwrap();
+        save_game_to_disk("abc-123", "{\"round_number\":2}").unwrap();
+        assert!(dir.join("abc-123.json").exists());
+        assert_eq!(load_game_from_disk("abc-123").unwrap(), "{\"round_number\":2}");
+        // Writes go through temp files, none of which are left behind
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        delete_saved_game("abc-123").unwrap();
+        assert!(load_game_from_disk("abc-123").is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn events_append_one_line_each_for_their_own_session_up_to_the_cap() {
+        let _guard = SAVES_DIR_LOCK.lock().unwrap();
+        let dir = std::env::temp_dir().join(format!("imposter-events-{}", std::process::id()));
+        std::env::set_var(SAVES_DIR_ENV, &dir);
+
+        let event = |ts: usize, session: &str| serde_json::to_string(&GameEvent {
+            ts: ts as f64,
+            session_id: session.to_string(),
+            from: GameScreen::Setup,
+            to: GameScreen::CategorySelection,
+        }).unwrap();
+        assert!(append_event_to_disk("abc-123", &event(1, "abc-123")).is_err(), "no save, no log");
+        save_game_to_disk("abc-123", "{}").unwrap();
+
+        append_event_to_disk("abc-123", &event(1, "abc-123")).unwrap();
+        append_event_to_disk("abc-123", &event(2, "abc-123")).unwrap();
+        assert!(append_event_to_disk("abc-123", "{}\n{}").is_err());
+        assert!(append_event_to_disk("abc-123", "\"filler\"").is_err(), "only game events are logged");
+        assert!(append_event_to_disk("abc-123", &event(3, "other")).is_err(), "events stay in their own session");
+        let log = fs::read_to_string(dir.join("abc-123.events.ndjson")).unwrap();
+        assert_eq!(log, format!("{}\n{}\n", event(1, "abc-123"), event(2, "abc-123")));
+
+        // Past the cap the oldest lines go, as in the client's log
+        for ts in 3..EVENT_LOG_CAP + 3 {
+            append_event_to_disk("abc-123", &event(ts, "abc-123")).unwrap();
+        }
+        let log = fs::read_to_string(dir.join("abc-123.events.ndjson")).unwrap();
+        assert_eq!(log.lines().count(), EVENT_LOG_CAP);
+        assert_eq!(log.lines().next(), Some(event(3, "abc-123").as_str()));
+        assert_eq!(log.lines().last(), Some(event(EVENT_LOG_CAP + 2, "abc-123").as_str()));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn deleting_a_private_game_leaves_no_files_behind() {
+        let _guard = SAVES_DIR_LOCK.lock().unwrap();
+        let dir = std::env::temp_dir().join(format!("imposter-delete-{}", std::process::id()));
+        std::env::set_var(SAVES_DIR_ENV, &dir);
+
+        save_game_to_disk("abc-123", "{}").unwrap();
+        let event = GameEvent { ts: 1.0, session_id: "abc-123".to_string(), from: GameScreen::Setup, to: GameScreen::Voting };
+        append_event_to_disk("abc-123", &serde_json::to_string(&event).unwrap()).unwrap();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        delete_saved_game("abc-123").unwrap();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        delete_saved_game("abc-123").expect("nothing left to delete is not an error");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn only_recent_saves_load() {
+        let _guard = SAVES_DIR_LOCK.lock().unwrap();
+        let dir = std::env::temp_dir().join(format!("imposter-rejoin-{}", std::process::id()));
+        std::env::set_var(SAVES_DIR_ENV, &dir);
+
+        save_game_to_disk("K7QX3M", "{\"round_number\":3}").unwrap();
+        assert_eq!(load_game_from_disk("K7QX3M").unwrap(), "{\"round_number\":3}");
+        assert_eq!(load_game_from_disk("ZZZZZZ"), Err(RejoinError::Unknown));
+        assert_eq!(load_game_from_disk("../K7QX3M"), Err(RejoinError::Unknown));
+
+        let stale = SystemTime::now() - REJOIN_EXPIRY - Duration::from_secs(60);
+        fs::File::options().write(true).open(dir.join("K7QX3M.json")).unwrap().set_modified(stale).unwrap();
+        assert_eq!(load_game_from_disk("K7QX3M"), Err(RejoinError::Expired));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn session_ids_cannot_escape_the_saves_directory() {
+        assert!(save_file_path("../secrets").is_err());
+        assert!(save_file_path("a/b").is_err());
+        assert!(save_file_path("").is_err());
+    }
+}
@@ -1,7 +1,13 @@
 // Game module structure
 mod types;
-mod utils;
+mod i18n;
+pub mod utils;
+pub mod game_engine;
 mod persistence;
+mod share;
+mod sound;
+mod stats;
+mod flagged_pairs;
 mod components;
 
 // Re-export types for use in components
@@ -18,93 +24,355 @@ const _GAME_CSS: Asset = asset!("/assets/styling/game.css");
 #[component]
 pub fn Game() -> Element {
     // Initialize game state - load from localStorage if available
-    let mut session_id = use_signal(|| String::new());
+    let mut session_id = use_signal(String::new);
     let mut game_screen = use_signal(|| GameScreen::Setup);
-    let mut players = use_signal(|| Vec::<Player>::new());
+    let mut players = use_signal(Vec::<Player>::new);
     let mut player_count_input = use_signal(|| String::from("3"));
-    let mut player_names = use_signal(|| Vec::<String>::new());
+    let mut player_names = use_signal(Vec::<String>::new);
     let mut round_number = use_signal(|| 1);
-    let mut cards = use_signal(|| Vec::<GameCard>::new());
+    let mut cards = use_signal(Vec::<GameCard>::new);
     let mut imposter_index = use_signal(|| 0usize);
     let mut current_category = use_signal(|| None::<(String, String)>);
     let mut selected_category_index = use_signal(|| None::<usize>);
-    let mut hide_imposter_identity = use_signal(|| false);
     let mut current_round_words = use_signal(|| None::<(String, String)>);
+    let mut dealt_pair = use_signal(|| None::<(String, String)>);
+    let mut twist_active = use_signal(|| false);
     let mut starting_player_index = use_signal(|| 0usize);
+    let mut settings = use_signal(Settings::default);
+    let mut grace_round_pending = use_signal(|| false);
+    let mut timer_remaining = use_signal(|| None::<u32>);
+    let mut timer_paused = use_signal(|| false);
+    let mut history = use_signal(Vec::<usize>::new);
+    let mut hidden_catch = use_signal(|| None::<usize>);
+    let mut recent_pairs = use_signal(Vec::<RecentPair>::new);
+    let mut skipped_players = use_signal(Vec::<usize>::new);
+    let mut turn_order = use_signal(Vec::<usize>::new);
+    let mut round_log = use_signal(Vec::<RoundRecord>::new);
+    let mut consecutive_skips = use_signal(|| 0usize);
+    let mut solo = use_signal(|| false);
+    let mut sealed_deal = use_signal(|| None::<SealedDeal>);
+    let mut opened_deal = use_signal(|| None::<DealProof>);
+    let mut times_imposter = use_signal(Vec::<i32>::new);
+    let mut event_log = use_signal(Vec::<GameEvent>::new);
+    // Opened from a join link: this device only shows the game and never saves it
+    let mut joined = use_signal(|| false);
+    // Set last on every load path, once the restored game is fully in the signals
     let mut initialized = use_signal(|| false);
+    let mut save_status = use_signal(|| SaveStatus::Saving);
+    // Checked once: without storage the game still plays, it just can't outlive the page
+    let storage_ok = use_hook(storage_available);
+    let mut storage_notice_open = use_signal(|| !storage_ok);
+    
+    // Replace every live signal with the contents of a saved state
+    let mut apply_state = move |saved_state: GameState| {
+        session_id.set(saved_state.session_id);
+        game_screen.set(saved_state.game_screen);
+        players.set(saved_state.players);
+        player_count_input.set(saved_state.player_count_input);
+        player_names.set(saved_state.player_names);
+        round_number.set(saved_state.round_number);
+        cards.set(saved_state.cards);
+        imposter_index.set(saved_state.imposter_index);
+        current_category.set(saved_state.current_category);
+        selected_category_index.set(saved_state.selected_category_index);
+        current_round_words.set(saved_state.current_round_words);
+        dealt_pair.set(saved_state.dealt_pair);
+        twist_active.set(saved_state.twist_active);
+        starting_player_index.set(saved_state.starting_player_index);
+        settings.set(saved_state.settings);
+        grace_round_pending.set(saved_state.grace_round_pending);
+        timer_remaining.set(saved_state.timer_remaining);
+        timer_paused.set(saved_state.timer_paused);
+        history.set(saved_state.history);
+        hidden_catch.set(saved_state.hidden_catch);
+        recent_pairs.set(saved_state.recent_pairs);
+        skipped_players.set(saved_state.skipped_players);
+        turn_order.set(saved_state.turn_order);
+        round_log.set(saved_state.round_log);
+        consecutive_skips.set(saved_state.consecutive_skips);
+        times_imposter.set(saved_state.times_imposter);
+        event_log.set(saved_state.event_log);
+        solo.set(saved_state.solo);
+        sealed_deal.set(saved_state.sealed_deal);
+        opened_deal.set(saved_state.opened_deal);
+    };
     
     // Initialize once on mount
     use_effect(move || {
         if !initialized() {
             // Try to load existing session
-            let sid = load_session_id().unwrap_or_else(|| {
-                let id = generate_session_id();
-                save_session_id(&id);
-                id
-            });
+            // A new session id is only stored once the game is saved (private games never are)
+            let sid = load_session_id().unwrap_or_else(generate_session_id);
             
             session_id.set(sid.clone());
             
-            // Try to load saved game state for this session
-            if let Some(saved_state) = load_game_state(&sid) {
-                game_screen.set(saved_state.game_screen);
-                players.set(saved_state.players);
-                player_count_input.set(saved_state.player_count_input);
-                player_names.set(saved_state.player_names);
-                round_number.set(saved_state.round_number);
-                cards.set(saved_state.cards);
-                imposter_index.set(saved_state.imposter_index);
-                current_category.set(saved_state.current_category);
-                selected_category_index.set(saved_state.selected_category_index);
-                hide_imposter_identity.set(saved_state.hide_imposter_identity);
-                current_round_words.set(saved_state.current_round_words);
-                starting_player_index.set(saved_state.starting_player_index);
-            }
+            // A shared setup link pre-fills the setup screen
+            let mut finish_init = move || {
+                if let Some(setup) = share::setup_from_page_url() {
+                    player_count_input.set(setup.player_names.len().to_string());
+                    player_names.set(setup.player_names);
+                    // The link carries the sender's game settings, not their sound or display choices
+                    let local = settings.peek().clone();
+                    settings.set(share::shared_settings(setup.settings, &local));
+                    game_screen.set(GameScreen::Setup);
+                    // Drop the fragment so a reload doesn't re-apply it
+                    let _ = document::eval("history.replaceState(null, '', location.pathname + location.search);");
+                }
+                initialized.set(true);
+            };
             
-            initialized.set(true);
+            // A join link opens another device's game on its scoreboard, straight from the server
+            if let Some(code) = share::session_from_page_url() {
+                let load = async move {
+                    let joined_state = load_game_state_from_server(&code).await;
+                    // Drop the query so a reload doesn't join again
+                    let _ = document::eval("history.replaceState(null, '', location.pathname + location.hash);");
+                    match joined_state {
+                        Some(saved_state) => {
+                            joined.set(true);
+                            Some(share::spectator_view(prepare_resume(saved_state)))
+                        }
+                        None => load_game_state(&sid).map(prepare_resume),
+                    }
+                };
+                spawn(load_then_finish(load, apply_state, finish_init));
+            } else if let Some(saved_state) = load_game_state(&sid).map(prepare_resume) {
+                apply_state(saved_state);
+                finish_init();
+            } else {
+                // Nothing in this browser - the server may still have the game.
+                // Auto-save stays off until this settles so it can't overwrite the server copy.
+                let load = async move { load_game_state_from_server(&sid).await.map(prepare_resume) };
+                spawn(load_then_finish(load, apply_state, finish_init));
+            }
         }
     });
     
-    // Auto-save game state whenever it changes (but only after initialization)
+    // Snapshot every live signal into a saveable state
+    let current_state = move || GameState {
+        schema_version: SCHEMA_VERSION,
+        session_id: session_id(),
+        game_screen: game_screen(),
+        players: players(),
+        player_count_input: player_count_input(),
+        player_names: player_names(),
+        round_number: round_number(),
+        cards: cards(),
+        imposter_index: imposter_index(),
+        current_category: current_category(),
+        selected_category_index: selected_category_index(),
+        current_round_words: current_round_words(),
+        dealt_pair: dealt_pair(),
+        twist_active: twist_active(),
+        starting_player_index: starting_player_index(),
+        settings: settings(),
+        grace_round_pending: grace_round_pending(),
+        timer_remaining: timer_remaining(),
+        timer_paused: timer_paused(),
+        history: history(),
+        hidden_catch: hidden_catch(),
+        recent_pairs: recent_pairs(),
+        skipped_players: skipped_players(),
+        turn_order: turn_order(),
+        round_log: round_log(),
+        consecutive_skips: consecutive_skips(),
+        times_imposter: times_imposter(),
+        event_log: event_log(),
+        solo: solo(),
+        sealed_deal: sealed_deal(),
+        opened_deal: opened_deal(),
+    };
+    
+    // What the latest change did in this browser, shown if the server save behind it fails
+    let mut last_local_save = use_signal(|| SaveStatus::NotSaved);
+    // The private session whose saves were last removed, so that's done once, not on every change
+    let mut forgotten_session = use_signal(|| None::<String>);
+    // Auto-save game state whenever it changes, but never mid-load
     use_effect(move || {
-        if initialized() && !session_id().is_empty() {
-            let state = GameState {
-                session_id: session_id(),
-                game_screen: game_screen(),
-                players: players(),
-                player_count_input: player_count_input(),
-                player_names: player_names(),
-                round_number: round_number(),
-                cards: cards(),
-                imposter_index: imposter_index(),
-                current_category: current_category(),
-                selected_category_index: selected_category_index(),
-                hide_imposter_identity: hide_imposter_identity(),
-                current_round_words: current_round_words(),
-                starting_player_index: starting_player_index(),
-            };
-            save_game_state(&state);
+        let state = current_state();
+        if !ready_to_autosave(initialized(), &state) {
+            return;
+        }
+        // The host's device owns a joined game's save; writing here would overwrite it
+        if joined() {
+            save_status.set(SaveStatus::Viewing);
+            return;
         }
+        let local_save = save_game_state(&state);
+        save_status.set(local_save);
+        save_session_id(&state.session_id, state.settings.persistence_enabled);
+        if !state.settings.persistence_enabled {
+            // Anything saved before the game went private goes too, once per session
+            if forgotten_session.peek().as_ref() != Some(&state.session_id) {
+                forgotten_session.set(Some(state.session_id.clone()));
+                spawn(async move {
+                    delete_game_state_from_server(&state.session_id).await;
+                });
+            }
+            return;
+        }
+        if forgotten_session.peek().is_some() {
+            forgotten_session.set(None);
+        }
+        // This page forgets the session code on reload without local storage, so a server
+        // copy could never be found again and would only be left behind
+        if !storage_ok {
+            return;
+        }
+        // Mirror to the server so clearing the browser cache doesn't lose the game.
+        // Changes made while a save is on its way only replace what's sent next, and the
+        // save that's sending reports for them once nothing is left to send.
+        last_local_save.set(local_save);
+        save_status.set(SaveStatus::Saving);
+        spawn(async move {
+            if let Some(sent) = save_game_state_to_server(&state).await {
+                // Unless the game went private or was joined meanwhile
+                if *save_status.peek() == SaveStatus::Saving {
+                    save_status.set(if sent { SaveStatus::Saved } else { *last_local_save.peek() });
+                }
+            }
+        });
+    });
+
+    // Watch the one screen signal so every transition is logged, whichever screen made it.
+    // Loading a game is not a transition, so logging starts from the screen it opened on.
+    let mut logged_screen = use_signal(|| None::<GameScreen>);
+    use_effect(move || {
+        let to = game_screen();
+        if !initialized() {
+            return;
+        }
+        let from = logged_screen.peek().clone();
+        logged_screen.set(Some(to.clone()));
+        let Some(from) = from.filter(|from| *from != to) else {
+            return;
+        };
+        let settings = settings.peek().clone();
+        if !settings.record_events {
+            return;
+        }
+        let event = GameEvent { ts: utils::now_ms(), session_id: session_id.peek().clone(), from, to };
+        game_engine::record_event(&mut event_log.write(), event.clone());
+        if settings.persistence_enabled && storage_ok && !*joined.peek() {
+            spawn(async move {
+                save_event_to_server(&event).await;
+            });
+        }
+    });
+
+    // Rules run on a snapshot of the whole game, then the result replaces the live signals
+    let start_next_round = move |_: ()| {
+        let mut state = current_state();
+        game_engine::start_next_round(&mut state);
+        apply_state(state);
+    };
+    // A decided game goes into the lifetime stats, unless it's private
+    let record_outcome = |state: &GameState, outcome: game_engine::EvictionOutcome| {
+        if outcome.game_over() && state.settings.persistence_enabled {
+            let imposter_won = outcome == game_engine::EvictionOutcome::ImposterWins;
+            stats::record_finished_game(&state.players, state.imposter_index, imposter_won);
+        }
+    };
+    let start_new_game = move |_: ()| {
+        let mut state = current_state();
+        game_engine::start_new_game(&mut state);
+        apply_state(state);
+    };
+
+    let announcement = use_memo(move || {
+        utils::screen_announcement(&game_screen(), &players(), starting_player_index(), &turn_order(), settings().lang)
     });
     
     rsx! {
         document::Stylesheet { href: _GAME_CSS }
-        div { class: "game-container",
+        div { class: utils::root_class(settings().theme, settings().accessibility, settings().display_mode),
+            div { class: "session-info",
+                span { class: "session-code", {(i18n::ui_text(settings().lang).session_label)(&session_id())} }
+                span {
+                    class: match save_status() {
+                        SaveStatus::NotSaved | SaveStatus::Unavailable => "save-status not-saved",
+                        SaveStatus::Private => "save-status private",
+                        _ => "save-status",
+                    },
+                    "aria-live": "polite",
+                    "{save_status().label(settings().lang)}"
+                }
+                button {
+                    class: if settings().sound_enabled { "sound-toggle" } else { "sound-toggle muted" },
+                    "aria-pressed": "{!settings().sound_enabled}",
+                    "aria-label": i18n::ui_text(settings().lang).toggle_sound_label,
+                    onclick: move |_| {
+                        let on = settings().sound_enabled;
+                        settings.write().sound_enabled = !on;
+                    },
+                    if settings().sound_enabled {
+                        "{i18n::ui_text(settings().lang).mute_sound}"
+                    } else {
+                        "{i18n::ui_text(settings().lang).unmute_sound}"
+                    }
+                }
+                button {
+                    class: "theme-toggle",
+                    "aria-pressed": "{settings().theme == Theme::Dark}",
+                    "aria-label": i18n::ui_text(settings().lang).toggle_theme_label,
+                    onclick: move |_| {
+                        let theme = settings().theme;
+                        settings.write().theme = if theme == Theme::Dark { Theme::Light } else { Theme::Dark };
+                    },
+                    // Names the theme the button switches to
+                    if settings().theme == Theme::Dark {
+                        "{i18n::ui_text(settings().lang).light_theme}"
+                    } else {
+                        "{i18n::ui_text(settings().lang).dark_theme}"
+                    }
+                }
+            }
+
+            if storage_notice_open() {
+                div { class: "storage-notice", role: "status",
+                    span { "{i18n::ui_text(settings().lang).saving_unavailable}" }
+                    button {
+                        class: "storage-notice-close",
+                        "aria-label": "{i18n::ui_text(settings().lang).dismiss}",
+                        onclick: move |_| storage_notice_open.set(false),
+                        "✕"
+                    }
+                }
+            }
+
+            // Screen readers hear a summary whenever the screen changes
+            div {
+                class: "sr-only",
+                role: "status",
+                "aria-live": "polite",
+                "{announcement}"
+            }
+            
             match game_screen() {
                 GameScreen::Setup => rsx! {
                     SetupScreen {
+                        session_id: session_id(),
                         player_count_input,
                         player_names,
                         players,
                         game_screen,
                         round_number,
                         starting_player_index,
+                        recent_pairs,
+                        selected_category_index,
+                        settings,
+                        solo,
+                        on_restore: move |restored: GameState| {
+                            save_session_id(&restored.session_id, restored.settings.persistence_enabled);
+                            apply_state(prepare_resume(restored));
+                        },
                     }
                 },
                 GameScreen::CategorySelection => rsx! {
                     CategorySelectionScreen {
                         game_screen,
                         selected_category_index,
+                        settings,
                     }
                 },
                 GameScreen::CategoryReveal { category_name, category_icon } => rsx! {
@@ -112,7 +380,7 @@ pub fn Game() -> Element {
                         category_name,
                         category_icon,
                         game_screen,
-                        hide_imposter_identity,
+                        settings,
                     }
                 },
                 GameScreen::CardView { current_player_index } => rsx! {
@@ -124,9 +392,25 @@ pub fn Game() -> Element {
                         game_screen,
                         current_category,
                         selected_category_index,
-                        hide_imposter_identity,
-                        current_round_words,
                         starting_player_index,
+                        settings,
+                        skipped_players,
+                        turn_order,
+                        solo,
+                        sealed_deal,
+                        twist_active,
+                        on_deal: move |_| {
+                            let mut state = current_state();
+                            if game_engine::deal_round(&mut state) {
+                                apply_state(state);
+                            }
+                        },
+                        on_all_seen: move |_| {
+                            let mut state = current_state();
+                            game_engine::open_sealed_deal(&mut state);
+                            state.game_screen = GameScreen::Voting;
+                            apply_state(state);
+                        },
                     }
                 },
                 GameScreen::Voting => rsx! {
@@ -137,6 +421,45 @@ pub fn Game() -> Element {
                         cards,
                         current_category,
                         starting_player_index,
+                        settings,
+                        grace_round_pending,
+                        timer_remaining,
+                        timer_paused,
+                        round_number,
+                        history,
+                        hidden_catch,
+                        skipped_players,
+                        turn_order,
+                        consecutive_skips,
+                        solo,
+                        twist_active,
+                    }
+                },
+                GameScreen::SecretVoting { current_voter_index, votes, candidates, revotes } => rsx! {
+                    SecretVotingScreen {
+                        current_voter_index,
+                        votes,
+                        candidates,
+                        revotes,
+                        players,
+                        game_screen,
+                        starting_player_index,
+                        settings,
+                        turn_order,
+                    }
+                },
+                GameScreen::VoteReveal { tally, revotes } => rsx! {
+                    VoteRevealScreen { tally, revotes, players, game_screen, imposter_index, settings }
+                },
+                GameScreen::TieBreak { tied_indices, revotes } => rsx! {
+                    TieBreakScreen {
+                        tied_indices,
+                        revotes,
+                        players,
+                        game_screen,
+                        round_number,
+                        timer_remaining,
+                        settings,
                     }
                 },
                 GameScreen::Elimination { eliminated_index, was_imposter } => rsx! {
@@ -145,9 +468,29 @@ pub fn Game() -> Element {
                         eliminated_index,
                         was_imposter,
                         game_screen,
-                        round_number,
                         cards,
+                        current_round_words,
+                        settings,
+                        history,
+                        on_confirm: move |_| {
+                            let mut state = current_state();
+                            let outcome = game_engine::resolve_eviction(&mut state, eliminated_index);
+                            record_outcome(&state, outcome);
+                            apply_state(state);
+                        },
+                    }
+                },
+                GameScreen::ImposterGuess { civilian_word } => rsx! {
+                    ImposterGuessScreen {
+                        players,
                         imposter_index,
+                        settings,
+                        on_guess: move |guess: String| {
+                            let mut state = current_state();
+                            let outcome = game_engine::resolve_imposter_guess(&mut state, &civilian_word, &guess);
+                            record_outcome(&state, outcome);
+                            apply_state(state);
+                        },
                     }
                 },
                 GameScreen::RoundEnd { imposter_found, game_over } => rsx! {
@@ -155,22 +498,74 @@ pub fn Game() -> Element {
                         players,
                         imposter_found,
                         game_over,
+                        insight: game_engine::insight(
+                            game_engine::RoundEnding::of(imposter_found, players().get(imposter_index())),
+                            game_engine::votes_taken(&history(), hidden_catch()),
+                            settings().lang,
+                        ),
                         game_screen,
                         round_number,
                         cards,
                         imposter_index,
                         current_round_words,
-                        starting_player_index,
+                        dealt_pair: dealt_pair(),
+                        opened_deal: opened_deal(),
+                        settings,
+                        history,
+                        hidden_catch,
+                        round_log,
+                        on_next_round: start_next_round,
+                        on_new_game: start_new_game,
                     }
                 },
                 GameScreen::GameScore => rsx! {
                     GameScoreScreen {
                         players,
+                        player_names,
                         round_number,
                         game_screen,
+                        settings,
+                        round_log,
+                        session_id: session_id(),
+                        on_next_round: start_next_round,
+                        on_new_game: start_new_game,
+                        on_export: move |_| download_game_export(&current_state()),
+                    }
+                },
+                GameScreen::Stats => rsx! {
+                    StatsScreen { game_screen, lang: settings().lang }
+                },
+                GameScreen::RoundHistory => rsx! {
+                    RoundHistoryScreen {
+                        round_log,
+                        game_screen,
+                        reveal_imposter: settings().reveal_imposter_at_round_end,
+                        lang: settings().lang,
+                    }
+                },
+                GameScreen::Replay { step } => rsx! {
+                    ReplayScreen {
+                        round_log,
+                        step,
+                        game_screen,
+                        location_mode: settings().game_mode == GameMode::Location,
+                        reveal_imposter: settings().reveal_imposter_at_round_end,
+                        lang: settings().lang,
+                    }
+                },
+                GameScreen::EditPlayers => rsx! {
+                    EditPlayersScreen {
+                        players,
+                        player_names,
+                        player_count_input,
+                        game_screen,
+                        round_number,
                         cards,
                         imposter_index,
                         starting_player_index,
+                        history,
+                        times_imposter,
+                        settings,
                     }
                 },
             }
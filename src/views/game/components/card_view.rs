@@ -1,102 +1,328 @@
 use dioxus::prelude::*;
-use crate::views::game::{types::*, utils::generate_cards_for_category};
+use crate::views::game::components::{PlayerCard, PrintCardsSheet, RoundRecovery};
+use crate::views::game::{game_engine::sealed_card, i18n::ui_text, sound::{self, Sound}, types::*, utils::{cards_seen, commitment_code, pass_order, focus_on_mount, matches_name_initial, now_ms, sleep_ms, SOLO_SEAT}};
 
-/// Screen where players view their cards one by one
+/// Two reveals closer together than this suggest the device wasn't passed on
+const SEAT_SWAP_THRESHOLD_MS: f64 = 3000.0;
+
+/// How long a simulated player in a solo game "looks" at their card before passing it on
+const SIMULATED_TURN_MS: u32 = 1500;
+
+/// Screen where players view their cards one by one.
+/// Whether a card is face up lives only in this component, never in `GameState`,
+/// so a reload always lands on the ready screen for the current player.
 #[component]
 pub fn CardViewScreen(
     current_player_index: usize,
     players: Signal<Vec<Player>>,
-    mut cards: Signal<Vec<GameCard>>,
-    mut imposter_index: Signal<usize>,
+    cards: Signal<Vec<GameCard>>,
+    imposter_index: Signal<usize>,
     mut game_screen: Signal<GameScreen>,
-    mut current_category: Signal<Option<(String, String)>>,
+    current_category: Signal<Option<(String, String)>>,
     selected_category_index: Signal<Option<usize>>,
-    hide_imposter_identity: Signal<bool>,
-    mut current_round_words: Signal<Option<(String, String)>>,
-    mut starting_player_index: Signal<usize>,
+    starting_player_index: Signal<usize>,
+    settings: Signal<Settings>,
+    mut skipped_players: Signal<Vec<usize>>,
+    turn_order: Signal<Vec<usize>>,
+    solo: Signal<bool>,
+    sealed_deal: Signal<Option<SealedDeal>>,
+    twist_active: Signal<bool>,
+    on_deal: EventHandler<()>,
+    on_all_seen: EventHandler<()>, // Moves on to the vote, opening a blind deal first
 ) -> Element {
-    // Initialize cards for the round using the selected category
+    // Deal the round once there are players and (for word rounds) a category to deal from
     use_effect(move || {
-        let player_count = players().len();
-        if cards().is_empty() && player_count > 0 {
-            if let Some(cat_index) = selected_category_index() {
-                let (new_cards, new_imposter, cat_name, cat_icon) = generate_cards_for_category(player_count, cat_index);
-                
-                // Store the words for this round (civilian word, imposter word)
-                if !new_cards.is_empty() {
-                    let civilian_word = new_cards.iter()
-                        .find(|c| c.card_type == CardType::Normal)
-                        .map(|c| c.word.clone())
-                        .unwrap_or_default();
-                    let imposter_word = new_cards.iter()
-                        .find(|c| c.card_type == CardType::Imposter)
-                        .map(|c| c.word.clone())
-                        .unwrap_or_default();
-                    current_round_words.set(Some((civilian_word, imposter_word)));
-                }
-                
-                cards.set(new_cards);
-                imposter_index.set(new_imposter);
-                current_category.set(Some((cat_name.clone(), cat_icon.clone())));
-            }
+        let _ = selected_category_index();
+        if cards().is_empty() && sealed_deal().is_none() && !players().is_empty() {
+            on_deal.call(());
         }
     });
 
     let player_list = players();
-    let cards_list = cards();
+    let t = ui_text(settings().lang);
     
     // Calculate rotated player order
     let total_players = player_list.len();
-    let start_idx = starting_player_index() % total_players;
-    let actual_player_index = (start_idx + current_player_index) % total_players;
+    if total_players == 0 {
+        return rsx! { RoundRecovery { players, cards, game_screen, lang: settings().lang } };
+    }
+    let order = pass_order(total_players, starting_player_index(), &turn_order());
+    let actual_player_index = order.get(current_player_index).copied().unwrap_or(0);
     
     if current_player_index >= player_list.len() {
+        // (seat, place in the pass order, name) for everyone still to look
+        let pending: Vec<(usize, usize, String)> = skipped_players().iter()
+            .filter_map(|&seat| {
+                let position = order.iter().position(|&s| s == seat)?;
+                player_list.get(seat).map(|p| (seat, position, p.name.clone()))
+            })
+            .collect();
+        let everyone_seen = pending.is_empty();
         return rsx! {
             div { class: "transition-screen",
-                h2 { "All players have seen their cards!" }
+                if everyone_seen {
+                    h2 { "{t.all_cards_seen}" }
+                } else {
+                    h2 { "{t.almost_all_cards_seen}" }
+                    div { class: "skipped-players", role: "alert",
+                        p { "{t.skipped_players_warning}" }
+                        for (seat, position, name) in pending {
+                            button {
+                                key: "{seat}",
+                                class: "revisit-btn",
+                                onclick: move |_| {
+                                    game_screen.set(GameScreen::CardView {
+                                        current_player_index: position
+                                    });
+                                },
+                                {(t.show_players_card)(&name)}
+                            }
+                        }
+                    }
+                }
                 button {
                     class: "proceed-btn",
-                    onclick: move |_| {
-                        game_screen.set(GameScreen::Voting);
-                    },
-                    "Proceed to Discussion"
+                    onmounted: focus_on_mount,
+                    onclick: move |_| on_all_seen.call(()),
+                    if everyone_seen { "{t.proceed_to_discussion}" } else { "{t.proceed_without_them}" }
                 }
             }
         };
     }
     
-    // Check if cards are initialized
-    if cards_list.is_empty() || actual_player_index >= cards_list.len() {
+    // A blind deal works out only this seat's card, never the whole hand
+    let current_card = match sealed_deal() {
+        Some(deal) => sealed_card(&deal, actual_player_index),
+        None => cards().get(actual_player_index).cloned(),
+    };
+    let Some(current_card) = current_card else {
         return rsx! {
             div { class: "loading-screen",
-                p { "Preparing cards..." }
+                p { "{t.preparing_cards}" }
             }
         };
-    }
+    };
 
+    // Deliberately not saved: a resumed game must never open on someone's word
     let mut card_revealed = use_signal(|| false);
+    let mut name_check_input = use_signal(String::new);
+    let mut show_print_sheet = use_signal(|| false);
+    let mut last_reveal_at = use_signal(|| None::<f64>);
+    let mut show_swap_nudge = use_signal(|| false);
+    let mut word_hidden = use_signal(|| false);
+    // Seconds until a revealed card passes itself on; None when no countdown is running
+    let mut reveal_countdown = use_signal(|| None::<u32>);
+    // The hand-off's "get ready" pause: (place in line, when it started, seconds left)
+    let mut pass_wait = use_signal(|| None::<(usize, f64, u32)>);
     let current_player = &player_list[actual_player_index];
-    let current_card = &cards_list[actual_player_index];
-    
-    // Determine what to show based on hard mode
-    let is_imposter = current_card.card_type == CardType::Imposter;
-    let hard_mode_enabled = hide_imposter_identity();
-    let should_hide_imposter = hard_mode_enabled && is_imposter;
-    
-    // Always show each player's assigned word; hard mode only hides identity text
-    let displayed_word = current_card.word.clone();
+    let name_check_required = settings().reveal_name_check;
+    let location_mode = settings().game_mode == GameMode::Location;
+    let custom_words = !settings().custom_pairs.is_empty() && !location_mode;
+    let random_category = settings().random_category_per_round && !location_mode && !custom_words;
+    // Hard mode only hides identity text; each player always sees their assigned word
+    let hard_mode_enabled = settings().hide_imposter_identity;
+    // A skipped player coming back from the hand-off screen returns there afterwards
+    let revisiting = skipped_players().contains(&actual_player_index);
+    let first_in_line = current_player_index == 0 && !revisiting;
+    // Nobody looked right before the first player, so only later hand-offs pause.
+    // Until this hand-off's pause has started it counts as the full wait.
+    let pass_delay = settings().pass_delay_secs.filter(|s| *s > 0 && !first_in_line);
+    let get_ready_left = pass_delay.map(|delay| match pass_wait() {
+        Some((place, _, left)) if place == current_player_index => left,
+        _ => delay,
+    }).filter(|left| *left > 0);
+    let reveal_unlocked = get_ready_left.is_none()
+        && (!name_check_required || matches_name_initial(&current_player.name, &name_check_input()));
+    let seen = cards_seen(current_player_index, total_players, skipped_players().len(), revisiting);
+    let progress_percent = seen * 100 / total_players;
+
+    let mut next_player = move || {
+        card_revealed.set(false);
+        word_hidden.set(false);
+        reveal_countdown.set(None);
+        name_check_input.set(String::new());
+        if revisiting {
+            skipped_players.write().retain(|&seat| seat != actual_player_index);
+        }
+        game_screen.set(GameScreen::CardView {
+            current_player_index: if revisiting { total_players } else { current_player_index + 1 }
+        });
+    };
+    let mut reveal_card = move || {
+        let now = now_ms();
+        let too_fast = last_reveal_at()
+            .map(|previous| now - previous < SEAT_SWAP_THRESHOLD_MS)
+            .unwrap_or(false);
+        show_swap_nudge.set(settings().seat_swap_warning && too_fast);
+        last_reveal_at.set(Some(now));
+        card_revealed.set(true);
+        sound::play(Sound::Reveal, settings().sound_enabled);
+
+        // Only a revealed card counts down; the ready screen always waits for its player
+        if let Some(seconds) = settings().reveal_timeout_secs.filter(|s| *s > 0) {
+            reveal_countdown.set(Some(seconds));
+            spawn(async move {
+                loop {
+                    sleep_ms(1000).await;
+                    // This reveal is over if the card was passed on early or someone else revealed since
+                    if !*card_revealed.peek() || *last_reveal_at.peek() != Some(now) {
+                        return;
+                    }
+                    let left = *reveal_countdown.peek();
+                    match left {
+                        Some(left) if left > 1 => reveal_countdown.set(Some(left - 1)),
+                        _ => {
+                            next_player();
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+    };
+    // Counts down from each mount of the ready screen; a newer pause takes over from older ones
+    let mut start_pass_wait = move || {
+        let Some(delay) = pass_delay else { return };
+        let started = now_ms();
+        pass_wait.set(Some((current_player_index, started, delay)));
+        spawn(async move {
+            loop {
+                sleep_ms(1000).await;
+                let current = *pass_wait.peek();
+                match current {
+                    Some((place, at, left)) if at == started && left > 0 => pass_wait.set(Some((place, at, left - 1))),
+                    _ => return,
+                }
+            }
+        });
+    };
+    let mut skip_player = move || {
+        name_check_input.set(String::new());
+        if !revisiting {
+            skipped_players.write().push(actual_player_index);
+        }
+        game_screen.set(GameScreen::CardView {
+            current_player_index: if revisiting { total_players } else { current_player_index + 1 }
+        });
+    };
+
+    // In a solo game the app takes the other seats' turns; their cards are never shown
+    if solo() && actual_player_index != SOLO_SEAT {
+        // Only moves on if nobody has already (the button and the timer race)
+        let mut pass_on = move || {
+            if *game_screen.peek() == (GameScreen::CardView { current_player_index }) {
+                game_screen.set(GameScreen::CardView { current_player_index: current_player_index + 1 });
+            }
+        };
+        return rsx! {
+            div { class: "card-view-screen",
+                div {
+                    key: "{current_player_index}",
+                    class: "simulated-turn",
+                    role: "status",
+                    style: "--player-color: {current_player.color}",
+                    onmounted: move |_| {
+                        spawn(async move {
+                            sleep_ms(SIMULATED_TURN_MS).await;
+                            pass_on();
+                        });
+                    },
+                    h2 { {(t.simulated_turn)(&current_player.name)} }
+                    button {
+                        class: "next-btn",
+                        onclick: move |_| pass_on(),
+                        "{t.next_player}"
+                    }
+                }
+            }
+        };
+    }
 
     rsx! {
         div { class: "card-view-screen",
+            // Space reveals the card, then passes on to the next player (not from the name check, which keeps its keys)
+            onkeydown: move |evt| {
+                if show_print_sheet() || evt.key() != Key::Character(" ".to_string()) {
+                    return;
+                }
+                evt.prevent_default();
+                if card_revealed() {
+                    next_player();
+                } else if reveal_unlocked {
+                    reveal_card();
+                }
+            },
+            div { class: "card-progress",
+                p { class: "card-progress-label", {(t.card_progress)(seen + 1, total_players)} }
+                div {
+                    class: "card-progress-track",
+                    role: "progressbar",
+                    "aria-valuemin": "0",
+                    "aria-valuemax": "{total_players}",
+                    "aria-valuenow": "{seen}",
+                    div { class: "card-progress-fill", style: "width: {progress_percent}%" }
+                }
+            }
+            if show_print_sheet() {
+                PrintCardsSheet {
+                    players: player_list.clone(),
+                    cards: cards(),
+                    imposter_index: imposter_index(),
+                    lang: settings().lang,
+                    on_close: move |_| show_print_sheet.set(false),
+                }
+            }
+            
             if !card_revealed() {
                 div { class: "player-ready-screen",
-                    h2 { "Pass device to:" }
+                    // A fresh screen for every hand-off, so each one starts its own pause
+                    key: "{current_player_index}",
+                    style: "--player-color: {current_player.color}",
+                    tabindex: "-1",
+                    // With the name check on, the letter field takes focus instead
+                    onmounted: move |evt| {
+                        start_pass_wait();
+                        if !name_check_required {
+                            focus_on_mount(evt);
+                        }
+                    },
+                    h2 { "{t.pass_device_to}" }
                     h1 { class: "player-name", "{current_player.name}" }
-                    p { class: "instruction", "⚠️ Make sure other players can't see the screen!" }
+                    if random_category {
+                        if let Some((cat_name, cat_icon)) = current_category() {
+                            p { class: "round-theme", {(t.round_theme)(&cat_icon, &cat_name)} }
+                        }
+                    }
+                    if twist_active() {
+                        p { class: "twist-banner", role: "note", "{t.twist_banner}" }
+                    }
+                    p { class: "instruction", "{t.keep_screen_private}" }
+                    
+                    if name_check_required {
+                        div { class: "name-check",
+                            label { r#for: "name-check-input", "{t.name_check_prompt}" }
+                            input {
+                                id: "name-check-input",
+                                r#type: "text",
+                                maxlength: "1",
+                                autocomplete: "off",
+                                value: "{name_check_input}",
+                                onmounted: focus_on_mount,
+                                oninput: move |e| name_check_input.set(e.value()),
+                                // Keys typed here are the player's answer, never the screen's Space shortcut
+                                onkeydown: move |e| {
+                                    e.stop_propagation();
+                                    if e.key() == Key::Enter && reveal_unlocked {
+                                        reveal_card();
+                                    }
+                                },
+                            }
+                        }
+                    }
                     
                     div { class: "ready-screen-buttons",
-                        // Show back button only for first player
-                        if current_player_index == 0 {
+                        // Show back button only for first player (rounds without a chosen category have no screen to return to)
+                        if first_in_line && !random_category && !location_mode && !custom_words {
                             button {
                                 class: "back-btn",
                                 onclick: move |_| {
@@ -107,69 +333,183 @@ pub fn CardViewScreen(
                                         });
                                     }
                                 },
-                                "← Back"
+                                "{t.back}"
                             }
                         }
                         
                         button {
                             class: "reveal-btn",
-                            onclick: move |_| {
-                                card_revealed.set(true);
-                            },
-                            "Reveal My Card"
+                            disabled: !reveal_unlocked,
+                            onclick: move |_| reveal_card(),
+                            "{t.reveal_my_card}"
+                        }
+                    }
+                    if let Some(left) = get_ready_left {
+                        p { class: "pass-countdown", role: "timer", {(t.get_ready_in)(left)} }
+                    }
+                    button {
+                        class: "skip-btn",
+                        onclick: move |_| skip_player(),
+                        "{t.skip_for_now}"
+                    }
+                    p { class: "keyboard-hint", "{t.reveal_key_hint}" }
+                    
+                    if first_in_line {
+                        if let Some(deal) = sealed_deal() {
+                            p { class: "sealed-deal-code", {(t.sealed_deal_code)(commitment_code(&deal.commitment))} }
+                        }
+                    }
+
+                    // Print-and-play: only offered before anyone has looked at a card, and
+                    // never for a blind deal, which has no full hand to print
+                    if first_in_line && !solo() && sealed_deal().is_none() {
+                        button {
+                            class: "print-cards-btn",
+                            onclick: move |_| show_print_sheet.set(true),
+                            "{t.print_cards}"
                         }
                     }
                 }
             } else {
                 div { class: "card-revealed-screen",
-                    h2 { "{current_player.name}'s Card" }
+                    tabindex: "-1",
+                    onmounted: focus_on_mount,
+                    if show_swap_nudge() {
+                        p { class: "seat-swap-nudge", role: "status",
+                            "{t.seat_swap_nudge}"
+                        }
+                    }
+                    h2 { {(t.players_card)(&current_player.name)} }
                     
-                    div { 
-                        class: if should_hide_imposter {
-                            "game-card normal-card"
-                        } else if is_imposter {
-                            "game-card imposter-card"
-                        } else {
-                            "game-card normal-card"
-                        },
-                        div { class: "card-word",
-                            "{displayed_word}"
+                    if word_hidden() {
+                        div { class: "game-card hidden-card",
+                            div { class: "card-word", "🂠" }
+                            div { class: "card-type-hint", "{t.card_hidden}" }
                         }
-                        if !hard_mode_enabled {
-                            div { class: "card-type-hint",
-                                if should_hide_imposter {
-                                    "👥 You are a civilian"
-                                } else if is_imposter {
-                                    "🎭 You are the IMPOSTER!"
-                                } else {
-                                    "👥 You are a civilian"
-                                }
-                            }
+                    } else {
+                        PlayerCard {
+                            card: current_card.clone(),
+                            location_mode,
+                            hide_imposter_identity: hard_mode_enabled,
+                            show_tip: settings().show_tips,
+                            lang: settings().lang,
                         }
                     }
                     
-                    p { class: "card-instruction",
-                        if should_hide_imposter {
-                            "Find the player with the different word!"
-                        } else if is_imposter {
-                            "Try to blend in! Don't let others know you have the odd word."
-                        } else {
-                            "Find the player with the different word!"
-                        }
+                    // Hiding and re-showing never moves on; only Next Player does
+                    button {
+                        class: "peek-btn",
+                        "aria-pressed": "{word_hidden()}",
+                        onclick: move |_| word_hidden.set(!word_hidden()),
+                        if word_hidden() { "{t.show_card_again}" } else { "{t.hide_card}" }
                     }
                     
                     button {
                         class: "next-btn",
-                        onclick: move |_| {
-                            card_revealed.set(false);
-                            game_screen.set(GameScreen::CardView {
-                                current_player_index: current_player_index + 1
-                            });
-                        },
-                        "Next Player"
+                        onclick: move |_| next_player(),
+                        "{t.next_player}"
+                    }
+                    if let Some(left) = reveal_countdown() {
+                        p { class: "reveal-countdown", role: "timer", {(t.auto_next_in)(left)} }
                     }
+                    p { class: "keyboard-hint", "{t.pass_on_key_hint}" }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::persistence::{parse_saved_game, prepare_resume};
+
+    /// Three players with Coffee/Tea dealt, handing off at the given place in line
+    fn dealt(current_player_index: usize, solo: bool) -> GameState {
+        GameState {
+            game_screen: GameScreen::CardView { current_player_index },
+            players: named_players(&["Ana", "Ben", "Cleo"]),
+            cards: (0..3)
+                .map(|i| GameCard {
+                    card_type: if i == 2 { CardType::Imposter } else { CardType::Normal },
+                    word: if i == 2 { "Tea".to_string() } else { "Coffee".to_string() },
+                    role: None,
+                    hint: None,
+                })
+                .collect(),
+            imposter_index: 2,
+            solo,
+            ..GameState::default()
+        }
+    }
+
+    fn render(state: GameState) -> String {
+        let mut dom = VirtualDom::new_with_props(
+            |state: GameState| {
+                let GameScreen::CardView { current_player_index } = state.game_screen else {
+                    panic!("not on the card view: {:?}", state.game_screen);
+                };
+                rsx! {
+                    CardViewScreen {
+                        current_player_index,
+                        players: use_signal(|| state.players.clone()),
+                        cards: use_signal(|| state.cards.clone()),
+                        imposter_index: use_signal(|| state.imposter_index),
+                        game_screen: use_signal(|| state.game_screen.clone()),
+                        current_category: use_signal(|| None::<(String, String)>),
+                        selected_category_index: use_signal(|| None::<usize>),
+                        starting_player_index: use_signal(|| 0usize),
+                        settings: use_signal(|| state.settings.clone()),
+                        skipped_players: use_signal(Vec::<usize>::new),
+                        turn_order: use_signal(Vec::<usize>::new),
+                        solo: use_signal(|| state.solo),
+                        sealed_deal: use_signal(|| state.sealed_deal.clone()),
+                        twist_active: use_signal(|| state.twist_active),
+                        on_deal: |_| {},
+                        on_all_seen: |_| {},
+                    }
+                }
+            },
+            state,
+        );
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn reload_mid_reveal_returns_to_the_ready_screen() {
+        // Saved while the second player's card was face up
+        let json = serde_json::to_string(&dealt(1, false)).unwrap();
+        let html = render(prepare_resume(parse_saved_game(&json).unwrap()));
+        assert!(html.contains("player-ready-screen"), "not on the ready screen: {html}");
+        assert!(html.contains("Ben"), "wrong player up: {html}");
+        assert!(!html.contains("Coffee") && !html.contains("Tea"), "a word is showing: {html}");
+    }
+
+    #[test]
+    fn later_hand_offs_wait_before_the_reveal_unlocks() {
+        let with_delay = |place| GameState {
+            settings: Settings { pass_delay_secs: Some(3), ..Settings::default() },
+            ..dealt(place, false)
+        };
+        let second = render(with_delay(1));
+        assert!(second.contains("Get ready… 3"), "no countdown: {second}");
+        assert!(second.contains(r#"class="reveal-btn" disabled"#), "reveal is unlocked: {second}");
+
+        // The first player follows nobody's reveal, and without the setting nobody waits
+        let first = render(with_delay(0));
+        assert!(!first.contains("Get ready") && !first.contains(r#"class="reveal-btn" disabled"#), "{first}");
+        let plain = render(dealt(1, false));
+        assert!(!plain.contains("Get ready") && !plain.contains(r#"class="reveal-btn" disabled"#), "{plain}");
+    }
+
+    #[test]
+    fn solo_games_pass_the_other_seats_automatically() {
+        let simulated = render(dealt(1, true));
+        assert!(simulated.contains("simulated-turn"), "simulated seat waits for a reveal: {simulated}");
+        assert!(!simulated.contains("reveal-btn"), "{simulated}");
+        assert!(!simulated.contains("Coffee") && !simulated.contains("Tea"), "a word is showing: {simulated}");
+
+        let mine = render(dealt(0, true));
+        assert!(mine.contains("player-ready-screen"), "the real player can't reveal: {mine}");
+    }
+}
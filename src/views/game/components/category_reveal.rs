@@ -1,21 +1,24 @@
 use dioxus::prelude::*;
 use crate::views::game::types::*;
+use crate::views::game::i18n::ui_text;
+use crate::views::game::utils::focus_on_mount;
 
 #[component]
 pub fn CategoryRevealScreen(
     category_name: String,
     category_icon: String,
     mut game_screen: Signal<GameScreen>,
-    mut hide_imposter_identity: Signal<bool>,
+    mut settings: Signal<Settings>,
 ) -> Element {
+    let t = ui_text(settings().lang);
     rsx! {
         div { class: "category-reveal-screen",
             div { class: "category-card",
                 div { class: "category-icon", "{category_icon}" }
-                h1 { class: "category-title", "Category" }
+                h1 { class: "category-title", "{t.category_title}" }
                 h2 { class: "category-name", "{category_name}" }
                 p { class: "category-hint", 
-                    "All players will receive words related to this category"
+                    "{t.category_hint}"
                 }
                 
                 // Hard Mode Toggle
@@ -24,20 +27,20 @@ pub fn CategoryRevealScreen(
                         input {
                             r#type: "checkbox",
                             class: "toggle-checkbox",
-                            checked: hide_imposter_identity(),
+                            checked: settings().hide_imposter_identity,
                             oninput: move |evt| {
-                                hide_imposter_identity.set(evt.value() == "true");
+                                settings.write().hide_imposter_identity = evt.value() == "true";
                             }
                         }
                         span { class: "toggle-text",
-                            "🔥 Hard Mode"
+                            "{t.hard_mode}"
                         }
                     }
                     p { class: "hard-mode-description",
-                        if hide_imposter_identity() {
-                            "The imposter won't know they're the imposter!"
+                        if settings().hide_imposter_identity {
+                            "{t.hard_mode_on}"
                         } else {
-                            "Enable to hide the imposter's identity from themselves"
+                            "{t.hard_mode_off}"
                         }
                     }
                 }
@@ -48,15 +51,16 @@ pub fn CategoryRevealScreen(
                         onclick: move |_| {
                             game_screen.set(GameScreen::CategorySelection);
                         },
-                        "← Back to Categories"
+                        "{t.back_to_categories}"
                     }
                     
                     button {
                         class: "continue-btn",
+                        onmounted: focus_on_mount,
                         onclick: move |_| {
                             game_screen.set(GameScreen::CardView { current_player_index: 0 });
                         },
-                        "▶️ Start Round"
+                        "{t.start_round}"
                     }
                 }
             }
@@ -1,24 +1,28 @@
 use dioxus::prelude::*;
-use crate::views::game::{types::*, utils::get_all_categories};
+use crate::views::game::{i18n::ui_text, types::*, utils::{category_indices_for_pack, get_all_categories}};
 
 #[component]
 pub fn CategorySelectionScreen(
     mut game_screen: Signal<GameScreen>,
     mut selected_category_index: Signal<Option<usize>>,
+    settings: Signal<Settings>,
 ) -> Element {
     // Use a signal to store categories so they live long enough
-    let categories = use_signal(|| get_all_categories());
+    let categories = use_signal(get_all_categories);
     let cats = categories.read();
+    // Only offer the categories in the selected word pack
+    let pack_indices = category_indices_for_pack(settings().word_pack.as_deref());
+    let t = ui_text(settings().lang);
     
     rsx! {
         div { class: "category-selection-screen",
             div { class: "selection-header",
-                h1 { "🎯 Choose Your Category" }
-                p { class: "selection-subtitle", "Pick a theme for this round" }
+                h1 { "{t.choose_category}" }
+                p { class: "selection-subtitle", "{t.choose_category_subtitle}" }
             }
             
             div { class: "categories-grid",
-                for (index, category) in cats.iter().enumerate() {
+                for (index, category) in cats.iter().enumerate().filter(|(i, _)| pack_indices.contains(i)) {
                     {
                         let cat_name = category.name.clone();
                         let cat_icon = category.icon.clone();
@@ -36,7 +40,7 @@ pub fn CategorySelectionScreen(
                                 div { class: "category-icon-large", "{cat_icon}" }
                                 h3 { class: "category-name-selectable", "{cat_name}" }
                                 p { class: "category-pairs-count", 
-                                    "{category.pairs.len()} word pairs" 
+                                    {(t.word_pair_count)(category.pairs.len())}
                                 }
                             }
                         }
@@ -0,0 +1,21 @@
+use dioxus::prelude::*;
+
+// Embedded at compile time so nothing has to be fetched when a round ends
+const CELEBRATION_JS: &str = include_str!("../../../../assets/scripts/celebration.js");
+
+/// Canvas confetti overlay played once when a round is won
+#[component]
+pub fn Celebration(imposter_won: bool) -> Element {
+    use_effect(move || {
+        let style = if imposter_won { "imposter" } else { "civilians" };
+        let _ = document::eval(&format!("{CELEBRATION_JS}(\"{style}\");"));
+    });
+
+    rsx! {
+        canvas {
+            id: "celebration-canvas",
+            class: "celebration-canvas",
+            "aria-hidden": "true",
+        }
+    }
+}
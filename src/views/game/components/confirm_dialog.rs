@@ -0,0 +1,78 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::utils::focus_on_mount;
+
+/// Modal asking the group to confirm something that can't be undone.
+/// Cancel takes focus so a stray Enter never confirms; Escape also cancels.
+#[component]
+pub fn ConfirmDialog(
+    title: String,
+    message: String,
+    confirm_label: String,
+    lang: Lang,
+    on_confirm: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let t = ui_text(lang);
+
+    rsx! {
+        div {
+            class: "confirmation-overlay",
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape {
+                    on_cancel.call(());
+                }
+            },
+            div {
+                class: "confirmation-dialog",
+                role: "alertdialog",
+                "aria-modal": "true",
+                "aria-labelledby": "confirm-dialog-title",
+                "aria-describedby": "confirm-dialog-message",
+                h2 { id: "confirm-dialog-title", "⚠️ {title}" }
+                p { id: "confirm-dialog-message", "{message}" }
+                div { class: "confirmation-buttons",
+                    button {
+                        class: "confirm-yes-btn",
+                        onclick: move |_| on_confirm.call(()),
+                        "{confirm_label}"
+                    }
+                    button {
+                        class: "confirm-no-btn",
+                        onmounted: focus_on_mount,
+                        onclick: move |_| on_cancel.call(()),
+                        "{t.cancel}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_message_and_both_choices() {
+        fn app() -> Element {
+            rsx! {
+                ConfirmDialog {
+                    title: "Start New Game?",
+                    message: "Current scores will be lost.",
+                    confirm_label: "Yes, Start New Game",
+                    lang: Lang::English,
+                    on_confirm: |_| {},
+                    on_cancel: |_| {},
+                }
+            }
+        }
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+        assert!(html.contains("Current scores will be lost."), "missing message: {html}");
+        assert!(html.contains("Yes, Start New Game"), "missing confirm button: {html}");
+        assert!(html.contains("Cancel"), "missing cancel button: {html}");
+        assert!(html.contains("role=\"alertdialog\""), "dialog should be announced: {html}");
+    }
+}
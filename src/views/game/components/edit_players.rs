@@ -0,0 +1,134 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
+use crate::views::game::types::*;
+use crate::views::game::utils::{duplicate_name_indices, next_free_color, normalize_name, reseat_imposter_counts, round_start_screen, MAX_PLAYERS, MIN_PLAYERS};
+
+/// Between rounds: add newcomers or remove players who left, keeping everyone else's score
+#[component]
+pub fn EditPlayersScreen(
+    mut players: Signal<Vec<Player>>,
+    mut player_names: Signal<Vec<String>>,
+    mut player_count_input: Signal<String>,
+    mut game_screen: Signal<GameScreen>,
+    mut round_number: Signal<i32>,
+    mut cards: Signal<Vec<GameCard>>,
+    mut imposter_index: Signal<usize>,
+    mut starting_player_index: Signal<usize>,
+    mut history: Signal<Vec<usize>>,
+    mut times_imposter: Signal<Vec<i32>>,
+    settings: Signal<Settings>,
+) -> Element {
+    let mut roster = use_signal(|| players.peek().clone());
+    // Each row's seat before editing (None for newcomers), so per-seat counts follow their player
+    let mut old_seats = use_signal(|| (0..players.peek().len()).map(Some).collect::<Vec<_>>());
+    let mut new_name = use_signal(String::new);
+
+    let names: Vec<String> = roster().iter().map(|p| p.name.clone()).collect();
+    let duplicates = duplicate_name_indices(&names);
+    let count = roster().len();
+    let can_add = count < MAX_PLAYERS && !normalize_name(&new_name()).is_empty();
+    let can_save = count >= MIN_PLAYERS && duplicates.is_empty();
+    let t = ui_text(settings().lang);
+
+    let mut add_player = move || {
+        let name = normalize_name(&new_name());
+        if name.is_empty() || roster().len() >= MAX_PLAYERS {
+            return;
+        }
+        let color = next_free_color(&roster());
+        roster.write().push(Player { name, score: 0, is_eliminated: false, color, last_round_delta: 0 });
+        old_seats.write().push(None);
+        new_name.set(String::new());
+    };
+
+    rsx! {
+        div { class: "edit-players-screen",
+            h1 { "{t.edit_players}" }
+            p { class: "hint", "{t.edit_players_hint}" }
+
+            ul { class: "edit-players-list",
+                for (i, player) in roster().into_iter().enumerate() {
+                    li {
+                        key: "{i}-{player.name}",
+                        class: if duplicates.contains(&i) { "edit-player-row duplicate-name" } else { "edit-player-row" },
+                        style: "--player-color: {player.color}",
+                        span { class: "edit-player-name", "{player.name}" }
+                        span { class: "edit-player-score", {(t.points_short)(player.score)} }
+                        button {
+                            class: "remove-player-btn",
+                            "aria-label": (t.remove_player)(&player.name),
+                            disabled: count <= MIN_PLAYERS,
+                            onclick: move |_| {
+                                roster.write().remove(i);
+                                old_seats.write().remove(i);
+                            },
+                            "✕"
+                        }
+                    }
+                }
+            }
+
+            if !duplicates.is_empty() {
+                p { class: "duplicate-names-warning", role: "alert",
+                    "{t.names_must_differ}"
+                }
+            }
+
+            div { class: "add-player-row",
+                input {
+                    r#type: "text",
+                    placeholder: "{t.new_player_placeholder}",
+                    value: "{new_name}",
+                    disabled: count >= MAX_PLAYERS,
+                    oninput: move |e| new_name.set(e.value()),
+                    onkeydown: move |e| {
+                        if e.key() == Key::Enter {
+                            add_player();
+                        }
+                    },
+                }
+                button {
+                    class: "add-player-btn",
+                    disabled: !can_add,
+                    onclick: move |_| add_player(),
+                    "{t.add_player}"
+                }
+            }
+            p { class: "hint", {(t.player_range)(MIN_PLAYERS, MAX_PLAYERS)} }
+
+            div { class: "action-buttons",
+                button {
+                    class: "next-round-btn",
+                    disabled: !can_save,
+                    onclick: move |_| {
+                        let mut updated_players = roster();
+                        for player in updated_players.iter_mut() {
+                            player.is_eliminated = false;
+                            player.last_round_delta = 0;
+                        }
+                        let player_count = updated_players.len();
+                        player_names.set(updated_players.iter().map(|p| p.name.clone()).collect());
+                        player_count_input.set(player_count.to_string());
+                        players.set(updated_players);
+                        times_imposter.set(reseat_imposter_counts(&times_imposter(), &old_seats()));
+
+                        // The old hand was dealt for a different roster, so deal fresh
+                        cards.set(Vec::new());
+                        imposter_index.set(0);
+                        history.set(Vec::new());
+                        round_number.set(round_number() + 1);
+                        starting_player_index.set((starting_player_index() + 1) % player_count);
+
+                        game_screen.set(round_start_screen(&settings()));
+                    },
+                    "{t.save_and_play_next_round}"
+                }
+                button {
+                    class: "new-game-btn",
+                    onclick: move |_| game_screen.set(GameScreen::GameScore),
+                    "{t.cancel}"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+
+/// Text marker for eliminated players so the status never relies on color alone
+#[component]
+pub fn EliminatedTag(lang: Lang) -> Element {
+    rsx! {
+        span { class: "eliminated-tag",
+            span { "aria-hidden": "true", "❌ " }
+            "{ui_text(lang).out_tag}"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_text_indicator() {
+        fn app() -> Element {
+            rsx! { EliminatedTag { lang: Lang::English } }
+        }
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+        assert!(html.contains("(out)"), "missing text indicator: {html}");
+        assert!(html.contains("aria-hidden=\"true\""), "icon should be hidden from screen readers: {html}");
+    }
+}
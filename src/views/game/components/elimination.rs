@@ -1,99 +1,168 @@
 use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
+use crate::views::game::sound::{self, Sound};
 use crate::views::game::types::*;
+use crate::views::game::components::{FlipCard, PlayerRoster, PrivateRoleCheck, RoundRecovery};
+use crate::views::game::game_engine::comeback_word;
+use crate::views::game::utils::{challenge_bonus, focus_on_mount, round_speed_bonus, CIVILIAN_WIN_POINTS};
 
-/// Screen showing elimination results
+/// Screen showing elimination results; `on_confirm` carries out the eviction
 #[component]
 pub fn EliminationScreen(
-    mut players: Signal<Vec<Player>>,
+    players: Signal<Vec<Player>>,
     eliminated_index: usize,
     was_imposter: bool,
     mut game_screen: Signal<GameScreen>,
-    mut round_number: Signal<i32>,
-    mut cards: Signal<Vec<GameCard>>,
-    imposter_index: Signal<usize>,
+    cards: Signal<Vec<GameCard>>,
+    current_round_words: Signal<Option<(String, String)>>,
+    settings: Signal<Settings>,
+    history: Signal<Vec<usize>>,
+    on_confirm: EventHandler<()>,
 ) -> Element {
+    use_hook(|| sound::play(Sound::Elimination, settings().sound_enabled));
     let player_list = players();
-    let eliminated_player = &player_list[eliminated_index];
+    let t = ui_text(settings().lang);
+    let Some(eliminated_player) = player_list.get(eliminated_index) else {
+        return rsx! { RoundRecovery { players, cards, game_screen, lang: settings().lang } };
+    };
     let active_count = player_list.iter().filter(|p| !p.is_eliminated).count();
+    let animate = settings().animations;
+    let bonus = round_speed_bonus(&settings(), history().len());
+    let challenge = challenge_bonus(&settings());
+    let single_vote = settings().single_vote_challenge;
+    let comeback = comeback_word(&settings(), current_round_words().as_ref()).is_some();
+
+    // Blind evictions: say who's out and nothing more, whoever they were
+    if !settings().reveal_role_on_eviction {
+        return rsx! {
+            div { class: "elimination-screen",
+                h1 { class: "blind-evicted", {(t.player_is_out)(&eliminated_player.name)} }
+                div { class: "elimination-result",
+                    // The challenge's one vote ends the round, whoever went out
+                    if single_vote {
+                        p { class: "result-message challenge-result", "{t.challenge_vote_cast}" }
+                    } else {
+                        p { class: "result-message", "{t.roles_stay_hidden}" }
+                        p { class: "players-remaining",
+                            {(t.players_remaining)(active_count - 1)}
+                        }
+                    }
+                }
+
+                PlayerRoster { players: player_list.clone(), leaving: Some(eliminated_index), lang: settings().lang }
+
+                if settings().eliminated_role_check {
+                    PrivateRoleCheck {
+                        player_name: eliminated_player.name.clone(),
+                        card: cards().get(eliminated_index).cloned(),
+                        lang: settings().lang,
+                    }
+                }
+
+                div { class: "action-buttons",
+                    button {
+                        class: "undo-btn",
+                        onclick: move |_| game_screen.set(GameScreen::Voting),
+                        "{t.undo}"
+                    }
+                    button {
+                        class: "continue-btn",
+                        onmounted: focus_on_mount,
+                        onclick: move |_| on_confirm.call(()),
+                        "{t.continue_label}"
+                    }
+                }
+            }
+        };
+    }
     
     rsx! {
-        div { class: "elimination-screen",
+        div { class: if animate { "elimination-screen with-flip" } else { "elimination-screen" },
+            // Flip the evicted player's card over to reveal their role
+            FlipCard {
+                key: "{eliminated_index}",
+                animate,
+                front: rsx! {
+                    span { class: "flip-card-name", "{eliminated_player.name}" }
+                },
+                back: rsx! {
+                    if was_imposter {
+                        span { class: "flip-card-icon", "🎭" }
+                        span { class: "flip-card-role imposter", "{t.imposter}" }
+                    } else {
+                        span { class: "flip-card-icon", "👥" }
+                        span { class: "flip-card-role civilian", "{t.civilian}" }
+                    }
+                },
+            }
+            
             // Show different emoji and message based on who was evicted
             if was_imposter {
-                h1 { class: "imposter-found", "🎉 Imposter Evicted!" }
+                h1 { class: "imposter-found", "{t.imposter_evicted}" }
                 div { class: "elimination-result celebration",
                     p { class: "eliminated-player success",
-                        "🎊 {eliminated_player.name} was the IMPOSTER!"
+                        {(t.was_the_imposter)(&eliminated_player.name)}
                     }
                     p { class: "result-message",
-                        "🏆 Civilians win this round!"
+                        "{t.civilians_win_round}"
                     }
-                    p { class: "players-remaining",
-                        "Remaining civilians receive +10 points"
+                    if comeback {
+                        p { class: "players-remaining", "{t.comeback_pending}" }
+                    } else {
+                        p { class: "players-remaining",
+                            if bonus > 0 {
+                                {(t.civilian_reward_with_bonus)(CIVILIAN_WIN_POINTS + bonus + challenge, bonus)}
+                            } else {
+                                {(t.civilian_reward)(CIVILIAN_WIN_POINTS + challenge)}
+                            }
+                        }
+                        if single_vote {
+                            p { class: "challenge-result", {(t.single_vote_bonus)(challenge)} }
+                        }
                     }
                 }
             } else {
-                h1 { class: "civilian-evicted", "😔 Civilian Evicted" }
+                h1 { class: "civilian-evicted", "{t.civilian_evicted}" }
                 div { class: "elimination-result disappointment",
                     p { class: "eliminated-player failure",
-                        "💔 {eliminated_player.name} was a CIVILIAN"
-                    }
-                    p { class: "result-message",
-                        "😈 The imposter remains among you..."
+                        {(t.was_a_civilian)(&eliminated_player.name)}
                     }
-                    p { class: "players-remaining",
-                        "{active_count - 1} players remaining"
+                    // The challenge allows one vote, so a wrong one hands the round over
+                    if single_vote {
+                        p { class: "result-message challenge-result", "{t.challenge_lost}" }
+                    } else {
+                        p { class: "result-message",
+                            "{t.imposter_remains}"
+                        }
+                        p { class: "players-remaining",
+                            {(t.players_remaining)(active_count - 1)}
+                        }
                     }
                 }
             }
             
+            PlayerRoster { players: player_list.clone(), leaving: Some(eliminated_index), lang: settings().lang }
+
+            if settings().eliminated_role_check {
+                PrivateRoleCheck {
+                    player_name: eliminated_player.name.clone(),
+                    card: cards().get(eliminated_index).cloned(),
+                    lang: settings().lang,
+                }
+            }
+            
             div { class: "action-buttons",
+                // Nothing has been changed yet, so a misclick can simply go back to the vote
+                button {
+                    class: "undo-btn",
+                    onclick: move |_| game_screen.set(GameScreen::Voting),
+                    "{t.undo}"
+                }
                 button {
                     class: "continue-btn",
-                    onclick: move |_| {
-                        let mut updated_players = players();
-                        // Eliminate the player
-                        updated_players[eliminated_index].is_eliminated = true;
-                        
-                        // Check if imposter was eliminated
-                        if was_imposter {
-                            // Imposter found - civilians win!
-                            for (i, player) in updated_players.iter_mut().enumerate() {
-                                // Award only active civilians; evicted players get nothing
-                                if i != imposter_index() && !player.is_eliminated {
-                                    player.score += 10;
-                                }
-                            }
-                            players.set(updated_players);
-                            game_screen.set(GameScreen::RoundEnd { 
-                                imposter_found: true,
-                                game_over: true 
-                            });
-                        } else {
-                            // Check if only 2 players remain
-                            let remaining_count = updated_players.iter()
-                                .filter(|p| !p.is_eliminated)
-                                .count();
-                            
-                            players.set(updated_players);
-                            
-                            if remaining_count <= 2 {
-                                // Imposter wins!
-                                let mut final_players = players();
-                                final_players[imposter_index()].score += 20;
-                                players.set(final_players);
-                                game_screen.set(GameScreen::RoundEnd { 
-                                    imposter_found: false,
-                                    game_over: true 
-                                });
-                            } else {
-                                // Continue to next voting round
-                                round_number.set(round_number() + 1);
-                                game_screen.set(GameScreen::Voting);
-                            }
-                        }
-                    },
-                    "Continue"
+                    onmounted: focus_on_mount,
+                    onclick: move |_| on_confirm.call(()),
+                    "{t.continue_label}"
                 }
             }
         }
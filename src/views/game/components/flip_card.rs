@@ -0,0 +1,15 @@
+use dioxus::prelude::*;
+
+/// A card that flips from its front face to its back face.
+/// With `animate` off (or under prefers-reduced-motion) the back face is shown instantly.
+#[component]
+pub fn FlipCard(front: Element, back: Element, animate: bool) -> Element {
+    rsx! {
+        div { class: if animate { "flip-card animate" } else { "flip-card instant" },
+            div { class: "flip-card-inner",
+                div { class: "flip-card-face flip-card-front", "aria-hidden": "true", {front} }
+                div { class: "flip-card-face flip-card-back", {back} }
+            }
+        }
+    }
+}
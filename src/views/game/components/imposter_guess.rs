@@ -0,0 +1,55 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
+use crate::views::game::types::*;
+use crate::views::game::utils::focus_on_mount;
+
+/// The evicted imposter's one guess at the civilian word (comeback rule).
+/// The word itself never appears here; `on_guess` gets whatever was typed, empty for no guess.
+#[component]
+pub fn ImposterGuessScreen(
+    players: Signal<Vec<Player>>,
+    imposter_index: Signal<usize>,
+    settings: Signal<Settings>,
+    on_guess: EventHandler<String>,
+) -> Element {
+    let t = ui_text(settings().lang);
+    let imposter_name = players().get(imposter_index())
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| t.unknown_player.to_string());
+    let mut guess = use_signal(String::new);
+
+    rsx! {
+        div { class: "imposter-guess-screen",
+            h1 { {(t.imposter_guess_heading)(&imposter_name)} }
+            p { class: "hint", "{t.imposter_guess_prompt}" }
+            form {
+                class: "imposter-guess-form",
+                onsubmit: move |evt| {
+                    evt.prevent_default();
+                    on_guess.call(guess());
+                },
+                label { class: "sr-only", r#for: "imposter-guess-input", "{t.imposter_guess_label}" }
+                input {
+                    id: "imposter-guess-input",
+                    r#type: "text",
+                    autocomplete: "off",
+                    placeholder: "{t.imposter_guess_label}",
+                    value: "{guess}",
+                    onmounted: focus_on_mount,
+                    oninput: move |e| guess.set(e.value()),
+                }
+                button {
+                    class: "continue-btn",
+                    r#type: "submit",
+                    disabled: guess().trim().is_empty(),
+                    "{t.lock_in_guess}"
+                }
+            }
+            button {
+                class: "back-btn",
+                onclick: move |_| on_guess.call(String::new()),
+                "{t.no_guess}"
+            }
+        }
+    }
+}
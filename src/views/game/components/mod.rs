@@ -3,16 +3,56 @@ pub mod category_selection;
 pub mod category_reveal;
 pub mod card_view;
 pub mod voting;
+pub mod secret_voting;
+pub mod vote_reveal;
+pub mod tie_break;
 pub mod elimination;
+pub mod imposter_guess;
 pub mod round_end;
 pub mod score;
+pub mod stats;
+pub mod round_history;
+pub mod replay;
+pub mod edit_players;
+pub mod round_recovery;
+pub mod settings;
+pub mod role_check;
+pub mod celebration;
+pub mod flip_card;
+pub mod print_cards;
+pub mod eliminated_tag;
+pub mod confirm_dialog;
+pub mod player_card;
+pub mod word_reminder;
+pub mod moderator;
+pub mod roster;
 
 pub use setup::SetupScreen;
 pub use category_selection::CategorySelectionScreen;
 pub use category_reveal::CategoryRevealScreen;
 pub use card_view::CardViewScreen;
 pub use voting::VotingScreen;
+pub use secret_voting::SecretVotingScreen;
+pub use vote_reveal::VoteRevealScreen;
+pub use tie_break::TieBreakScreen;
 pub use elimination::EliminationScreen;
+pub use imposter_guess::ImposterGuessScreen;
 pub use round_end::RoundEndScreen;
 pub use score::GameScoreScreen;
+pub use stats::StatsScreen;
+pub use round_history::RoundHistoryScreen;
+pub use replay::ReplayScreen;
+pub use edit_players::EditPlayersScreen;
+pub use round_recovery::RoundRecovery;
+pub use settings::{SettingToggle, SettingsPanel};
+pub use role_check::PrivateRoleCheck;
+pub use celebration::Celebration;
+pub use flip_card::FlipCard;
+pub use print_cards::PrintCardsSheet;
+pub use eliminated_tag::EliminatedTag;
+pub use confirm_dialog::ConfirmDialog;
+pub use player_card::PlayerCard;
+pub use word_reminder::WordReminder;
+pub use moderator::ModeratorPanel;
+pub use roster::PlayerRoster;
 
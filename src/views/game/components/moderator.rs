@@ -0,0 +1,94 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::*;
+use crate::views::game::utils::now_ms;
+
+/// How long the moderator has to hold the button before the words show
+pub const MODERATOR_HOLD_MS: f64 = 1500.0;
+
+/// Both words and who has the imposter card, for a moderator who isn't playing.
+/// Opens only on a long press, so a quick tap by a player on the way past does nothing.
+/// From the keyboard, Space or Enter has to be held just as long.
+#[component]
+pub fn ModeratorPanel(players: Vec<Player>, cards: Vec<GameCard>, imposter_index: usize, lang: Lang) -> Element {
+    let t = ui_text(lang);
+    let mut open = use_signal(|| false);
+    // When the current press started; lifting the finger early or sliding off cancels it
+    let mut pressed_at = use_signal(|| None::<f64>);
+    // A press long enough opens the panel when it ends, however it was made
+    let mut release = move || {
+        if pressed_at.take().is_some_and(|at| now_ms() - at >= MODERATOR_HOLD_MS) {
+            open.set(true);
+        }
+    };
+    let is_hold_key = |key: Key| key == Key::Enter || key == Key::Character(" ".to_string());
+    let civilian_word = cards.iter().find(|c| c.card_type == CardType::Normal).map(|c| c.word.clone()).unwrap_or_default();
+    let imposter_word = cards.get(imposter_index).map(|c| c.word.clone()).unwrap_or_default();
+    let imposter_name = players.get(imposter_index).map(|p| p.name.clone()).unwrap_or_else(|| t.unknown_player.to_string());
+
+    rsx! {
+        div { class: "moderator-panel",
+            if open() {
+                div { class: "role-check-panel moderator-sheet",
+                    dl {
+                        dt { "{t.moderator_civilian_word}" }
+                        dd { "{civilian_word}" }
+                        dt { "{t.moderator_imposter_word}" }
+                        dd { if imposter_word.is_empty() { "—" } else { "{imposter_word}" } }
+                        dt { "{t.moderator_imposter}" }
+                        dd { class: "moderator-imposter", "{imposter_name}" }
+                    }
+                    button {
+                        class: "next-btn",
+                        onclick: move |_| open.set(false),
+                        "{t.moderator_hide}"
+                    }
+                }
+            } else {
+                button {
+                    class: if pressed_at().is_some() { "moderator-btn holding" } else { "moderator-btn" },
+                    style: "--hold-ms: {MODERATOR_HOLD_MS}ms",
+                    onpointerdown: move |_| pressed_at.set(Some(now_ms())),
+                    onpointerup: move |_| release(),
+                    onpointerleave: move |_| pressed_at.set(None),
+                    // A held key repeats its keydown, so only the first one starts the timer
+                    onkeydown: move |evt| {
+                        if is_hold_key(evt.key()) {
+                            evt.prevent_default();
+                            if pressed_at.peek().is_none() {
+                                pressed_at.set(Some(now_ms()));
+                            }
+                        }
+                    },
+                    onkeyup: move |evt| {
+                        if is_hold_key(evt.key()) {
+                            release();
+                        }
+                    },
+                    onblur: move |_| pressed_at.set(None),
+                    "{t.moderator_hold}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_stay_hidden_until_the_long_press() {
+        fn app() -> Element {
+            let players = named_players(&["Alex", "Dana", "Sam"]);
+            let card = |card_type, word: &str| GameCard { card_type, word: word.to_string(), role: None, hint: None };
+            let cards = vec![card(CardType::Normal, "Sun"), card(CardType::Imposter, "Moon"), card(CardType::Normal, "Sun")];
+            rsx! { ModeratorPanel { players, cards, imposter_index: 1, lang: Lang::English } }
+        }
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+        assert!(html.contains("moderator-btn"), "hold button missing: {html}");
+        assert!(!html.contains("Sun") && !html.contains("Moon") && !html.contains("Dana"), "words leaked: {html}");
+    }
+}
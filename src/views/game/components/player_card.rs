@@ -0,0 +1,135 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::*;
+
+/// Face of a player's secret card with its instruction line.
+/// Shared by the first reveal and later word reminders so both always show the same thing.
+/// `show_tip` adds a one-line strategy tip for the role the player can see they have.
+#[component]
+pub fn PlayerCard(card: GameCard, location_mode: bool, hide_imposter_identity: bool, show_tip: bool, lang: Lang) -> Element {
+    let t = ui_text(lang);
+    let is_imposter = card.card_type == CardType::Imposter;
+    let should_hide_imposter = hide_imposter_identity && is_imposter;
+    // The spy in location mode has nothing to hide behind, so always tell them
+    let is_spy = location_mode && is_imposter;
+    // No-word and category-hint modes hand the imposter a blank card (the latter with a hint)
+    let has_no_word = !location_mode && is_imposter && card.word.is_empty();
+    // An imposter kept in the dark by hard mode gets the civilian tip, or the tip would tell them
+    let knows_imposter = is_spy || has_no_word || (is_imposter && !hide_imposter_identity);
+    let tip = if knows_imposter { t.imposter_tip } else { t.civilian_tip };
+
+    rsx! {
+        if is_spy {
+            div { class: "game-card imposter-card",
+                div { class: "card-word", "{t.spy_card}" }
+                div { class: "card-type-hint", "{t.spy_hint}" }
+            }
+            
+            p { class: "card-instruction",
+                "{t.spy_instruction}"
+            }
+        } else if let (true, Some(hint)) = (has_no_word, card.hint.clone()) {
+            div { class: "game-card imposter-card",
+                div { class: "card-word", {(t.category_hint_card)(&hint)} }
+                div { class: "card-type-hint", "{t.category_hint_hint}" }
+            }
+            
+            p { class: "card-instruction",
+                "{t.category_hint_instruction}"
+            }
+        } else if has_no_word {
+            div { class: "game-card imposter-card",
+                div { class: "card-word", "❓❓❓" }
+                div { class: "card-type-hint", "{t.no_word_hint}" }
+            }
+            
+            p { class: "card-instruction",
+                "{t.no_word_instruction}"
+            }
+        } else {
+            div { 
+                class: if should_hide_imposter {
+                    "game-card normal-card"
+                } else if is_imposter {
+                    "game-card imposter-card"
+                } else {
+                    "game-card normal-card"
+                },
+                div { class: "card-word",
+                    if location_mode { "📍 " }
+                    "{card.word}"
+                }
+                if let Some(role) = card.role.clone() {
+                    div { class: "card-role", {(t.your_role)(&role)} }
+                }
+                if !hide_imposter_identity {
+                    div { class: "card-type-hint",
+                        if is_imposter {
+                            "{t.you_are_imposter}"
+                        } else {
+                            "{t.you_are_civilian}"
+                        }
+                    }
+                }
+            }
+            
+            p { class: "card-instruction",
+                if location_mode {
+                    "{t.location_instruction}"
+                } else if should_hide_imposter {
+                    "{t.find_odd_word}"
+                } else if is_imposter {
+                    "{t.imposter_instruction}"
+                } else {
+                    "{t.find_odd_word}"
+                }
+            }
+        }
+        if show_tip {
+            p { class: "role-tip", "{tip}" }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(card: GameCard, hide_imposter_identity: bool) -> String {
+        render_with_tip(card, hide_imposter_identity, false)
+    }
+
+    fn render_with_tip(card: GameCard, hide_imposter_identity: bool, show_tip: bool) -> String {
+        let mut dom = VirtualDom::new_with_props(
+            PlayerCard,
+            PlayerCardProps { card, location_mode: false, hide_imposter_identity, show_tip, lang: Lang::English },
+        );
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn hard_mode_keeps_the_imposter_unaware() {
+        let card = GameCard { card_type: CardType::Imposter, word: "Tea".to_string(), role: None, hint: None };
+        assert!(render(card.clone(), false).contains("IMPOSTER"));
+
+        let html = render(card, true);
+        assert!(html.contains("Tea"), "word should still show: {html}");
+        assert!(!html.contains("IMPOSTER"), "identity leaked in hard mode: {html}");
+    }
+
+    #[test]
+    fn tips_match_the_role_the_player_can_see() {
+        let civilian = GameCard { card_type: CardType::Normal, word: "Tea".to_string(), role: None, hint: None };
+        let imposter = GameCard { card_type: CardType::Imposter, ..civilian.clone() };
+        let civilian_tip = ui_text(Lang::English).civilian_tip;
+        let imposter_tip = ui_text(Lang::English).imposter_tip;
+
+        assert!(!render(civilian.clone(), false).contains(civilian_tip));
+        assert!(render_with_tip(civilian, false, true).contains(civilian_tip));
+        assert!(render_with_tip(imposter.clone(), false, true).contains(imposter_tip));
+        // Hard mode: the imposter doesn't know, so the tip mustn't tell them
+        let html = render_with_tip(imposter, true, true);
+        assert!(html.contains(civilian_tip) && !html.contains(imposter_tip), "tip leaked the role: {html}");
+    }
+}
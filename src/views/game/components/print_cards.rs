@@ -0,0 +1,79 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::*;
+
+/// Host-only printable sheet with every player's card for the current round.
+/// Shows all roles, so it sits behind an explicit warning step.
+#[component]
+pub fn PrintCardsSheet(
+    players: Vec<Player>,
+    cards: Vec<GameCard>,
+    imposter_index: usize,
+    lang: Lang,
+    on_close: EventHandler<()>,
+) -> Element {
+    let t = ui_text(lang);
+    let mut confirmed = use_signal(|| false);
+
+    rsx! {
+        div { class: "confirmation-overlay print-overlay",
+            if !confirmed() {
+                div { class: "confirmation-dialog",
+                    h2 { "{t.print_cards_title}" }
+                    p { "{t.print_cards_warning}" }
+                    div { class: "confirmation-buttons",
+                        button {
+                            class: "confirm-yes-btn",
+                            onclick: move |_| confirmed.set(true),
+                            "{t.host_show_cards}"
+                        }
+                        button {
+                            class: "confirm-no-btn",
+                            onclick: move |_| on_close.call(()),
+                            "{t.cancel}"
+                        }
+                    }
+                }
+            } else {
+                div { class: "print-sheet",
+                    div { class: "print-controls",
+                        button {
+                            class: "confirm-yes-btn",
+                            onclick: move |_| {
+                                let _ = document::eval("window.print();");
+                            },
+                            "{t.print}"
+                        }
+                        button {
+                            class: "confirm-no-btn",
+                            onclick: move |_| on_close.call(()),
+                            "{t.close}"
+                        }
+                    }
+                    div { class: "print-cards-grid",
+                        for (index, card) in cards.iter().enumerate() {
+                            div { key: "{index}", class: "print-card",
+                                p { class: "print-card-player",
+                                    {players.get(index).map(|p| p.name.clone()).unwrap_or_default()}
+                                }
+                                p { class: "print-card-word",
+                                    if let (true, Some(hint)) = (card.word.is_empty(), card.hint.clone()) {
+                                        {(t.category_hint_card)(&hint)}
+                                    } else if card.word.is_empty() {
+                                        "—"
+                                    } else {
+                                        "{card.word}"
+                                    }
+                                }
+                                p { class: "print-card-role",
+                                    if index == imposter_index { "{t.imposter_label}" } else { "{t.civilian_label}" }
+                                }
+                                p { class: "print-card-fold", "{t.fold_here}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
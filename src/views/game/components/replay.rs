@@ -0,0 +1,192 @@
+use dioxus::prelude::*;
+use crate::views::game::components::PlayerCard;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::{CardType, GameScreen, RoundRecord};
+
+/// One stop in the replay of a finished round
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum ReplayStep {
+    Card(usize), // A seat's card
+    Eviction(usize), // The nth eviction of the round
+    Outcome,
+}
+
+/// Every card in seat order, then each eviction in the order it happened, then who won
+fn replay_steps(record: &RoundRecord) -> Vec<ReplayStep> {
+    (0..record.seats.len())
+        .map(ReplayStep::Card)
+        .chain((0..record.evicted.len()).map(ReplayStep::Eviction))
+        .chain([ReplayStep::Outcome])
+        .collect()
+}
+
+/// Read-only walk through the last finished round, one step at a time
+#[component]
+pub fn ReplayScreen(
+    round_log: Signal<Vec<RoundRecord>>,
+    step: usize,
+    mut game_screen: Signal<GameScreen>,
+    location_mode: bool,
+    reveal_imposter: bool, // Off when round end keeps the imposter back: no name, and their card looks like the rest
+    lang: Lang,
+) -> Element {
+    let t = ui_text(lang);
+    let Some(record) = round_log().last().cloned() else {
+        return rsx! {
+            div { class: "stats-screen",
+                p { class: "stats-empty", "{t.round_history_empty}" }
+                button {
+                    class: "continue-btn",
+                    onclick: move |_| game_screen.set(GameScreen::GameScore),
+                    "{t.back_to_scoreboard}"
+                }
+            }
+        };
+    };
+    let steps = replay_steps(&record);
+    let step = step.min(steps.len() - 1);
+    let name_of = |seat: usize| record.seats.get(seat).map(|s| s.name.clone()).unwrap_or_else(|| t.unknown_player.to_string());
+
+    rsx! {
+        div { class: "stats-screen replay-screen",
+            h1 { {(t.replay_title)(record.round_number)} }
+            p { class: "hint", {(t.replay_progress)(step + 1, steps.len())} }
+
+            div { class: "replay-step",
+                match steps[step] {
+                    ReplayStep::Card(seat) => rsx! {
+                        h2 { {(t.players_card)(&name_of(seat))} }
+                        PlayerCard {
+                            card: record.seats[seat].card.clone(),
+                            location_mode,
+                            hide_imposter_identity: !reveal_imposter,
+                            show_tip: false,
+                            lang,
+                        }
+                    },
+                    ReplayStep::Eviction(nth) => {
+                        let seat = record.evicted[nth];
+                        let was_imposter = record.seats.get(seat).is_some_and(|s| s.card.card_type == CardType::Imposter);
+                        rsx! {
+                            h2 { {(t.replay_eviction)(nth + 1)} }
+                            p { class: if was_imposter { "replay-evicted replay-imposter" } else { "replay-evicted" },
+                                {(t.replay_evicted)(&name_of(seat))}
+                                " "
+                                if was_imposter { "{t.imposter_label}" } else { "{t.civilian_label}" }
+                            }
+                        }
+                    },
+                    ReplayStep::Outcome => rsx! {
+                        h2 { if record.civilians_won { "{t.civilians_win}" } else { "{t.imposter_wins}" } }
+                        if reveal_imposter {
+                            p { class: "imposter-reveal", {(t.the_imposter_was)(&record.imposter_name)} }
+                        }
+                        p { class: "history-words",
+                            "{record.civilian_word} / "
+                            span { class: "history-imposter-word",
+                                if record.imposter_word.is_empty() { "—" } else { "{record.imposter_word}" }
+                            }
+                        }
+                    },
+                }
+            }
+
+            div { class: "action-buttons",
+                button {
+                    class: "view-scores-btn",
+                    disabled: step == 0,
+                    onclick: move |_| game_screen.set(GameScreen::Replay { step: step.saturating_sub(1) }),
+                    "{t.replay_previous}"
+                }
+                button {
+                    class: "next-round-btn",
+                    disabled: step + 1 == steps.len(),
+                    onclick: move |_| game_screen.set(GameScreen::Replay { step: step + 1 }),
+                    "{t.replay_next}"
+                }
+                button {
+                    class: "continue-btn",
+                    onclick: move |_| game_screen.set(GameScreen::GameScore),
+                    "{t.back_to_scoreboard}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::types::{GameCard, SeatRecord};
+
+    fn record() -> RoundRecord {
+        let seat = |name: &str, card_type: CardType, word: &str| SeatRecord {
+            name: name.to_string(),
+            card: GameCard { card_type, word: word.to_string(), role: None, hint: None },
+        };
+        RoundRecord {
+            round_number: 3,
+            civilian_word: "Coffee".to_string(),
+            imposter_word: "Espresso".to_string(),
+            imposter_name: "Cleo".to_string(),
+            civilians_won: true,
+            seats: vec![
+                seat("Ana", CardType::Normal, "Coffee"),
+                seat("Ben", CardType::Normal, "Coffee"),
+                seat("Cleo", CardType::Imposter, "Espresso"),
+            ],
+            evicted: vec![1, 2],
+        }
+    }
+
+    fn render(step: usize) -> String {
+        render_revealing(step, true)
+    }
+
+    fn render_revealing(step: usize, reveal_imposter: bool) -> String {
+        let mut dom = VirtualDom::new_with_props(
+            |(step, reveal_imposter): (usize, bool)| {
+                let round_log = use_signal(|| vec![record()]);
+                let game_screen = use_signal(|| GameScreen::Replay { step });
+                rsx! { ReplayScreen { round_log, step, game_screen, location_mode: false, reveal_imposter, lang: Lang::English } }
+            },
+            (step, reveal_imposter),
+        );
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn steps_through_cards_then_evictions_then_the_outcome() {
+        assert_eq!(
+            replay_steps(&record()),
+            vec![
+                ReplayStep::Card(0),
+                ReplayStep::Card(1),
+                ReplayStep::Card(2),
+                ReplayStep::Eviction(0),
+                ReplayStep::Eviction(1),
+                ReplayStep::Outcome,
+            ]
+        );
+        let older = RoundRecord { seats: Vec::new(), evicted: Vec::new(), ..record() };
+        assert_eq!(replay_steps(&older), vec![ReplayStep::Outcome]);
+
+        let card = render(2);
+        assert!(card.contains("Cleo") && card.contains("Espresso"), "imposter's card missing: {card}");
+        let eviction = render(3);
+        assert!(eviction.contains("Ben"), "first eviction missing: {eviction}");
+        // Past the end stays on the outcome
+        let outcome = render(99);
+        assert!(outcome.contains("Civilians Win") && outcome.contains("6 of 6"), "outcome missing: {outcome}");
+        assert!(outcome.contains("Cleo"), "imposter not named: {outcome}");
+    }
+
+    #[test]
+    fn keeps_the_imposter_back_when_round_end_does() {
+        let outcome = render_revealing(5, false);
+        assert!(outcome.contains("Civilians Win") && !outcome.contains("Cleo"), "imposter named: {outcome}");
+        let card = render_revealing(2, false);
+        assert!(card.contains("Espresso") && !card.contains("imposter-card"), "imposter's card marked: {card}");
+    }
+}
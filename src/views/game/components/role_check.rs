@@ -0,0 +1,59 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum RoleCheckStep {
+    Hidden,
+    PassDevice,
+    Revealed,
+}
+
+/// Private side channel letting an evicted player confirm their own role.
+/// Only the evicted player's card is ever shown - never who the imposter is.
+#[component]
+pub fn PrivateRoleCheck(player_name: String, card: Option<GameCard>, lang: Lang) -> Element {
+    let t = ui_text(lang);
+    let mut step = use_signal(|| RoleCheckStep::Hidden);
+    let is_imposter = card.as_ref().map(|c| c.card_type == CardType::Imposter).unwrap_or(false);
+    let word = card.map(|c| c.word).unwrap_or_default();
+
+    rsx! {
+        div { class: "role-check",
+            match step() {
+                RoleCheckStep::Hidden => rsx! {
+                    button {
+                        class: "role-check-btn",
+                        onclick: move |_| step.set(RoleCheckStep::PassDevice),
+                        {(t.role_check_for)(&player_name)}
+                    }
+                },
+                RoleCheckStep::PassDevice => rsx! {
+                    div { class: "role-check-panel",
+                        p { {(t.pass_device_only_to)(&player_name)} }
+                        button {
+                            class: "reveal-btn",
+                            onclick: move |_| step.set(RoleCheckStep::Revealed),
+                            {(t.show_my_role_as)(&player_name)}
+                        }
+                    }
+                },
+                RoleCheckStep::Revealed => rsx! {
+                    div { class: "role-check-panel",
+                        if is_imposter {
+                            p { class: "role-check-result", "{t.you_were_imposter}" }
+                        } else {
+                            p { class: "role-check-result", "{t.you_were_civilian}" }
+                            p { {(t.your_word_was)(&word)} }
+                        }
+                        button {
+                            class: "next-btn",
+                            onclick: move |_| step.set(RoleCheckStep::Hidden),
+                            "{t.hide_and_pass_back}"
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::Player;
+
+/// Everyone at the table, with evicted players struck through and a count of who's left.
+/// `leaving` marks a player whose eviction is on screen but not yet confirmed.
+#[component]
+pub fn PlayerRoster(players: Vec<Player>, leaving: Option<usize>, lang: Lang) -> Element {
+    let t = ui_text(lang);
+    let is_out = |i: usize, player: &Player| player.is_eliminated || leaving == Some(i);
+    let still_in = players.iter().enumerate().filter(|&(i, p)| !is_out(i, p)).count();
+
+    rsx! {
+        aside { class: "player-roster", "aria-label": t.roster_heading,
+            div { class: "roster-header",
+                h3 { "{t.roster_heading}" }
+                span { class: "roster-count", {(t.roster_count)(still_in, players.len())} }
+            }
+            ul { class: "roster-list",
+                for (i, player) in players.iter().enumerate() {
+                    li {
+                        key: "{i}",
+                        class: if is_out(i, player) { "roster-player out" } else { "roster-player" },
+                        style: "--player-color: {player.color}",
+                        if is_out(i, player) {
+                            s { "{player.name}" }
+                            span { class: "sr-only", " {t.out_tag}" }
+                        } else {
+                            "{player.name}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::types::named_players;
+
+    #[test]
+    fn strikes_through_evicted_and_leaving_players() {
+        fn app() -> Element {
+            let mut players = named_players(&["Ana", "Ben", "Cleo", "Dev"]);
+            players[0].is_eliminated = true;
+            rsx! { PlayerRoster { players, leaving: Some(2), lang: Lang::English } }
+        }
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+        assert!(html.contains("2 of 4 still in"), "wrong count: {html}");
+        assert!(html.contains("<s>Ana</s>"), "evicted player not struck through: {html}");
+        assert!(html.contains("<s>Cleo</s>"), "leaving player not struck through: {html}");
+        assert!(!html.contains("<s>Ben</s>"), "active player struck through: {html}");
+    }
+}
@@ -1,6 +1,10 @@
 use dioxus::prelude::*;
+use crate::views::game::flagged_pairs::{load_flagged_pairs, pair_key, save_flagged_pairs, toggle_flag};
+use crate::views::game::i18n::ui_text;
+use crate::views::game::sound::{self, Sound};
 use crate::views::game::types::*;
-use crate::views::game::utils::get_random_starting_index;
+use crate::views::game::utils::{challenge_bonus, commitment_code, eviction_order, focus_on_mount, round_speed_bonus, seed_commitment, CIVILIAN_WIN_POINTS, IMPOSTER_WIN_POINTS};
+use crate::views::game::components::{Celebration, ConfirmDialog};
 
 /// Screen showing round results
 #[component]
@@ -8,80 +12,126 @@ pub fn RoundEndScreen(
     mut players: Signal<Vec<Player>>,
     imposter_found: bool,
     game_over: bool,
+    insight: String, // How the round went, in a line
     mut game_screen: Signal<GameScreen>,
-    mut round_number: Signal<i32>,
-    mut cards: Signal<Vec<GameCard>>,
-    mut imposter_index: Signal<usize>,
+    round_number: Signal<i32>,
+    cards: Signal<Vec<GameCard>>,
+    imposter_index: Signal<usize>,
     current_round_words: Signal<Option<(String, String)>>,
-    mut starting_player_index: Signal<usize>,
+    dealt_pair: Option<(String, String)>, // Both words of the round's pair, for flagging it
+    opened_deal: Option<DealProof>, // A blind deal's seed, checked here against the code shown before the reveals
+    settings: Signal<Settings>,
+    history: Signal<Vec<usize>>,
+    hidden_catch: Signal<Option<usize>>,
+    mut round_log: Signal<Vec<RoundRecord>>,
+    on_next_round: EventHandler<()>,
+    on_new_game: EventHandler<()>,
 ) -> Element {
+    // Cheer when the group caught the imposter, groan when they got away
+    use_hook(|| sound::play(if imposter_found { Sound::Win } else { Sound::Lose }, settings().sound_enabled));
     let player_list = players();
-    let imposter_name = &player_list[imposter_index()].name;
+    let t = ui_text(settings().lang);
+    // A blind catch is scored as of the vote that found the imposter
+    let earlier_evictions = hidden_catch().unwrap_or(history().len());
+    let bonus = round_speed_bonus(&settings(), earlier_evictions);
+    let challenge = challenge_bonus(&settings());
+    let votes_taken = earlier_evictions + 1;
+    let imposter_name = player_list.get(imposter_index())
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| t.unknown_player.to_string());
     let mut show_confirmation = use_signal(|| false);
+    // Groups reading the table across rounds can keep the name back until the scoreboard
+    let reveal_imposter = settings().reveal_imposter_at_round_end;
+    let mut pair_flagged = use_signal(|| {
+        dealt_pair.as_ref().is_some_and(|(first, second)| load_flagged_pairs().contains(&pair_key(first, second)))
+    });
+
+    // Log the round once; coming back to this screen must not add it twice
+    use_hook(move || {
+        let round = round_number();
+        if round_log.peek().last().is_some_and(|r| r.round_number == round) {
+            return;
+        }
+        let (civilian_word, imposter_word) = current_round_words().unwrap_or_default();
+        let imposter_name = players.peek().get(imposter_index()).map(|p| p.name.clone()).unwrap_or_default();
+        // Everything the replay needs, since the cards are gone once the next round is dealt
+        let seats = players.peek().iter().zip(cards()).map(|(player, card)| SeatRecord { name: player.name.clone(), card }).collect();
+        let evicted = eviction_order(&players.peek(), &history());
+        round_log.write().push(RoundRecord {
+            round_number: round,
+            civilian_word,
+            imposter_word,
+            imposter_name,
+            civilians_won: imposter_found,
+            seats,
+            evicted,
+        });
+    });
 
     rsx! {
         div { class: "round-end-screen",
-            // Confirmation dialog
+            if settings().animations {
+                Celebration { imposter_won: !imposter_found }
+            }
+
+            // Only wipe the session once the group confirms
             if show_confirmation() {
-                div { class: "confirmation-overlay",
-                    div { class: "confirmation-dialog",
-                        h2 { "⚠️ Start New Game?" }
-                        p { "All player scores will be reset. Are you sure?" }
-                        div { class: "confirmation-buttons",
-                            button {
-                                class: "confirm-yes-btn",
-                                onclick: move |_| {
-                                    // Clear all game state for a fresh start
-                                    cards.set(Vec::new());
-                                    imposter_index.set(0);
-                                    round_number.set(1);
-                                    
-                                    // Randomize starting player for new game
-                                    let player_count = players().len();
-                                    starting_player_index.set(get_random_starting_index(player_count));
-                                    
-                                    show_confirmation.set(false);
-                                    game_screen.set(GameScreen::Setup);
-                                },
-                                "Yes, Start New Game"
-                            }
-                            button {
-                                class: "confirm-no-btn",
-                                onclick: move |_| {
-                                    show_confirmation.set(false);
-                                },
-                                "Cancel"
-                            }
-                        }
-                    }
+                ConfirmDialog {
+                    title: t.new_game_title,
+                    message: t.new_game_message,
+                    confirm_label: t.confirm_new_game,
+                    lang: settings().lang,
+                    on_confirm: move |_| {
+                        show_confirmation.set(false);
+                        on_new_game.call(());
+                    },
+                    on_cancel: move |_| show_confirmation.set(false),
                 }
             }
             
             h1 {
                 if imposter_found {
-                    "✅ Civilians Win!"
+                    "{t.civilians_win}"
                 } else {
-                    "😈 Imposter Wins!"
+                    "{t.imposter_wins}"
                 }
             }
+            p { class: "round-insight", "{insight}" }
             
             div { class: "round-result",
-                p { class: "imposter-reveal",
-                    "The imposter was: {imposter_name}"
+                if reveal_imposter {
+                    p { class: "imposter-reveal",
+                        {(t.the_imposter_was)(&imposter_name)}
+                    }
                 }
                 
                 // Display the words that were used this round
                 if let Some((civilian_word, imposter_word)) = current_round_words() {
                     div { class: "words-reveal",
-                        h3 { "📝 Words This Round:" }
+                        h3 { "{t.words_this_round}" }
                         div { class: "word-display",
                             div { class: "word-item civilian-word-display",
-                                span { class: "word-label", "👥 Civilian Word:" }
+                                span { class: "word-label", "{t.civilian_word}" }
                                 span { class: "word-value", "{civilian_word}" }
                             }
                             div { class: "word-item imposter-word-display",
-                                span { class: "word-label", "🎭 Imposter Word:" }
-                                span { class: "word-value", "{imposter_word}" }
+                                span { class: "word-label", "{t.imposter_word}" }
+                                span { class: "word-value",
+                                    if imposter_word.is_empty() { "—" } else { "{imposter_word}" }
+                                }
+                            }
+                        }
+                        // Kept on this device only: a flagged pair isn't dealt here again
+                        if let Some((first, second)) = dealt_pair.clone() {
+                            button {
+                                class: if pair_flagged() { "flag-pair-btn flagged" } else { "flag-pair-btn" },
+                                "aria-pressed": "{pair_flagged()}",
+                                onclick: move |_| {
+                                    let mut flagged = load_flagged_pairs();
+                                    pair_flagged.set(toggle_flag(&mut flagged, &first, &second));
+                                    save_flagged_pairs(&flagged);
+                                },
+                                if pair_flagged() { "{t.pair_flagged}" } else { "{t.flag_pair}" }
                             }
                         }
                     }
@@ -89,18 +139,70 @@ pub fn RoundEndScreen(
                 
                 if imposter_found {
                     p { class: "result-message",
-                        "🎉 Remaining civilians get 10 points!"
+                        {(t.civilians_get_points)(CIVILIAN_WIN_POINTS + bonus + challenge)}
+                    }
+                    if bonus > 0 || challenge > 0 {
+                        div { class: "score-breakdown",
+                            p { {(t.base_win)(CIVILIAN_WIN_POINTS)} }
+                            if bonus > 0 {
+                                p { {(t.quick_catch_on_vote)(votes_taken, bonus)} }
+                            }
+                            if challenge > 0 {
+                                p { {(t.single_vote_bonus)(challenge)} }
+                            }
+                        }
                     }
                 } else {
                     p { class: "result-message",
-                        "😈 The imposter gets 20 points!"
+                        {(t.imposter_gets_points)(IMPOSTER_WIN_POINTS)}
                     }
                 }
 
-                div { class: "imposter-summary",
-                    h3 { "🕵️ Imposter Reveal" }
-                    p { class: "imposter-name",
-                        "Imposter: {imposter_name}"
+                // Full recap of every hand - only once the game is over so nothing leaks mid-game
+                if game_over && reveal_imposter {
+                    details { class: "round-recap",
+                        summary { "{t.see_everyones_card}" }
+                        ul { class: "recap-list",
+                            for (i, card) in cards().into_iter().enumerate() {
+                                li {
+                                    key: "{i}",
+                                    class: if i == imposter_index() { "recap-row recap-imposter" } else { "recap-row" },
+                                    span { class: "recap-name",
+                                        {player_list.get(i).map(|p| p.name.clone()).unwrap_or_default()}
+                                    }
+                                    span { class: "recap-word",
+                                        if card.word.is_empty() { "—" } else { "{card.word}" }
+                                        if let Some(role) = card.role {
+                                            " ({role})"
+                                        }
+                                    }
+                                    span { class: "recap-role",
+                                        if i == imposter_index() { "{t.imposter_label}" } else { "{t.civilian_label}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if reveal_imposter {
+                    div { class: "imposter-summary",
+                        h3 { "{t.imposter_reveal}" }
+                        p { class: "imposter-name",
+                            {(t.imposter_named)(&imposter_name)}
+                        }
+                    }
+                }
+
+                // The seed fixes the imposter's seat, so it is shown only where the imposter is
+                if let Some(proof) = opened_deal.filter(|_| reveal_imposter) {
+                    div { class: "sealed-deal-check",
+                        p { {(t.sealed_deal_seed)(commitment_code(&proof.commitment), proof.seed)} }
+                        if seed_commitment(proof.seed) == proof.commitment {
+                            p { class: "sealed-deal-match", "{t.sealed_deal_matches}" }
+                        } else {
+                            p { class: "sealed-deal-mismatch", "{t.sealed_deal_mismatch}" }
+                        }
                     }
                 }
             }
@@ -108,25 +210,9 @@ pub fn RoundEndScreen(
             div { class: "action-buttons",
                 button {
                     class: "next-round-btn",
-                    onclick: move |_| {
-                        // Reset all player states for new round
-                        let mut updated_players = players();
-                        for player in updated_players.iter_mut() {
-                            player.is_eliminated = false; // Reset eliminations for new round
-                        }
-                        players.set(updated_players);
-                        cards.set(Vec::new());
-                        round_number.set(round_number() + 1);
-                        
-                        // Rotate starting player for next round
-                        let player_count = players().len();
-                        if player_count > 0 {
-                            starting_player_index.set((starting_player_index() + 1) % player_count);
-                        }
-                        
-                        game_screen.set(GameScreen::CategorySelection);
-                    },
-                    "▶️ Next Round"
+                    onmounted: focus_on_mount,
+                    onclick: move |_| on_next_round.call(()),
+                    "{t.next_round}"
                 }
                 
                 button {
@@ -134,7 +220,13 @@ pub fn RoundEndScreen(
                     onclick: move |_| {
                         game_screen.set(GameScreen::GameScore);
                     },
-                    "🏆 View Scores"
+                    "{t.view_scores}"
+                }
+
+                button {
+                    class: "view-scores-btn",
+                    onclick: move |_| game_screen.set(GameScreen::RoundHistory),
+                    "{t.round_history}"
                 }
                 
                 button {
@@ -142,9 +234,58 @@ pub fn RoundEndScreen(
                     onclick: move |_| {
                         show_confirmation.set(true);
                     },
-                    "🔄 New Game"
+                    "{t.new_game}"
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(reveal_imposter_at_round_end: bool) -> String {
+        let mut dom = VirtualDom::new_with_props(
+            |props: bool| {
+                let players = use_signal(|| named_players(&["Ana", "Ben", "Cleo"]));
+                let settings = use_signal(|| Settings { reveal_imposter_at_round_end: props, ..Settings::default() });
+                rsx! {
+                    RoundEndScreen {
+                        players,
+                        imposter_found: false,
+                        game_over: true,
+                        insight: String::new(),
+                        game_screen: use_signal(|| GameScreen::RoundEnd { imposter_found: false, game_over: true }),
+                        round_number: use_signal(|| 1),
+                        cards: use_signal(Vec::<GameCard>::new),
+                        imposter_index: use_signal(|| 2usize),
+                        current_round_words: use_signal(|| None::<(String, String)>),
+                        dealt_pair: None,
+                        opened_deal: Some(DealProof { seed: 42, commitment: seed_commitment(42) }),
+                        settings,
+                        history: use_signal(Vec::<usize>::new),
+                        hidden_catch: use_signal(|| None::<usize>),
+                        round_log: use_signal(Vec::<RoundRecord>::new),
+                        on_next_round: |_| {},
+                        on_new_game: |_| {},
+                    }
+                }
+            },
+            reveal_imposter_at_round_end,
+        );
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn imposter_name_can_wait_for_the_scoreboard() {
+        let revealed = render(true);
+        assert!(revealed.contains("The imposter was: Cleo"), "{revealed}");
+        assert!(revealed.contains("the seed was 42") && revealed.contains("sealed-deal-match"), "blind deal unchecked: {revealed}");
+
+        let hidden = render(false);
+        assert!(!hidden.contains("Cleo"), "imposter named at round end: {hidden}");
+        assert!(hidden.contains("Imposter Wins"), "outcome missing: {hidden}");
+        assert!(!hidden.contains("the seed was"), "the seed gives the imposter away: {hidden}");
+    }
+}
@@ -0,0 +1,122 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::{GameScreen, RoundRecord};
+
+/// Scrollable log of every finished round in this session, newest at the top.
+/// `reveal_imposter` follows `Settings::reveal_imposter_at_round_end`, so the log
+/// can't name an imposter the round-end screen kept back.
+#[component]
+pub fn RoundHistoryScreen(
+    round_log: Signal<Vec<RoundRecord>>,
+    mut game_screen: Signal<GameScreen>,
+    reveal_imposter: bool,
+    lang: Lang,
+) -> Element {
+    let t = ui_text(lang);
+    let records: Vec<RoundRecord> = round_log().into_iter().rev().collect();
+
+    rsx! {
+        div { class: "stats-screen",
+            h1 { "{t.round_history}" }
+            p { class: "hint", "{t.round_history_hint}" }
+
+            if records.is_empty() {
+                p { class: "stats-empty", "{t.round_history_empty}" }
+            } else {
+                div { class: "round-history-list",
+                    table { class: "stats-table",
+                        thead {
+                            tr {
+                                th { "{t.round_column}" }
+                                th { "{t.words_column}" }
+                                th { "{t.imposter_column}" }
+                                th { "{t.winner_column}" }
+                            }
+                        }
+                        tbody {
+                            for record in records {
+                                tr { key: "{record.round_number}",
+                                    td { "{record.round_number}" }
+                                    td { class: "history-words",
+                                        "{record.civilian_word} / "
+                                        span { class: "history-imposter-word",
+                                            if record.imposter_word.is_empty() { "—" } else { "{record.imposter_word}" }
+                                        }
+                                    }
+                                    td {
+                                        if reveal_imposter { "{record.imposter_name}" } else { "—" }
+                                    }
+                                    td {
+                                        if record.civilians_won { "{t.civilians_won_cell}" } else { "{t.imposter_won_cell}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "action-buttons",
+                button {
+                    class: "continue-btn",
+                    onclick: move |_| game_screen.set(GameScreen::GameScore),
+                    "{t.back_to_scoreboard}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(reveal_imposter: bool) -> String {
+        let mut dom = VirtualDom::new_with_props(
+            |reveal_imposter: bool| {
+            let round_log = use_signal(|| vec![
+                RoundRecord {
+                    round_number: 1,
+                    civilian_word: "Coffee".to_string(),
+                    imposter_word: "Espresso".to_string(),
+                    imposter_name: "Ana".to_string(),
+                    civilians_won: true,
+                    seats: Vec::new(),
+                    evicted: Vec::new(),
+                },
+                RoundRecord {
+                    round_number: 2,
+                    civilian_word: "Cat".to_string(),
+                    imposter_word: String::new(),
+                    imposter_name: "Ben".to_string(),
+                    civilians_won: false,
+                    seats: Vec::new(),
+                    evicted: Vec::new(),
+                },
+            ]);
+            let game_screen = use_signal(|| GameScreen::RoundHistory);
+            rsx! { RoundHistoryScreen { round_log, game_screen, reveal_imposter, lang: Lang::English } }
+            },
+            reveal_imposter,
+        );
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn lists_newest_round_first() {
+        let html = render(true);
+        let newest = html.find("Ben").expect("round 2 missing");
+        let oldest = html.find("Ana").expect("round 1 missing");
+        assert!(newest < oldest, "newest round should come first: {html}");
+        assert!(html.contains("Espresso"), "imposter word missing: {html}");
+        assert!(html.contains("🎭 Imposter"), "winner missing: {html}");
+    }
+
+    #[test]
+    fn keeps_imposters_back_when_round_end_does() {
+        let html = render(false);
+        assert!(!html.contains("Ana") && !html.contains("Ben"), "imposter named: {html}");
+        assert!(html.contains("Espresso"), "the words still show: {html}");
+    }
+}
@@ -0,0 +1,43 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::*;
+
+/// Shown instead of a mid-round screen whose saved data no longer matches the players
+/// (e.g. an old save from a different roster). Offers a clean re-deal or a return to setup.
+#[component]
+pub fn RoundRecovery(
+    mut players: Signal<Vec<Player>>,
+    mut cards: Signal<Vec<GameCard>>,
+    mut game_screen: Signal<GameScreen>,
+    lang: Lang,
+) -> Element {
+    let t = ui_text(lang);
+    let has_players = !players().is_empty();
+
+    rsx! {
+        div { class: "round-recovery", role: "alert",
+            h2 { "{t.round_cannot_continue}" }
+            p { "{t.round_recovery_explainer}" }
+            div { class: "confirmation-buttons",
+                if has_players {
+                    button {
+                        class: "confirm-yes-btn",
+                        onclick: move |_| {
+                            for player in players.write().iter_mut() {
+                                player.is_eliminated = false;
+                            }
+                            cards.set(Vec::new());
+                            game_screen.set(GameScreen::CardView { current_player_index: 0 });
+                        },
+                        "{t.redeal_round}"
+                    }
+                }
+                button {
+                    class: "confirm-no-btn",
+                    onclick: move |_| game_screen.set(GameScreen::Setup),
+                    "{t.back_to_setup}"
+                }
+            }
+        }
+    }
+}
@@ -1,72 +1,204 @@
 use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
 use crate::views::game::types::*;
-use crate::views::game::utils::get_random_starting_index;
+use crate::views::game::components::{ConfirmDialog, EliminatedTag};
+use crate::views::game::share::{copy_to_clipboard, current_page_url, encode_join_link, join_link_qr, results_summary};
+use crate::views::game::utils::{focus_on_mount, normalize_name, valid_rename};
 
 /// Screen showing all player scores
 #[component]
 pub fn GameScoreScreen(
-    players: Signal<Vec<Player>>,
+    mut players: Signal<Vec<Player>>,
+    mut player_names: Signal<Vec<String>>,
     round_number: Signal<i32>,
     mut game_screen: Signal<GameScreen>,
-    mut cards: Signal<Vec<GameCard>>,
-    mut imposter_index: Signal<usize>,
-    mut starting_player_index: Signal<usize>,
+    mut settings: Signal<Settings>,
+    round_log: Signal<Vec<RoundRecord>>,
+    session_id: String,
+    on_next_round: EventHandler<()>,
+    on_new_game: EventHandler<()>,
+    on_export: EventHandler<()>,
 ) -> Element {
-    let mut sorted_players = players();
-    sorted_players.sort_by(|a, b| b.score.cmp(&a.score));
+    // Ranked by score, each with their seat so a rename lands on the right player
+    let mut sorted_players: Vec<(usize, Player)> = players().into_iter().enumerate().collect();
+    sorted_players.sort_by_key(|(_, p)| std::cmp::Reverse(p.score));
     let mut show_confirmation = use_signal(|| false);
+    let mut join_link = use_signal(|| None::<String>);
+    // The copied summary and whether the clipboard took it; shown for selecting by hand if not
+    let mut copied_results = use_signal(|| None::<(String, bool)>);
+    // The seat being renamed and the name typed so far
+    let mut renaming = use_signal(|| None::<usize>);
+    let mut draft = use_signal(String::new);
+    let compact = settings().compact_scoreboard;
+    let t = ui_text(settings().lang);
+    let rename_to = renaming().and_then(|seat| valid_rename(&players(), seat, &draft()));
+
+    // Changed in place, so the seat order (and the imposter index with it) stays put
+    let mut save_rename = move || {
+        let Some(seat) = renaming() else { return };
+        if let Some(name) = valid_rename(&players(), seat, &draft()) {
+            players.write()[seat].name = name.clone();
+            if let Some(typed) = player_names.write().get_mut(seat) {
+                *typed = name;
+            }
+            renaming.set(None);
+        }
+    };
+    let mut start_rename = move |seat: usize| {
+        draft.set(players.peek().get(seat).map(|p| p.name.clone()).unwrap_or_default());
+        renaming.set(Some(seat));
+    };
 
     rsx! {
         div { class: "score-screen",
-            // Confirmation dialog
+            // Only wipe the session once the group confirms
             if show_confirmation() {
-                div { class: "confirmation-overlay",
-                    div { class: "confirmation-dialog",
-                        h2 { "⚠️ Start New Game?" }
-                        p { "All player scores will be reset. Are you sure?" }
-                        div { class: "confirmation-buttons",
-                            button {
-                                class: "confirm-yes-btn",
-                                onclick: move |_| {
-                                    // Clear all game state for a completely fresh start
-                                    cards.set(Vec::new());
-                                    imposter_index.set(0);
-                                    
-                                    // Randomize starting player for new game
-                                    let player_count = players().len();
-                                    starting_player_index.set(get_random_starting_index(player_count));
-                                    
-                                    show_confirmation.set(false);
-                                    game_screen.set(GameScreen::Setup);
-                                },
-                                "Yes, Start New Game"
-                            }
-                            button {
-                                class: "confirm-no-btn",
-                                onclick: move |_| {
-                                    show_confirmation.set(false);
-                                },
-                                "Cancel"
-                            }
+                ConfirmDialog {
+                    title: t.new_game_title,
+                    message: t.new_game_message,
+                    confirm_label: t.confirm_new_game,
+                    lang: settings().lang,
+                    on_confirm: move |_| {
+                        show_confirmation.set(false);
+                        on_new_game.call(());
+                    },
+                    on_cancel: move |_| show_confirmation.set(false),
+                }
+            }
+            
+            h1 { "{t.scoreboard}" }
+            p { class: "round-info", {(t.after_round)(round_number())} }
+
+            // Round end kept the imposters hidden, so the scoreboard names them
+            if !settings().reveal_imposter_at_round_end && !round_log().is_empty() {
+                div { class: "imposter-summary",
+                    h3 { "{t.imposters_so_far}" }
+                    for record in round_log() {
+                        p { key: "{record.round_number}", class: "imposter-name",
+                            {(t.round_imposter)(record.round_number, &record.imposter_name)}
                         }
                     }
                 }
             }
             
-            h1 { "🏆 Scoreboard" }
-            p { class: "round-info", "After Round {round_number()}" }
+            button {
+                class: "scoreboard-view-toggle",
+                onclick: move |_| {
+                    let compact = settings().compact_scoreboard;
+                    settings.write().compact_scoreboard = !compact;
+                },
+                if compact { "{t.card_view_toggle}" } else { "{t.compact_view_toggle}" }
+            }
+
+            if renaming().is_some() {
+                div { class: "rename-player",
+                    h3 { "{t.rename_heading}" }
+                    input {
+                        r#type: "text",
+                        value: "{draft}",
+                        "aria-label": "{t.rename_heading}",
+                        onmounted: focus_on_mount,
+                        oninput: move |e| draft.set(e.value()),
+                        onkeydown: move |e| {
+                            if e.key() == Key::Enter {
+                                save_rename();
+                            } else if e.key() == Key::Escape {
+                                renaming.set(None);
+                            }
+                        },
+                    }
+                    if rename_to.is_none() {
+                        p { class: "duplicate-names-warning", role: "alert",
+                            if normalize_name(&draft()).is_empty() { "{t.name_cannot_be_empty}" } else { "{t.names_must_differ}" }
+                        }
+                    }
+                    div { class: "action-buttons",
+                        button {
+                            class: "next-round-btn",
+                            disabled: rename_to.is_none(),
+                            onclick: move |_| save_rename(),
+                            "{t.save_name}"
+                        }
+                        button {
+                            class: "new-game-btn",
+                            onclick: move |_| renaming.set(None),
+                            "{t.cancel}"
+                        }
+                    }
+                }
+            }
             
-            div { class: "scoreboard",
-                for (rank, player) in sorted_players.iter().enumerate() {
-                    div { 
-                        class: if rank == 0 { "score-card winner" } else { "score-card" },
-                        div { class: "rank", "#{rank + 1}" }
-                        div { class: "player-score-info",
-                            h3 { "{player.name}" }
-                            p { class: "score", "{player.score} points" }
+            if compact {
+                table { class: "scoreboard-compact",
+                    thead {
+                        tr {
+                            th { "#" }
+                            th { "{t.player_column}" }
+                            th { "{t.points_column}" }
+                        }
+                    }
+                    tbody {
+                        for (rank, (seat, player)) in sorted_players.iter().cloned().enumerate() {
+                            tr { class: if player.is_eliminated { "eliminated" } else if rank == 0 { "winner" } else { "" },
+                                style: "--player-color: {player.color}",
+                                td { class: "compact-rank", "{rank + 1}" }
+                                td { class: "compact-name",
+                                    "{player.name}"
+                                    button {
+                                        class: "rename-player-btn",
+                                        "aria-label": (t.rename_player)(&player.name),
+                                        onclick: move |_| start_rename(seat),
+                                        "✎"
+                                    }
+                                    if rank == 0 { " 👑" }
+                                    if player.is_eliminated {
+                                        " "
+                                        EliminatedTag { lang: settings().lang }
+                                    }
+                                }
+                                td { class: "compact-score",
+                                    "{player.score}"
+                                    if player.last_round_delta != 0 {
+                                        " "
+                                        span { class: "score-delta", "{player.last_round_delta:+}" }
+                                    }
+                                }
+                            }
                         }
-                        if rank == 0 {
-                            span { class: "winner-badge", "👑" }
+                    }
+                }
+            } else {
+                div { class: "scoreboard",
+                    for (rank, (seat, player)) in sorted_players.iter().cloned().enumerate() {
+                        div { 
+                            class: if rank == 0 { "score-card winner" } else { "score-card" },
+                            style: "--player-color: {player.color}",
+                            div { class: "rank", "#{rank + 1}" }
+                            div { class: "player-score-info",
+                                h3 {
+                                    "{player.name}"
+                                    button {
+                                        class: "rename-player-btn",
+                                        "aria-label": (t.rename_player)(&player.name),
+                                        onclick: move |_| start_rename(seat),
+                                        "✎"
+                                    }
+                                    if player.is_eliminated {
+                                        " "
+                                        EliminatedTag { lang: settings().lang }
+                                    }
+                                }
+                                p { class: "score",
+                                    {(t.points_total)(player.score)}
+                                    if player.last_round_delta != 0 {
+                                        " "
+                                        span { class: "score-delta", "{player.last_round_delta:+}" }
+                                    }
+                                }
+                            }
+                            if rank == 0 {
+                                span { class: "winner-badge", "👑" }
+                            }
                         }
                     }
                 }
@@ -75,25 +207,14 @@ pub fn GameScoreScreen(
             div { class: "action-buttons",
                 button {
                     class: "next-round-btn",
-                    onclick: move |_| {
-                        // Reset all player states for new round
-                        let mut updated_players = players();
-                        for player in updated_players.iter_mut() {
-                            player.is_eliminated = false; // Reset eliminations for new round
-                        }
-                        players.set(updated_players);
-                        cards.set(Vec::new());
-                        round_number.set(round_number() + 1);
-                        
-                        // Rotate starting player for next round
-                        let player_count = players().len();
-                        if player_count > 0 {
-                            starting_player_index.set((starting_player_index() + 1) % player_count);
-                        }
-                        
-                        game_screen.set(GameScreen::CategorySelection);
-                    },
-                    "Play Next Round"
+                    onclick: move |_| on_next_round.call(()),
+                    "{t.play_next_round}"
+                }
+                
+                button {
+                    class: "edit-players-btn",
+                    onclick: move |_| game_screen.set(GameScreen::EditPlayers),
+                    "{t.edit_players}"
                 }
                 
                 button {
@@ -101,7 +222,101 @@ pub fn GameScoreScreen(
                     onclick: move |_| {
                         show_confirmation.set(true);
                     },
-                    "New Game"
+                    "{t.new_game_plain}"
+                }
+                
+                button {
+                    class: "export-btn",
+                    onclick: move |_| game_screen.set(GameScreen::Stats),
+                    "{t.player_stats}"
+                }
+
+                button {
+                    class: "export-btn",
+                    onclick: move |_| game_screen.set(GameScreen::RoundHistory),
+                    "{t.round_history}"
+                }
+
+                if !round_log().is_empty() {
+                    button {
+                        class: "export-btn",
+                        onclick: move |_| game_screen.set(GameScreen::Replay { step: 0 }),
+                        "{t.replay_last_round}"
+                    }
+                }
+                
+                button {
+                    class: "export-btn",
+                    onclick: move |_| on_export.call(()),
+                    "{t.export_game}"
+                }
+
+                button {
+                    class: "export-btn",
+                    onclick: move |_| {
+                        let summary = results_summary(&players(), &round_log(), settings().lang);
+                        spawn(async move {
+                            let copied = copy_to_clipboard(&summary).await;
+                            copied_results.set(Some((summary, copied)));
+                        });
+                    },
+                    "{t.copy_results}"
+                }
+            }
+
+            if let Some((summary, copied)) = copied_results() {
+                div { class: "copy-results", role: "status",
+                    if copied {
+                        p { "{t.results_copied}" }
+                    } else {
+                        // No clipboard access (permissions, insecure page): hand over selected text instead
+                        p { "{t.copy_results_fallback}" }
+                        textarea {
+                            class: "share-link copy-results-text",
+                            readonly: true,
+                            rows: "4",
+                            "aria-label": "{t.results_label}",
+                            value: "{summary}",
+                            onmounted: move |evt| {
+                                spawn(async move {
+                                    let _ = evt.set_focus(true).await;
+                                    let _ = document::eval("document.querySelector('.copy-results-text')?.select();");
+                                });
+                            },
+                        }
+                    }
+                }
+            }
+
+            // Only saved games live on the server, so private games have nothing to join
+            if settings().persistence_enabled {
+                div { class: "share-setup",
+                    p { class: "rejoin-code", {(t.rejoin_code_is)(&session_id)} }
+                    p { class: "hint", "{t.rejoin_code_hint}" }
+                    button {
+                        class: "share-setup-btn",
+                        onclick: move |_| {
+                            let base = current_page_url().unwrap_or_default();
+                            join_link.set(Some(encode_join_link(&session_id, &base)));
+                        },
+                        "{t.show_join_link}"
+                    }
+                    if let Some(link) = join_link() {
+                        input {
+                            class: "share-link join-link",
+                            r#type: "text",
+                            readonly: true,
+                            "aria-label": "{t.join_link_label}",
+                            value: "{link}",
+                            onfocus: move |_| {
+                                let _ = document::eval("document.querySelector('.join-link')?.select();");
+                            },
+                        }
+                        if let Some(qr) = join_link_qr(&link) {
+                            img { class: "join-qr", src: "{qr}", alt: "{t.join_qr_label}" }
+                        }
+                        p { class: "hint", "{t.join_link_hint}" }
+                    }
                 }
             }
         }
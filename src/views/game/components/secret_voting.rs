@@ -0,0 +1,173 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
+use crate::views::game::types::*;
+use crate::views::game::utils::{active_player_order, focus_on_mount, tally_votes};
+
+/// Secret ballot: the device is passed to each active player, who privately picks a suspect
+#[component]
+pub fn SecretVotingScreen(
+    current_voter_index: usize,
+    votes: Vec<usize>,
+    candidates: Vec<usize>,
+    revotes: u32,
+    players: Signal<Vec<Player>>,
+    mut game_screen: Signal<GameScreen>,
+    starting_player_index: Signal<usize>,
+    settings: Signal<Settings>,
+    turn_order: Signal<Vec<usize>>,
+) -> Element {
+    let t = ui_text(settings().lang);
+    let player_list = players();
+    let voters = active_player_order(&player_list, starting_player_index(), &turn_order());
+    let mut ballot_open = use_signal(|| false);
+
+    // Every ballot is in - show the counts before anyone is evicted
+    let mut count_votes = move |votes: Vec<usize>| {
+        if votes.is_empty() {
+            game_screen.set(GameScreen::Voting);
+        } else {
+            game_screen.set(GameScreen::VoteReveal { tally: tally_votes(&votes), revotes });
+        }
+    };
+
+    let Some(&voter_idx) = voters.get(current_voter_index) else {
+        return rsx! {
+            div { class: "transition-screen",
+                h2 { "{t.all_votes_in}" }
+                button {
+                    class: "proceed-btn",
+                    onmounted: focus_on_mount,
+                    onclick: move |_| count_votes(votes.clone()),
+                    "{t.count_votes}"
+                }
+            }
+        };
+    };
+    let voter_name = player_list[voter_idx].name.clone();
+    let voter_color = player_list[voter_idx].color.clone();
+    let suspects: Vec<(usize, String, String)> = voters.iter()
+        .filter(|&&idx| idx != voter_idx && (candidates.is_empty() || candidates.contains(&idx)))
+        .map(|&idx| (idx, player_list[idx].name.clone(), player_list[idx].color.clone()))
+        .collect();
+    let is_last_voter = current_voter_index + 1 == voters.len();
+    // Running counts for groups that don't mind seeing which way the vote is going
+    let live_tally = if settings().hide_live_tally { Vec::new() } else { tally_votes(&votes) };
+
+    rsx! {
+        div { class: "secret-voting-screen",
+            h1 { "{t.secret_ballot}" }
+            p { class: "ballot-progress", {(t.ballot_progress)(current_voter_index + 1, voters.len())} }
+            if !candidates.is_empty() {
+                p { class: "hint", "{t.revote_hint}" }
+            }
+            if !live_tally.is_empty() {
+                div { class: "live-tally",
+                    p { class: "hint", "{t.votes_so_far}" }
+                    ul {
+                        for (index, count) in live_tally {
+                            if let Some(player) = player_list.get(index) {
+                                li { key: "{index}", style: "--player-color: {player.color}",
+                                    span { "{player.name}" }
+                                    span { {(t.vote_count)(count)} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !ballot_open() {
+                div { class: "player-ready-screen",
+                    style: "--player-color: {voter_color}",
+                    h2 { "{t.pass_device_to}" }
+                    h1 { class: "player-name", "{voter_name}" }
+                    p { class: "instruction", "{t.keep_screen_private}" }
+                    button {
+                        class: "reveal-btn",
+                        onmounted: focus_on_mount,
+                        onclick: move |_| ballot_open.set(true),
+                        {(t.open_my_ballot)(&voter_name)}
+                    }
+                }
+            } else {
+                div { class: "ballot",
+                    h2 { {(t.who_is_imposter)(&voter_name)} }
+                    div { class: "players-voting-list",
+                        for (suspect_idx, suspect_name, suspect_color) in suspects {
+                            div { key: "{suspect_idx}", class: "player-voting-card",
+                                style: "--player-color: {suspect_color}",
+                                div { class: "player-info",
+                                    h3 { "{suspect_name}" }
+                                }
+                                button {
+                                    class: "evict-btn",
+                                    onclick: {
+                                        let votes = votes.clone();
+                                        let candidates = candidates.clone();
+                                        move |_| {
+                                            let mut votes = votes.clone();
+                                            votes.push(suspect_idx);
+                                            // Close the ballot so the next voter never sees this choice
+                                            ballot_open.set(false);
+                                            if is_last_voter {
+                                                count_votes(votes);
+                                            } else {
+                                                game_screen.set(GameScreen::SecretVoting {
+                                                    current_voter_index: current_voter_index + 1,
+                                                    votes,
+                                                    candidates: candidates.clone(),
+                                                    revotes,
+                                                });
+                                            }
+                                        }
+                                    },
+                                    "{t.vote}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::i18n::Lang;
+
+    fn render(hide_live_tally: bool) -> String {
+        let mut dom = VirtualDom::new_with_props(
+            |hide_live_tally: bool| {
+                let players = use_signal(|| named_players(&["Alex", "Dana", "Sam"]));
+                let game_screen = use_signal(|| GameScreen::Setup);
+                let settings = use_signal(|| Settings { lang: Lang::English, hide_live_tally, ..Settings::default() });
+                rsx! {
+                    SecretVotingScreen {
+                        current_voter_index: 2,
+                        votes: vec![1, 1],
+                        candidates: Vec::new(),
+                        revotes: 0,
+                        players,
+                        game_screen,
+                        starting_player_index: use_signal(|| 0usize),
+                        settings,
+                        turn_order: use_signal(Vec::<usize>::new),
+                    }
+                }
+            },
+            hide_live_tally,
+        );
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn running_counts_show_only_when_not_hidden() {
+        let hidden = render(true);
+        assert!(!hidden.contains("live-tally") && !hidden.contains("2 votes"), "counts leaked: {hidden}");
+        let live = render(false);
+        assert!(live.contains("live-tally") && live.contains("2 votes"), "no running count: {live}");
+    }
+}
@@ -0,0 +1,422 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
+use crate::views::game::types::{Difficulty, GameMode, ImposterMode, Settings, TieBreakMode};
+use crate::views::game::utils::{get_word_packs, imposter_win_threshold, parse_custom_pairs, MIN_IMPOSTER_WIN_THRESHOLD};
+
+/// Largest skip cap offered; more than this and a stalled group never has to decide
+const MAX_CONSECUTIVE_SKIPS: usize = 3;
+
+/// Auto-pass delays offered after a card is revealed, in seconds
+const REVEAL_TIMEOUT_CHOICES: [u32; 3] = [5, 8, 12];
+
+/// "Get ready" pauses offered before the next player can reveal, in seconds
+const PASS_DELAY_CHOICES: [u32; 2] = [3, 5];
+
+/// Collapsible panel of optional gameplay settings shown on the setup screen
+#[component]
+pub fn SettingsPanel(mut settings: Signal<Settings>, player_count: usize) -> Element {
+    let current = settings();
+    let win_threshold = imposter_win_threshold(&current, player_count);
+    let t = ui_text(current.lang);
+    let packs = use_signal(get_word_packs);
+    let mut custom_text = use_signal(|| {
+        settings.peek().custom_pairs.iter()
+            .map(|(a, b)| format!("{a}, {b}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+    let custom_errors = use_memo(move || parse_custom_pairs(&custom_text()).1);
+
+    rsx! {
+        details { class: "settings-panel",
+            summary { "{t.game_settings}" }
+
+            div { class: "setting-row setting-row-select",
+                label { class: "setting-label", r#for: "game-mode-select", "{t.game_mode_label}" }
+                select {
+                    id: "game-mode-select",
+                    class: "setting-select",
+                    value: if current.game_mode == GameMode::Location { "location" } else { "words" },
+                    onchange: move |evt| {
+                        settings.write().game_mode = if evt.value() == "location" {
+                            GameMode::Location
+                        } else {
+                            GameMode::WordPairs
+                        };
+                    },
+                    option { value: "words", "{t.word_pairs_mode}" }
+                    option { value: "location", "{t.location_mode}" }
+                }
+                p { class: "setting-description",
+                    if current.game_mode == GameMode::Location {
+                        "{t.location_mode_description}"
+                    } else {
+                        "{t.word_pairs_description}"
+                    }
+                }
+            }
+
+            if current.game_mode == GameMode::WordPairs {
+                div { class: "setting-row setting-row-select",
+                    label { class: "setting-label", r#for: "word-pack-select", "{t.word_pack_label}" }
+                    select {
+                        id: "word-pack-select",
+                        class: "setting-select",
+                        value: current.word_pack.clone().unwrap_or_default(),
+                        onchange: move |evt| {
+                            let value = evt.value();
+                            settings.write().word_pack = if value.is_empty() { None } else { Some(value) };
+                        },
+                        option { value: "", "{t.mixed_pack}" }
+                        for pack in packs.read().iter() {
+                            option { key: "{pack.name}", value: "{pack.name}", "{pack.icon} {pack.name}" }
+                        }
+                    }
+                }
+
+                div { class: "setting-row setting-row-select",
+                    label { class: "setting-label", r#for: "difficulty-select", "{t.difficulty_label}" }
+                    select {
+                        id: "difficulty-select",
+                        class: "setting-select",
+                        value: match current.difficulty {
+                            None => "any",
+                            Some(Difficulty::Easy) => "easy",
+                            Some(Difficulty::Medium) => "medium",
+                            Some(Difficulty::Hard) => "hard",
+                        },
+                        onchange: move |evt| {
+                            settings.write().difficulty = match evt.value().as_str() {
+                                "easy" => Some(Difficulty::Easy),
+                                "medium" => Some(Difficulty::Medium),
+                                "hard" => Some(Difficulty::Hard),
+                                _ => None,
+                            };
+                        },
+                        option { value: "any", "{t.any_difficulty_option}" }
+                        option { value: "easy", "{t.easy_option}" }
+                        option { value: "medium", "{t.medium_option}" }
+                        option { value: "hard", "{t.hard_option}" }
+                    }
+                    p { class: "setting-description", "{t.difficulty_description}" }
+                }
+
+                div { class: "setting-row setting-row-select",
+                    label { class: "setting-label", r#for: "imposter-mode-select", "{t.imposter_card_label}" }
+                    select {
+                        id: "imposter-mode-select",
+                        class: "setting-select",
+                        value: match current.imposter_mode {
+                            ImposterMode::SimilarWord => "similar",
+                            ImposterMode::CategoryHint => "hint",
+                            ImposterMode::NoWord => "none",
+                        },
+                        onchange: move |evt| {
+                            settings.write().imposter_mode = match evt.value().as_str() {
+                                "none" => ImposterMode::NoWord,
+                                "hint" => ImposterMode::CategoryHint,
+                                _ => ImposterMode::SimilarWord,
+                            };
+                        },
+                        option { value: "similar", "{t.similar_word_option}" }
+                        option { value: "hint", "{t.category_hint_option}" }
+                        option { value: "none", "{t.no_word_option}" }
+                    }
+                }
+
+                div { class: "setting-row setting-row-select",
+                    label { class: "setting-label", r#for: "custom-pairs-input", "{t.custom_pairs_label}" }
+                    textarea {
+                        id: "custom-pairs-input",
+                        class: "custom-pairs-input",
+                        rows: "4",
+                        placeholder: "Coffee, Tea\nBeach, Desert",
+                        value: "{custom_text}",
+                        oninput: move |evt| {
+                            let text = evt.value();
+                            settings.write().custom_pairs = parse_custom_pairs(&text).0;
+                            custom_text.set(text);
+                        },
+                    }
+                    p { class: "setting-description",
+                        if current.custom_pairs.is_empty() {
+                            "{t.custom_pairs_empty}"
+                        } else {
+                            {(t.custom_pairs_in_use)(current.custom_pairs.len())}
+                        }
+                    }
+                    for error in custom_errors().iter().map(|error| error.message(current.lang)) {
+                        p { key: "{error}", class: "custom-pairs-error", "⚠️ {error}" }
+                    }
+                }
+
+                SettingToggle {
+                    label: t.skip_flagged_label,
+                    description: t.skip_flagged_description,
+                    checked: current.skip_flagged_pairs,
+                    onchange: move |value| settings.write().skip_flagged_pairs = value,
+                }
+
+                SettingToggle {
+                    label: t.twist_round_label,
+                    description: t.twist_round_description,
+                    checked: current.twist_round,
+                    onchange: move |value| settings.write().twist_round = value,
+                }
+            }
+
+            div { class: "setting-row setting-row-select",
+                label { class: "setting-label", r#for: "timer-select", "{t.timer_label}" }
+                select {
+                    id: "timer-select",
+                    class: "setting-select",
+                    value: current.timer_seconds.map(|s| s.to_string()).unwrap_or_default(),
+                    onchange: move |evt| settings.write().timer_seconds = evt.value().parse().ok(),
+                    option { value: "", "{t.timer_off}" }
+                    option { value: "60", "{t.one_minute}" }
+                    option { value: "120", "{t.two_minutes}" }
+                    option { value: "180", "{t.three_minutes}" }
+                }
+                p { class: "setting-description", "{t.timer_description}" }
+            }
+
+            div { class: "setting-row setting-row-select",
+                label { class: "setting-label", r#for: "reveal-timeout-select", "{t.reveal_timeout_label}" }
+                select {
+                    id: "reveal-timeout-select",
+                    class: "setting-select",
+                    value: current.reveal_timeout_secs.map(|s| s.to_string()).unwrap_or_default(),
+                    onchange: move |evt| settings.write().reveal_timeout_secs = evt.value().parse().ok(),
+                    option { value: "", "{t.timer_off}" }
+                    for seconds in REVEAL_TIMEOUT_CHOICES {
+                        option { key: "{seconds}", value: "{seconds}", {(t.seconds_option)(seconds)} }
+                    }
+                }
+                p { class: "setting-description", "{t.reveal_timeout_description}" }
+            }
+
+            div { class: "setting-row setting-row-select",
+                label { class: "setting-label", r#for: "pass-delay-select", "{t.pass_delay_label}" }
+                select {
+                    id: "pass-delay-select",
+                    class: "setting-select",
+                    value: current.pass_delay_secs.map(|s| s.to_string()).unwrap_or_default(),
+                    onchange: move |evt| settings.write().pass_delay_secs = evt.value().parse().ok(),
+                    option { value: "", "{t.timer_off}" }
+                    for seconds in PASS_DELAY_CHOICES {
+                        option { key: "{seconds}", value: "{seconds}", {(t.seconds_option)(seconds)} }
+                    }
+                }
+                p { class: "setting-description", "{t.pass_delay_description}" }
+            }
+
+            div { class: "setting-row setting-row-select",
+                label { class: "setting-label", r#for: "win-threshold-select", "{t.win_threshold_label}" }
+                select {
+                    id: "win-threshold-select",
+                    class: "setting-select",
+                    value: "{win_threshold}",
+                    onchange: move |evt| {
+                        if let Ok(threshold) = evt.value().parse() {
+                            settings.write().imposter_win_threshold = threshold;
+                        }
+                    },
+                    // Anything from 2 up to two below the player count leaves the first vote open
+                    for threshold in MIN_IMPOSTER_WIN_THRESHOLD..player_count.saturating_sub(1).max(MIN_IMPOSTER_WIN_THRESHOLD + 1) {
+                        option { key: "{threshold}", value: "{threshold}", {(t.players_left)(threshold)} }
+                    }
+                }
+                p { class: "setting-description", "{t.win_threshold_description}" }
+            }
+
+            div { class: "setting-row setting-row-select",
+                label { class: "setting-label", r#for: "skip-cap-select", "{t.skip_cap_label}" }
+                select {
+                    id: "skip-cap-select",
+                    class: "setting-select",
+                    value: "{current.max_consecutive_skips}",
+                    onchange: move |evt| {
+                        if let Ok(cap) = evt.value().parse() {
+                            settings.write().max_consecutive_skips = cap;
+                        }
+                    },
+                    option { value: "0", "{t.skip_cap_off}" }
+                    for cap in 1..=MAX_CONSECUTIVE_SKIPS {
+                        option { key: "{cap}", value: "{cap}", "{cap}" }
+                    }
+                }
+                p { class: "setting-description", "{t.skip_cap_description}" }
+            }
+
+            SettingToggle {
+                label: t.private_game_label,
+                description: t.private_game_description,
+                checked: !current.persistence_enabled,
+                onchange: move |value: bool| settings.write().persistence_enabled = !value,
+            }
+
+            SettingToggle {
+                label: t.event_log_label,
+                description: t.event_log_description,
+                checked: current.record_events,
+                onchange: move |value| settings.write().record_events = value,
+            }
+
+            SettingToggle {
+                label: t.role_check_label,
+                description: t.role_check_description,
+                checked: current.eliminated_role_check,
+                onchange: move |value| settings.write().eliminated_role_check = value,
+            }
+
+            SettingToggle {
+                label: t.reveal_imposter_label,
+                description: t.reveal_imposter_description,
+                checked: current.reveal_imposter_at_round_end,
+                onchange: move |value| settings.write().reveal_imposter_at_round_end = value,
+            }
+
+            SettingToggle {
+                label: t.animations_label,
+                description: t.animations_description,
+                checked: current.animations,
+                onchange: move |value| settings.write().animations = value,
+            }
+
+            SettingToggle {
+                label: t.name_check_label,
+                description: t.name_check_description,
+                checked: current.reveal_name_check,
+                onchange: move |value| settings.write().reveal_name_check = value,
+            }
+
+            SettingToggle {
+                label: t.tips_label,
+                description: t.tips_description,
+                checked: current.show_tips,
+                onchange: move |value| settings.write().show_tips = value,
+            }
+
+            SettingToggle {
+                label: t.blind_deal_label,
+                description: t.blind_deal_description,
+                checked: current.blind_deal,
+                onchange: move |value| settings.write().blind_deal = value,
+            }
+
+            SettingToggle {
+                label: t.random_category_label,
+                description: t.random_category_description,
+                checked: current.random_category_per_round,
+                onchange: move |value| settings.write().random_category_per_round = value,
+            }
+
+            SettingToggle {
+                label: t.nudge_label,
+                description: t.nudge_description,
+                checked: current.seat_swap_warning,
+                onchange: move |value| settings.write().seat_swap_warning = value,
+            }
+
+            SettingToggle {
+                label: t.shuffle_order_label,
+                description: t.shuffle_order_description,
+                checked: current.shuffle_pass_order,
+                onchange: move |value| settings.write().shuffle_pass_order = value,
+            }
+
+            SettingToggle {
+                label: t.fair_rotation_label,
+                description: t.fair_rotation_description,
+                checked: current.fair_rotation,
+                onchange: move |value| settings.write().fair_rotation = value,
+            }
+
+            SettingToggle {
+                label: t.secret_ballot_label,
+                description: t.secret_ballot_description,
+                checked: current.secret_voting,
+                onchange: move |value| settings.write().secret_voting = value,
+            }
+
+            if current.secret_voting {
+                div { class: "setting-row setting-row-select",
+                    label { class: "setting-label", r#for: "tie-break-select", "{t.tied_ballots_label}" }
+                    select {
+                        id: "tie-break-select",
+                        class: "setting-select",
+                        value: if current.tie_break == TieBreakMode::NoElimination { "none" } else { "revote" },
+                        onchange: move |evt| {
+                            settings.write().tie_break = if evt.value() == "none" {
+                                TieBreakMode::NoElimination
+                            } else {
+                                TieBreakMode::Revote
+                            };
+                        },
+                        option { value: "revote", "{t.revote_option}" }
+                        option { value: "none", "{t.no_elimination_option}" }
+                    }
+                }
+
+                SettingToggle {
+                    label: t.hide_live_tally_label,
+                    description: t.hide_live_tally_description,
+                    checked: current.hide_live_tally,
+                    onchange: move |value| settings.write().hide_live_tally = value,
+                }
+            }
+
+            SettingToggle {
+                label: t.quick_catch_label,
+                description: t.quick_catch_description,
+                checked: current.speed_bonus,
+                onchange: move |value| settings.write().speed_bonus = value,
+            }
+
+            SettingToggle {
+                label: t.grace_round_label,
+                description: t.grace_round_description,
+                checked: current.grace_round,
+                onchange: move |value| settings.write().grace_round = value,
+            }
+
+            SettingToggle {
+                label: t.comeback_label,
+                description: t.comeback_description,
+                checked: current.comeback_enabled,
+                onchange: move |value| settings.write().comeback_enabled = value,
+            }
+
+            SettingToggle {
+                label: t.reveal_role_label,
+                description: t.reveal_role_description,
+                checked: current.reveal_role_on_eviction,
+                onchange: move |value| settings.write().reveal_role_on_eviction = value,
+            }
+        }
+    }
+}
+
+/// A single labelled on/off switch inside the settings panel
+#[component]
+pub fn SettingToggle(
+    label: String,
+    description: String,
+    checked: bool,
+    onchange: EventHandler<bool>,
+) -> Element {
+    rsx! {
+        div { class: "setting-row",
+            label { class: "toggle-label",
+                input {
+                    r#type: "checkbox",
+                    class: "toggle-checkbox",
+                    checked,
+                    oninput: move |evt| onchange.call(evt.value() == "true"),
+                }
+                span { class: "setting-label", "{label}" }
+            }
+            p { class: "setting-description", "{description}" }
+        }
+    }
+}
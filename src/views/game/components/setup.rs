@@ -1,61 +1,212 @@
 use dioxus::prelude::*;
-use crate::views::game::types::{Player, GameScreen};
-use crate::views::game::utils::get_random_starting_index;
+use crate::views::game::types::{DisplayMode, Player, GameScreen, GameState, RecentPair, Settings};
+use crate::views::game::persistence::{delete_local_session, import_game_json, ImportError, list_local_sessions, load_game_state, normalize_session_code, rejoin_server_game, RejoinError};
+use crate::views::game::share::{current_page_url, encode_setup_to_url, SetupConfig};
+use crate::views::game::components::{ConfirmDialog, SettingToggle, SettingsPanel};
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::utils::{duplicate_name_indices, get_random_starting_index, imposter_win_threshold, normalize_name, player_color, random_category_index, read_player_count, round_start_screen, seat_names, with_unique_suffixes, PlayerCountEntry, LARGE_GROUP, MAX_PLAYERS, MIN_PLAYERS, SOLO_SEAT};
 
 #[component]
 pub fn SetupScreen(
+    session_id: String,
     mut player_count_input: Signal<String>,
     mut player_names: Signal<Vec<String>>,
     mut players: Signal<Vec<Player>>,
     mut game_screen: Signal<GameScreen>,
     mut round_number: Signal<i32>,
     mut starting_player_index: Signal<usize>,
+    mut recent_pairs: Signal<Vec<RecentPair>>,
+    mut selected_category_index: Signal<Option<usize>>,
+    mut settings: Signal<Settings>,
+    mut solo: Signal<bool>,
+    on_restore: EventHandler<GameState>,
 ) -> Element {
-    let player_count = player_count_input().parse::<usize>().unwrap_or(3).max(3).min(10);
+    // Half-typed or out-of-range counts keep the current seats; only a supported count changes them
+    let count_entry = read_player_count(&player_count_input());
+    let mut last_valid_count = use_signal(|| match count_entry {
+        PlayerCountEntry::Valid(count) => count,
+        _ => player_names().len().clamp(MIN_PLAYERS, MAX_PLAYERS),
+    });
+    let player_count = match count_entry {
+        PlayerCountEntry::Valid(count) => count,
+        _ => last_valid_count(),
+    };
     
-    // Initialize player names if needed - ensure this happens before rendering
-    let mut current_names = player_names();
-    if current_names.len() != player_count {
-        let mut names = vec![String::new(); player_count];
-        for i in 0..player_count.min(current_names.len()) {
-            names[i] = current_names[i].clone();
-        }
-        current_names = names.clone();
-        player_names.set(names);
+    // The stored list only grows; seats past the count keep their names in case it goes back up
+    let current_names = seat_names(&player_names(), player_count);
+    if player_names().len() < player_count {
+        player_names.set(current_names.clone());
     }
 
+    let duplicates = duplicate_name_indices(&current_names);
+
+    let mut restore_code = use_signal(String::new);
+    let mut restore_error = use_signal(|| None::<String>);
+    let mut share_link = use_signal(|| None::<String>);
+    let mut import_error = use_signal(|| None::<String>);
+    // Other games saved in this browser; the one being set up now isn't offered
+    let mut local_sessions = use_signal(list_local_sessions);
+    let other_sessions: Vec<_> = local_sessions().into_iter().filter(|s| s.session_id != session_id).collect();
+    let mut pending_delete = use_signal(|| None::<String>);
+    let t = ui_text(settings().lang);
+
+    // Seat the players and reset the game, leaving the caller to pick the first screen
+    let mut seat_players = move |names: &[String]| {
+        let new_players: Vec<Player> = names.iter().enumerate().map(|(i, name)| Player {
+            name: normalize_name(name),
+            score: 0,
+            is_eliminated: false,
+            color: player_color(i),
+            last_round_delta: 0,
+        }).collect();
+        // Lock in a threshold the game can actually reach with this many players
+        let threshold = imposter_win_threshold(&settings(), new_players.len());
+        settings.write().imposter_win_threshold = threshold;
+        players.set(new_players.clone());
+        round_number.set(1);
+        // Custom pairs may have been edited here, moving every pair to a new position
+        recent_pairs.write().clear();
+        solo.set(false);
+        
+        // Randomize starting player for new game
+        starting_player_index.set(get_random_starting_index(new_players.len()));
+    };
+
+    let mut start_game = move || {
+        let names = seat_names(&player_names(), player_count);
+        // Names are the only way players tell each other apart, so collisions block the start
+        if names.iter().all(|n| !normalize_name(n).is_empty()) && duplicate_name_indices(&names).is_empty() {
+            seat_players(&names);
+            game_screen.set(round_start_screen(&settings()));
+        }
+    };
+
+    // Numbered players and straight to the cards; the typed names are left alone for next time
+    let mut deal_straight_in = move |names: &[String]| {
+        seat_players(names);
+        if round_start_screen(&settings()) == GameScreen::CategorySelection {
+            selected_category_index.set(Some(random_category_index(settings().word_pack.as_deref())));
+        }
+        game_screen.set(GameScreen::CardView { current_player_index: 0 });
+    };
+    let quick_start = move |_| {
+        let names: Vec<String> = (1..=player_count).map(|n| (t.numbered_player)(n)).collect();
+        deal_straight_in(&names);
+    };
+    // Same quick deal, with the first seat as "You" and the rest played by the app
+    let practice_solo = move |_| {
+        let names: Vec<String> = (0..player_count)
+            .map(|seat| if seat == SOLO_SEAT { t.you_label.to_string() } else { (t.numbered_player)(seat + 1) })
+            .collect();
+        deal_straight_in(&names);
+        solo.set(true);
+    };
+
     rsx! {
         div { class: "setup-screen",
             div { class: "setup-header",
                 h1 { "🎮 Ultimate Imposter" }
-                p { class: "subtitle", "The Social Deduction Game" }
+                p { class: "subtitle", "{t.subtitle}" }
+                div { class: "language-picker",
+                    label { r#for: "language-select", "{t.language_label}" }
+                    select {
+                        id: "language-select",
+                        value: settings().lang.code(),
+                        onchange: move |evt| settings.write().lang = Lang::from_code(&evt.value()),
+                        for lang in Lang::ALL {
+                            option { key: "{lang.code()}", value: lang.code(), "{lang.native_name()}" }
+                        }
+                    }
+                }
+                // Up front, so players who need them can switch these on before reading on
+                div { class: "display-options",
+                    SettingToggle {
+                        label: t.large_text_label,
+                        description: t.large_text_description,
+                        checked: settings().accessibility.large_text,
+                        onchange: move |value| settings.write().accessibility.large_text = value,
+                    }
+                    SettingToggle {
+                        label: t.high_contrast_label,
+                        description: t.high_contrast_description,
+                        checked: settings().accessibility.high_contrast,
+                        onchange: move |value| settings.write().accessibility.high_contrast = value,
+                    }
+                    div { class: "setting-row setting-row-select",
+                        label { class: "setting-label", r#for: "display-mode-select", "{t.display_mode_label}" }
+                        select {
+                            id: "display-mode-select",
+                            class: "setting-select",
+                            value: if settings().display_mode == DisplayMode::Tv { "tv" } else { "phone" },
+                            onchange: move |evt| {
+                                settings.write().display_mode = if evt.value() == "tv" { DisplayMode::Tv } else { DisplayMode::Phone };
+                            },
+                            option { value: "phone", "{t.display_mode_phone}" }
+                            option { value: "tv", "{t.display_mode_tv}" }
+                        }
+                        p { class: "setting-description", "{t.display_mode_description}" }
+                    }
+                }
             }
             
             div { class: "player-count-section",
                 label { 
-                    "👥 Number of Players"
-                    span { class: "hint", "(minimum 3)" }
+                    "{t.number_of_players}"
+                    span { class: "hint", {(t.minimum_players)(MIN_PLAYERS)} }
                 }
+                // A text field, so whatever was typed reaches us and can be explained;
+                // a number field would silently report "abc" as empty
                 input {
-                    r#type: "number",
-                    min: "3",
-                    max: "10",
+                    r#type: "text",
+                    inputmode: "numeric",
+                    pattern: "[0-9]*",
                     value: "{player_count_input}",
                     oninput: move |e| {
+                        if let PlayerCountEntry::Valid(count) = read_player_count(&e.value()) {
+                            last_valid_count.set(count);
+                        }
                         player_count_input.set(e.value());
+                    },
+                    // Leaving the field settles on a count the game supports
+                    onchange: move |_| {
+                        match read_player_count(&player_count_input()) {
+                            PlayerCountEntry::Valid(_) => {}
+                            PlayerCountEntry::Clamped(count) => {
+                                last_valid_count.set(count);
+                                player_count_input.set(count.to_string());
+                            }
+                            PlayerCountEntry::Invalid => player_count_input.set(player_count.to_string()),
+                        }
                     }
                 }
+                match count_entry {
+                    PlayerCountEntry::Clamped(count) => rsx! {
+                        p { class: "count-clamped-hint", role: "status", {(t.player_count_clamped)(MIN_PLAYERS, MAX_PLAYERS, count)} }
+                    },
+                    // Empty is just mid-edit; anything else typed isn't a count at all
+                    PlayerCountEntry::Invalid if !player_count_input().trim().is_empty() => rsx! {
+                        p { class: "count-clamped-hint", role: "alert", {(t.player_count_not_a_number)(player_count)} }
+                    },
+                    _ => rsx! {},
+                }
             }
             
             div { class: "player-names-section",
-                h2 { "✏️ Player Names" }
-                div { class: "player-inputs-grid",
+                h2 { "{t.player_names_heading}" }
+                div { class: if player_count > LARGE_GROUP { "player-inputs-grid many-players" } else { "player-inputs-grid" },
+                    // Enter in any name field starts the game once every name is filled in
+                    onkeydown: move |evt| {
+                        if evt.key() == Key::Enter {
+                            evt.prevent_default();
+                            start_game();
+                        }
+                    },
                     for i in 0..player_count {
-                        div { class: "player-input",
+                        div { class: if duplicates.contains(&i) { "player-input duplicate-name" } else { "player-input" },
                             span { class: "player-number", "{i + 1}" }
                             input {
                                 r#type: "text",
-                                placeholder: "Enter name...",
+                                placeholder: "{t.enter_name_placeholder}",
                                 value: "{current_names.get(i).cloned().unwrap_or_default()}",
                                 oninput: move |e| {
                                     let mut names = player_names();
@@ -70,28 +221,222 @@ pub fn SetupScreen(
                         }
                     }
                 }
+                if !duplicates.is_empty() {
+                    div { class: "duplicate-names-warning", role: "alert",
+                        p { "{t.duplicate_names_warning}" }
+                        button {
+                            class: "auto-number-btn",
+                            onclick: move |_| {
+                                let mut names = player_names();
+                                let numbered = with_unique_suffixes(&seat_names(&names, player_count));
+                                names.splice(..player_count.min(names.len()), numbered);
+                                player_names.set(names);
+                            },
+                            "{t.number_automatically}"
+                        }
+                    }
+                }
             }
             
+            // The moderator isn't one of the seats above, so this sits apart from the game settings
+            div { class: "moderator-option",
+                SettingToggle {
+                    label: t.moderator_mode_label,
+                    description: t.moderator_mode_description,
+                    checked: settings().moderator_mode,
+                    onchange: move |value| settings.write().moderator_mode = value,
+                }
+            }
+
+            // Changes how the whole game is won, so it's on show rather than in the settings
+            div { class: if settings().single_vote_challenge { "challenge-option active" } else { "challenge-option" },
+                SettingToggle {
+                    label: t.single_vote_label,
+                    description: t.single_vote_description,
+                    checked: settings().single_vote_challenge,
+                    onchange: move |value| settings.write().single_vote_challenge = value,
+                }
+            }
+
+            SettingsPanel { settings, player_count }
+
             button {
                 class: "start-game-btn",
-                onclick: move |_| {
-                    let names = player_names();
-                    if names.iter().all(|n| !n.trim().is_empty()) {
-                        let new_players: Vec<Player> = names.iter().map(|name| Player {
-                            name: name.clone(),
-                            score: 0,
-                            is_eliminated: false,
-                        }).collect();
-                        players.set(new_players.clone());
-                        round_number.set(1);
-                        
-                        // Randomize starting player for new game
-                        starting_player_index.set(get_random_starting_index(new_players.len()));
-                        
-                        game_screen.set(GameScreen::CategorySelection);
+                onclick: move |_| start_game(),
+                "{t.start_game}"
+            }
+            button {
+                class: "quick-start-btn",
+                onclick: quick_start,
+                "{t.quick_start}"
+            }
+            p { class: "quick-start-hint", "{t.quick_start_hint}" }
+            button {
+                class: "quick-start-btn practice-solo-btn",
+                onclick: practice_solo,
+                "{t.practice_solo}"
+            }
+            p { class: "quick-start-hint", "{t.practice_solo_hint}" }
+            
+            div { class: "share-setup",
+                button {
+                    class: "share-setup-btn",
+                    onclick: move |_| {
+                        let config = SetupConfig {
+                            player_names: seat_names(&player_names(), player_count),
+                            settings: settings(),
+                        };
+                        let base = current_page_url().unwrap_or_default();
+                        share_link.set(Some(encode_setup_to_url(&config, &base)));
+                    },
+                    "{t.share_setup}"
+                }
+                if let Some(link) = share_link() {
+                    input {
+                        class: "share-link",
+                        r#type: "text",
+                        readonly: true,
+                        "aria-label": "{t.setup_link_label}",
+                        value: "{link}",
+                        onfocus: move |_| {
+                            let _ = document::eval("document.querySelector('.share-link')?.select();");
+                        },
+                    }
+                }
+            }
+            
+            if let Some(code) = pending_delete() {
+                ConfirmDialog {
+                    title: t.delete_saved_game_title,
+                    message: (t.delete_saved_game_message)(&code),
+                    confirm_label: t.confirm_delete_saved_game,
+                    lang: settings().lang,
+                    on_confirm: move |_| {
+                        delete_local_session(&code);
+                        local_sessions.set(list_local_sessions());
+                        pending_delete.set(None);
+                    },
+                    on_cancel: move |_| pending_delete.set(None),
+                }
+            }
+
+            if !other_sessions.is_empty() {
+                div { class: "saved-sessions",
+                    h2 { "{t.saved_games_heading}" }
+                    ul { class: "saved-session-list",
+                        for summary in other_sessions {
+                            li { key: "{summary.session_id}", class: "saved-session",
+                                div { class: "saved-session-info",
+                                    span { class: "saved-session-players", {summary.player_names.join(", ")} }
+                                    span { class: "hint",
+                                        {(t.saved_game_details)(&summary.session_id, summary.round_number, summary.player_names.len())}
+                                    }
+                                }
+                                button {
+                                    class: "restore-btn",
+                                    onclick: {
+                                        let code = summary.session_id.clone();
+                                        move |_| {
+                                            if let Some(state) = load_game_state(&code) {
+                                                on_restore.call(state);
+                                            }
+                                        }
+                                    },
+                                    "{t.resume_saved_game}"
+                                }
+                                button {
+                                    class: "saved-session-delete",
+                                    "aria-label": "{t.delete_saved_game_label}",
+                                    onclick: {
+                                        let code = summary.session_id.clone();
+                                        move |_| pending_delete.set(Some(code.clone()))
+                                    },
+                                    "🗑️"
+                                }
+                            }
+                        }
                     }
-                },
-                "🚀 Start Game"
+                }
+            }
+            
+            details { class: "restore-section",
+                summary { "{t.restore_or_import}" }
+                div { class: "restore-row",
+                    input {
+                        r#type: "text",
+                        placeholder: "{t.session_code_placeholder}",
+                        value: "{restore_code}",
+                        oninput: move |e| {
+                            restore_code.set(e.value());
+                            restore_error.set(None);
+                        }
+                    }
+                    button {
+                        class: "restore-btn",
+                        onclick: move |_| {
+                            let code = normalize_session_code(&restore_code());
+                            if code.is_empty() {
+                                return;
+                            }
+                            if let Some(state) = load_game_state(&code) {
+                                on_restore.call(state);
+                                return;
+                            }
+                            // Not in this browser - the code may be a rejoin code for a game on the server
+                            spawn(async move {
+                                match rejoin_server_game(&code).await {
+                                    Ok(state) => on_restore.call(state),
+                                    Err(error) => restore_error.set(Some(match error {
+                                        RejoinError::Unknown => t.no_saved_game,
+                                        RejoinError::Expired => t.rejoin_code_expired,
+                                        RejoinError::Unreadable => t.rejoin_save_unreadable,
+                                        RejoinError::Unreachable => t.rejoin_server_unreachable,
+                                    }.to_string())),
+                                }
+                            });
+                        },
+                        "{t.restore}"
+                    }
+                }
+                if let Some(error) = restore_error() {
+                    p { class: "restore-error", "{error}" }
+                }
+                div { class: "import-row",
+                    label { class: "import-label", r#for: "import-file", "{t.import_exported}" }
+                    input {
+                        id: "import-file",
+                        r#type: "file",
+                        accept: ".json,application/json",
+                        onchange: move |evt| async move {
+                            let Some(file) = evt.files().into_iter().next() else {
+                                return;
+                            };
+                            let result = match file.read_string().await {
+                                Ok(json) => import_game_json(&json),
+                                Err(_) => Err(ImportError::Unreadable),
+                            };
+                            match result {
+                                Ok(state) => {
+                                    import_error.set(None);
+                                    on_restore.call(state);
+                                }
+                                Err(error) => import_error.set(Some(error.message(settings().lang))),
+                            }
+                        },
+                    }
+                }
+            }
+            
+            if let Some(error) = import_error() {
+                div { class: "error-toast", role: "alert",
+                    span { "⚠️ {error}" }
+                    button {
+                        class: "error-toast-close",
+                        "aria-label": "{t.dismiss}",
+                        onclick: move |_| import_error.set(None),
+                        "✕"
+                    }
+                }
             }
         }
     }
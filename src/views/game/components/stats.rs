@@ -0,0 +1,101 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::GameScreen;
+use crate::views::game::stats::{load_stats, PlayerStats};
+
+/// Column the stats table is sorted by
+#[derive(Clone, Copy, PartialEq)]
+enum StatsSort {
+    Name,
+    GamesPlayed,
+    TimesImposter,
+    ImposterWins,
+    CivilianWins,
+}
+
+impl StatsSort {
+    fn value(self, stats: &PlayerStats) -> u32 {
+        match self {
+            StatsSort::Name => 0,
+            StatsSort::GamesPlayed => stats.games_played,
+            StatsSort::TimesImposter => stats.times_imposter,
+            StatsSort::ImposterWins => stats.imposter_wins,
+            StatsSort::CivilianWins => stats.civilian_wins,
+        }
+    }
+}
+
+/// Lifetime player statistics, gathered across every game played on this device
+#[component]
+pub fn StatsScreen(mut game_screen: Signal<GameScreen>, lang: Lang) -> Element {
+    let t = ui_text(lang);
+    let mut sort = use_signal(|| StatsSort::GamesPlayed);
+    let mut rows: Vec<PlayerStats> = load_stats().into_values().collect();
+    let sort_by = sort();
+    if sort_by == StatsSort::Name {
+        rows.sort_by_key(|s| s.name.to_lowercase());
+    } else {
+        rows.sort_by_key(|s| std::cmp::Reverse(sort_by.value(s)));
+    }
+
+    let columns = [
+        (StatsSort::Name, t.player_column),
+        (StatsSort::GamesPlayed, t.games_column),
+        (StatsSort::TimesImposter, t.imposter_column),
+        (StatsSort::ImposterWins, t.imposter_wins_column),
+        (StatsSort::CivilianWins, t.civilian_wins_column),
+    ];
+
+    rsx! {
+        div { class: "stats-screen",
+            h1 { "{t.player_stats}" }
+            p { class: "hint", "{t.stats_hint}" }
+
+            if rows.is_empty() {
+                p { class: "stats-empty", "{t.stats_empty}" }
+            } else {
+                table { class: "stats-table",
+                    thead {
+                        tr {
+                            for (column, title) in columns {
+                                th {
+                                    key: "{title}",
+                                    // Names sort A→Z; every count sorts highest first
+                                    "aria-sort": match (sort_by == column, column) {
+                                        (false, _) => "none",
+                                        (true, StatsSort::Name) => "ascending",
+                                        (true, _) => "descending",
+                                    },
+                                    button {
+                                        class: if sort_by == column { "stats-sort active" } else { "stats-sort" },
+                                        onclick: move |_| sort.set(column),
+                                        "{title}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        for row in rows.iter() {
+                            tr { key: "{row.name}",
+                                td { "{row.name}" }
+                                td { "{row.games_played}" }
+                                td { "{row.times_imposter}" }
+                                td { "{row.imposter_wins}" }
+                                td { "{row.civilian_wins}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "action-buttons",
+                button {
+                    class: "continue-btn",
+                    onclick: move |_| game_screen.set(GameScreen::GameScore),
+                    "{t.back_to_scoreboard}"
+                }
+            }
+        }
+    }
+}
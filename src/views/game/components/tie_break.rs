@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
+use crate::views::game::types::*;
+use crate::views::game::utils::{tie_break_mode, MAX_REVOTES};
+
+/// Shown when a secret ballot ends with two or more players sharing the most votes.
+/// `revotes` counts the revotes that already ended in this tie.
+#[component]
+pub fn TieBreakScreen(
+    tied_indices: Vec<usize>,
+    revotes: u32,
+    players: Signal<Vec<Player>>,
+    mut game_screen: Signal<GameScreen>,
+    mut round_number: Signal<i32>,
+    mut timer_remaining: Signal<Option<u32>>,
+    settings: Signal<Settings>,
+) -> Element {
+    let player_list = players();
+    let tied_names: Vec<String> = tied_indices.iter()
+        .filter_map(|&idx| player_list.get(idx).map(|p| p.name.clone()))
+        .collect();
+    let mode = tie_break_mode(&settings(), revotes);
+    let t = ui_text(settings().lang);
+
+    rsx! {
+        div { class: "tie-break-screen",
+            h1 { "{t.its_a_tie}" }
+            p { "{t.tied_players_intro}" }
+            ul { class: "tied-players",
+                for name in tied_names.iter() {
+                    li { key: "{name}", "{name}" }
+                }
+            }
+
+            match mode {
+                TieBreakMode::Revote => rsx! {
+                    p { class: "hint", "{t.revote_explainer}" }
+                    button {
+                        class: "continue-btn",
+                        onclick: move |_| {
+                            game_screen.set(GameScreen::SecretVoting {
+                                current_voter_index: 0,
+                                votes: Vec::new(),
+                                candidates: tied_indices.clone(),
+                                revotes: revotes + 1,
+                            });
+                        },
+                        "{t.revote}"
+                    }
+                },
+                TieBreakMode::NoElimination => rsx! {
+                    if revotes >= MAX_REVOTES {
+                        p { class: "hint", {(t.revotes_exhausted)(revotes)} }
+                    } else {
+                        p { class: "hint", "{t.no_elimination_explainer}" }
+                    }
+                    button {
+                        class: "continue-btn",
+                        onclick: move |_| {
+                            round_number.set(round_number() + 1);
+                            timer_remaining.set(None);
+                            game_screen.set(GameScreen::Voting);
+                        },
+                        "{t.back_to_discussion}"
+                    }
+                },
+            }
+        }
+    }
+}
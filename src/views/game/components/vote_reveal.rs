@@ -0,0 +1,88 @@
+use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
+use crate::views::game::types::*;
+use crate::views::game::utils::{focus_on_mount, vote_leaders};
+
+/// Delay between one bar starting to fill and the next
+const TALLY_STAGGER_MS: usize = 400;
+
+/// Secret ballot counts, most votes first, shown before the eviction is revealed
+#[component]
+pub fn VoteRevealScreen(
+    tally: Vec<(usize, u32)>,
+    revotes: u32,
+    players: Signal<Vec<Player>>,
+    mut game_screen: Signal<GameScreen>,
+    imposter_index: Signal<usize>,
+    settings: Signal<Settings>,
+) -> Element {
+    let t = ui_text(settings().lang);
+    let player_list = players();
+    let most = tally.iter().map(|&(_, count)| count).max().unwrap_or(1);
+    let leaders = vote_leaders(&tally);
+
+    rsx! {
+        div { class: "vote-reveal-screen",
+            h1 { "{t.the_votes}" }
+            ul { class: if settings().animations { "vote-tally animated" } else { "vote-tally" },
+                for (row, (index, count)) in tally.iter().copied().enumerate() {
+                    if let Some(player) = player_list.get(index) {
+                        li { key: "{index}", class: "tally-row",
+                            style: "--player-color: {player.color}",
+                            div { class: "tally-label",
+                                span { "{player.name}" }
+                                span { {(t.vote_count)(count)} }
+                            }
+                            div { class: "tally-bar",
+                                div {
+                                    class: "tally-bar-fill",
+                                    style: "width: {count * 100 / most}%; animation-delay: {row * TALLY_STAGGER_MS}ms",
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Same outcome the ballot always had: the single leader goes, a shared lead is a tie
+            button {
+                class: "proceed-btn",
+                onmounted: focus_on_mount,
+                onclick: move |_| match leaders.as_slice() {
+                    [] => game_screen.set(GameScreen::Voting),
+                    [eliminated_index] => game_screen.set(GameScreen::Elimination {
+                        eliminated_index: *eliminated_index,
+                        was_imposter: *eliminated_index == imposter_index(),
+                    }),
+                    _ => game_screen.set(GameScreen::TieBreak { tied_indices: leaders.clone(), revotes }),
+                },
+                "{t.reveal_eviction}"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::i18n::Lang;
+
+    #[test]
+    fn counts_are_listed_most_votes_first() {
+        fn app() -> Element {
+            let players = use_signal(|| named_players(&["Alex", "Dana", "Sam"]));
+            let game_screen = use_signal(|| GameScreen::Setup);
+            let imposter_index = use_signal(|| 1);
+            let settings = use_signal(|| Settings { lang: Lang::English, ..Settings::default() });
+            rsx! { VoteRevealScreen { tally: vec![(0, 3), (1, 1)], revotes: 0, players, game_screen, imposter_index, settings } }
+        }
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+        let alex = html.find("Alex").expect("leader missing");
+        let dana = html.find("Dana").expect("runner-up missing");
+        assert!(alex < dana, "most votes should come first: {html}");
+        assert!(html.contains("3 votes") && html.contains("1 vote<"), "counts missing: {html}");
+        assert!(!html.contains("Sam"), "players without votes aren't listed: {html}");
+    }
+}
@@ -1,5 +1,11 @@
 use dioxus::prelude::*;
+use crate::views::game::i18n::ui_text;
 use crate::views::game::types::*;
+use crate::views::game::components::{ConfirmDialog, ModeratorPanel, PlayerRoster, WordReminder};
+use crate::views::game::utils::{active_player_order, format_mm_ss, now_ms, round_start_screen, skips_remaining, sleep_ms, LARGE_GROUP};
+
+/// How long an armed evict button waits for its confirming second tap
+const EVICT_CONFIRM_WINDOW_MS: u32 = 3000;
 
 /// Voting screen where all players collectively decide who to evict
 #[component]
@@ -10,65 +16,125 @@ pub fn VotingScreen(
     mut cards: Signal<Vec<GameCard>>,
     current_category: Signal<Option<(String, String)>>,
     starting_player_index: Signal<usize>,
+    settings: Signal<Settings>,
+    mut grace_round_pending: Signal<bool>,
+    mut timer_remaining: Signal<Option<u32>>,
+    mut timer_paused: Signal<bool>,
+    mut round_number: Signal<i32>,
+    mut history: Signal<Vec<usize>>,
+    mut hidden_catch: Signal<Option<usize>>,
+    skipped_players: Signal<Vec<usize>>,
+    turn_order: Signal<Vec<usize>>,
+    mut consecutive_skips: Signal<usize>,
+    solo: Signal<bool>,
+    twist_active: Signal<bool>,
 ) -> Element {
     let player_list = players();
     let mut show_restart_confirmation = use_signal(|| false);
+    let mut show_end_game_confirmation = use_signal(|| false);
+    // The one evict button waiting for a second tap, and when it was armed
+    let mut armed_evict = use_signal(|| None::<(usize, f64)>);
     
-    // Only show non-eliminated players
-    let active_indices: Vec<usize> = player_list.iter()
-        .enumerate()
-        .filter(|(_, p)| !p.is_eliminated)
-        .map(|(i, _)| i)
-        .collect();
-    
-    // Rotate voting order based on starting_player_index
-    let total_players = player_list.len();
-    let start_idx = starting_player_index() % total_players;
+    // Discussion countdown: starts fresh each voting round, or carries on from a saved value
+    use_future(move || async move {
+        if timer_remaining.peek().is_none() {
+            timer_remaining.set(settings.peek().timer_seconds);
+            timer_paused.set(false);
+        }
+        loop {
+            sleep_ms(1000).await;
+            // A paused timer keeps its remaining seconds and picks up from there on resume
+            if *timer_paused.peek() {
+                continue;
+            }
+            let remaining = *timer_remaining.peek();
+            if let Some(seconds) = remaining.filter(|s| *s > 0) {
+                timer_remaining.set(Some(seconds - 1));
+            }
+        }
+    });
     
-    // Create rotated player list for voting display
-    let mut rotated_player_data: Vec<(usize, String)> = Vec::new();
-    for i in 0..active_indices.len() {
-        let idx = active_indices[(start_idx + i) % active_indices.len()];
-        rotated_player_data.push((idx, player_list[idx].name.clone()));
-    }
+    // Only show non-eliminated players, rotated so the round's starting player goes first
+    let rotated_player_data: Vec<(usize, String, String)> = active_player_order(&player_list, starting_player_index(), &turn_order())
+        .into_iter()
+        .map(|idx| (idx, player_list[idx].name.clone(), player_list[idx].color.clone()))
+        .collect();
+    // Nobody to pass a ballot to when practising alone, so the player decides openly
+    let secret_voting = settings().secret_voting && !solo();
+    let skips_left = skips_remaining(&settings(), consecutive_skips());
+    let t = ui_text(settings().lang);
     
     rsx! {
         div { class: "voting-screen",
             // Restart confirmation dialog
             if show_restart_confirmation() {
-                div { class: "confirmation-overlay",
-                    div { class: "confirmation-dialog",
-                        h2 { "⚠️ Restart Round?" }
-                        p { "This will reset the current round and go back to category selection. Progress will be lost." }
-                        div { class: "confirmation-buttons",
-                            button {
-                                class: "confirm-yes-btn",
-                                onclick: move |_| {
-                                    // Reset round state
-                                    cards.set(Vec::new());
-                                    show_restart_confirmation.set(false);
-                                    game_screen.set(GameScreen::CategorySelection);
-                                },
-                                "Yes, Restart Round"
-                            }
-                            button {
-                                class: "confirm-no-btn",
-                                onclick: move |_| {
-                                    show_restart_confirmation.set(false);
-                                },
-                                "Cancel"
-                            }
+                ConfirmDialog {
+                    title: t.restart_round_title,
+                    message: t.restart_round_message,
+                    confirm_label: t.confirm_restart_round,
+                    lang: settings().lang,
+                    on_confirm: move |_| {
+                        // Reset round state
+                        cards.set(Vec::new());
+                        timer_remaining.set(None);
+                        consecutive_skips.set(0);
+                        show_restart_confirmation.set(false);
+                        game_screen.set(round_start_screen(&settings()));
+                    },
+                    on_cancel: move |_| show_restart_confirmation.set(false),
+                }
+            }
+
+            // Stopping here leaves the round unscored; the scoreboard's Next Round deals a fresh one
+            if show_end_game_confirmation() {
+                ConfirmDialog {
+                    title: t.end_game_title,
+                    message: t.end_game_message,
+                    confirm_label: t.confirm_end_game,
+                    lang: settings().lang,
+                    on_confirm: move |_| {
+                        timer_remaining.set(None);
+                        consecutive_skips.set(0);
+                        show_end_game_confirmation.set(false);
+                        game_screen.set(GameScreen::GameScore);
+                    },
+                    on_cancel: move |_| show_end_game_confirmation.set(false),
+                }
+            }
+            
+            h1 { "{t.discussion_and_voting}" }
+            
+            if let Some(seconds) = timer_remaining() {
+                div { class: if seconds == 0 { "discussion-timer times-up" } else { "discussion-timer" },
+                    span { class: "timer-value", "⏱️ {format_mm_ss(seconds)}" }
+                    if seconds == 0 {
+                        span { class: "timer-label", "{t.times_up}" }
+                    } else {
+                        button {
+                            class: "timer-pause-btn",
+                            onclick: move |_| timer_paused.set(!timer_paused()),
+                            if timer_paused() { "{t.resume}" } else { "{t.pause}" }
+                        }
+                        if timer_paused() {
+                            span { class: "timer-label", "{t.paused}" }
                         }
                     }
                 }
             }
-            
-            h1 { "🗳️ Discussion & Voting" }
+
+            PlayerRoster { players: player_list.clone(), leaving: None, lang: settings().lang }
+
+            if settings().single_vote_challenge {
+                p { class: "challenge-banner", role: "note", "{t.single_vote_banner}" }
+            }
+            if twist_active() {
+                p { class: "twist-banner", role: "note", "{t.twist_banner}" }
+            }
             
             div { class: "voting-instructions",
-                p { "💬 Discussion order (everyone explains their word):" }
+                p { "{t.discussion_order}" }
                 div { class: "discussion-order",
-                    for (order_num, (_idx, player_name)) in rotated_player_data.iter().enumerate() {
+                    for (order_num, (_idx, player_name, _color)) in rotated_player_data.iter().enumerate() {
                         span { 
                             key: "{order_num}",
                             class: "discussion-order-item",
@@ -76,37 +142,168 @@ pub fn VotingScreen(
                         }
                     }
                 }
-                p { class: "hint", "After discussion, tap on the player card you all agreed to evict." }
+                if solo() {
+                    p { class: "hint", "{t.solo_vote_hint}" }
+                } else if secret_voting {
+                    p { class: "hint", "{t.secret_vote_hint}" }
+                } else {
+                    p { class: "hint", "{t.open_vote_hint}" }
+                }
             }
             
-            div { class: "players-voting-list",
-                for &(player_idx, ref player_name) in rotated_player_data.iter() {
-                    div { class: "player-voting-card",
-                        div { class: "player-info",
-                            h3 { "{player_name}" }
+            if grace_round_pending() {
+                div { class: "grace-round",
+                    h2 { "{t.grace_round}" }
+                    p { "{t.grace_round_hint}" }
+                    button {
+                        class: "continue-btn",
+                        onclick: move |_| grace_round_pending.set(false),
+                        "{t.continue_to_real_vote}"
+                    }
+                }
+            } else if secret_voting {
+                button {
+                    class: "continue-btn",
+                    onclick: move |_| {
+                        timer_remaining.set(None);
+                        game_screen.set(GameScreen::SecretVoting {
+                            current_voter_index: 0,
+                            votes: Vec::new(),
+                            candidates: Vec::new(),
+                            revotes: 0,
+                        });
+                    },
+                    "{t.start_secret_ballot}"
+                }
+            } else {
+                div { class: if rotated_player_data.len() > LARGE_GROUP { "players-voting-list many-players" } else { "players-voting-list" },
+                    for &(player_idx, ref player_name, ref color) in rotated_player_data.iter() {
+                        div { class: "player-voting-card",
+                            style: "--player-color: {color}",
+                            div { class: "player-info",
+                                h3 { "{player_name}" }
+                                if skipped_players().contains(&player_idx) {
+                                    span { class: "skipped-tag", "{t.skipped_tag}" }
+                                }
+                            }
+                            // First tap arms this player's button; only a second tap in time evicts
+                            button {
+                                class: if armed_evict().is_some_and(|(armed, _)| armed == player_idx) { "evict-btn armed" } else { "evict-btn" },
+                                onclick: move |_| {
+                                    let now = now_ms();
+                                    let confirmed = armed_evict().is_some_and(|(armed, at)| {
+                                        armed == player_idx && now - at < EVICT_CONFIRM_WINDOW_MS as f64
+                                    });
+                                    if !confirmed {
+                                        armed_evict.set(Some((player_idx, now)));
+                                        spawn(async move {
+                                            sleep_ms(EVICT_CONFIRM_WINDOW_MS).await;
+                                            // Leave it alone if another tap has re-armed since
+                                            if armed_evict.peek().is_some_and(|(_, at)| at == now) {
+                                                armed_evict.set(None);
+                                            }
+                                        });
+                                        return;
+                                    }
+                                    armed_evict.set(None);
+                                    let was_imposter = player_idx == imposter_index();
+                                    // The next voting round gets a full timer
+                                    timer_remaining.set(None);
+                                    game_screen.set(GameScreen::Elimination { 
+                                        eliminated_index: player_idx,
+                                        was_imposter 
+                                    });
+                                },
+                                if armed_evict().is_some_and(|(armed, _)| armed == player_idx) {
+                                    {(t.confirm_evict)(player_name)}
+                                } else {
+                                    "{t.evict}"
+                                }
+                            }
                         }
-                        button {
-                            class: "evict-btn",
-                            onclick: move |_| {
-                                let was_imposter = player_idx == imposter_index();
-                                game_screen.set(GameScreen::Elimination { 
-                                    eliminated_index: player_idx,
-                                    was_imposter 
-                                });
-                            },
-                            "Evict"
+                    }
+                }
+            }
+            
+            // Nobody leaves this time, but only a few times in a row so the game can't stall.
+            // The single-vote challenge has no vote to spare.
+            if !grace_round_pending() && settings().max_consecutive_skips > 0 && !settings().single_vote_challenge {
+                div { class: "no-evict",
+                    button {
+                        class: "no-evict-btn",
+                        disabled: skips_left == 0,
+                        onclick: move |_| {
+                            consecutive_skips += 1;
+                            round_number += 1;
+                            // Still on this screen, so restart the countdown by hand
+                            timer_remaining.set(settings().timer_seconds);
+                            timer_paused.set(false);
+                        },
+                        "{t.no_evict_this_round}"
+                    }
+                    p { class: "hint",
+                        if skips_left == 0 {
+                            "{t.skips_used_up}"
+                        } else {
+                            {(t.skips_left)(skips_left)}
                         }
                     }
                 }
             }
+
+            WordReminder {
+                players: player_list.clone(),
+                cards: cards(),
+                location_mode: settings().game_mode == GameMode::Location,
+                hide_imposter_identity: settings().hide_imposter_identity,
+                lang: settings().lang,
+            }
+
+            if settings().moderator_mode {
+                ModeratorPanel {
+                    players: player_list.clone(),
+                    cards: cards(),
+                    imposter_index: imposter_index(),
+                    lang: settings().lang,
+                }
+            }
             
             div { class: "voting-footer",
+                if let Some((last_evicted, last_name)) = history().last()
+                    .and_then(|&idx| player_list.get(idx).map(|p| (idx, p.name.clone())))
+                {
+                    button {
+                        class: "revert-round-btn",
+                        onclick: move |_| {
+                            // Bring the last evicted player back and return to the vote they were evicted in.
+                            // Points are only awarded when a round ends, so there is no score to roll back.
+                            history.write().pop();
+                            // Undoing a blind catch puts the imposter back in play
+                            if last_evicted == imposter_index() {
+                                hidden_catch.set(None);
+                            }
+                            if let Some(player) = players.write().get_mut(last_evicted) {
+                                player.is_eliminated = false;
+                            }
+                            round_number.set((round_number() - 1).max(1));
+                            // Still on this screen, so restart the countdown by hand
+                            timer_remaining.set(settings().timer_seconds);
+                            timer_paused.set(false);
+                        },
+                        {(t.revert_last_eviction)(&last_name)}
+                    }
+                }
                 button {
                     class: "restart-round-btn",
                     onclick: move |_| {
                         show_restart_confirmation.set(true);
                     },
-                    "🔄 Restart Round"
+                    "{t.restart_round}"
+                }
+                button {
+                    class: "end-game-btn",
+                    onclick: move |_| show_end_game_confirmation.set(true),
+                    "{t.end_game}"
                 }
             }
         }
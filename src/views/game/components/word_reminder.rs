@@ -0,0 +1,89 @@
+use dioxus::prelude::*;
+use crate::views::game::components::PlayerCard;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ReminderStep {
+    Hidden,
+    ChoosePlayer,
+    PassDevice(usize),
+    Revealed(usize),
+}
+
+/// Lets a player who forgot their word see their own card again mid-discussion.
+/// The device is handed to that player first, just like the original reveal.
+#[component]
+pub fn WordReminder(
+    players: Vec<Player>,
+    cards: Vec<GameCard>,
+    location_mode: bool,
+    hide_imposter_identity: bool,
+    lang: Lang,
+) -> Element {
+    let t = ui_text(lang);
+    let mut step = use_signal(|| ReminderStep::Hidden);
+    let name_of = {
+        let players = players.clone();
+        move |index: usize| players.get(index).map(|p| p.name.clone()).unwrap_or_default()
+    };
+
+    rsx! {
+        div { class: "word-reminder",
+            match step() {
+                ReminderStep::Hidden => rsx! {
+                    button {
+                        class: "role-check-btn",
+                        onclick: move |_| step.set(ReminderStep::ChoosePlayer),
+                        "{t.remind_me}"
+                    }
+                },
+                ReminderStep::ChoosePlayer => rsx! {
+                    div { class: "role-check-panel",
+                        p { "{t.who_needs_reminder}" }
+                        div { class: "reminder-players",
+                            for (i, player) in players.iter().enumerate().filter(|(_, p)| !p.is_eliminated) {
+                                button {
+                                    key: "{i}",
+                                    class: "reminder-player-btn",
+                                    style: "--player-color: {player.color}",
+                                    onclick: move |_| step.set(ReminderStep::PassDevice(i)),
+                                    "{player.name}"
+                                }
+                            }
+                        }
+                        button {
+                            class: "confirm-no-btn",
+                            onclick: move |_| step.set(ReminderStep::Hidden),
+                            "{t.cancel}"
+                        }
+                    }
+                },
+                ReminderStep::PassDevice(index) => rsx! {
+                    div { class: "role-check-panel",
+                        p { {(t.pass_device_only_to)(&name_of(index))} }
+                        button {
+                            class: "reveal-btn",
+                            onclick: move |_| step.set(ReminderStep::Revealed(index)),
+                            {(t.show_my_card_as)(&name_of(index))}
+                        }
+                    }
+                },
+                ReminderStep::Revealed(index) => rsx! {
+                    div { class: "role-check-panel",
+                        if let Some(card) = cards.get(index).cloned() {
+                            PlayerCard { card, location_mode, hide_imposter_identity, show_tip: false, lang }
+                        } else {
+                            p { "{t.card_unavailable}" }
+                        }
+                        button {
+                            class: "next-btn",
+                            onclick: move |_| step.set(ReminderStep::Hidden),
+                            "{t.hide_and_pass_back}"
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::views::game::utils::normalize_name;
+use std::collections::BTreeSet;
+
+const FLAGGED_PAIRS_STORAGE_KEY: &str = "ultimate_imposter_flagged_pairs";
+
+/// Word pairs flagged as bad on this device, by `pair_key`
+pub type FlaggedPairs = BTreeSet<String>;
+
+/// Key for a word pair that doesn't depend on which word went to the imposter,
+/// so a flag holds however the pair is dealt (and survives edits to its list)
+pub fn pair_key(first: &str, second: &str) -> String {
+    let mut words = [normalize_name(first).to_lowercase(), normalize_name(second).to_lowercase()];
+    words.sort();
+    words.join(" / ")
+}
+
+/// Flag the pair, or unflag it if it already was; returns whether it's flagged now
+pub fn toggle_flag(flagged: &mut FlaggedPairs, first: &str, second: &str) -> bool {
+    let key = pair_key(first, second);
+    if flagged.remove(&key) {
+        return false;
+    }
+    flagged.insert(key);
+    true
+}
+
+/// Load the flagged pairs from localStorage (none when nothing is stored)
+pub fn load_flagged_pairs() -> FlaggedPairs {
+    #[cfg(target_arch = "wasm32")]
+    {
+        crate::views::game::persistence::browser_storage()
+            .and_then(|storage| storage.get_item(FLAGGED_PAIRS_STORAGE_KEY).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = FLAGGED_PAIRS_STORAGE_KEY;
+        FlaggedPairs::new()
+    }
+}
+
+/// Save the flagged pairs to localStorage
+pub fn save_flagged_pairs(_flagged: &FlaggedPairs) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = crate::views::game::persistence::browser_storage() {
+            if let Ok(json) = serde_json::to_string(_flagged) {
+                let _ = storage.set_item(FLAGGED_PAIRS_STORAGE_KEY, &json);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_pair_is_the_same_whichever_way_round() {
+        assert_eq!(pair_key("Coffee", "Tea"), pair_key(" tea", "COFFEE"));
+        assert_ne!(pair_key("Coffee", "Tea"), pair_key("Coffee", "Espresso"));
+
+        let mut flagged = FlaggedPairs::new();
+        assert!(toggle_flag(&mut flagged, "Coffee", "Tea"));
+        assert!(flagged.contains(&pair_key("Tea", "Coffee")));
+        assert!(!toggle_flag(&mut flagged, "Tea", "Coffee"));
+        assert!(flagged.is_empty());
+    }
+}
@@ -0,0 +1,774 @@
+use crate::views::game::flagged_pairs::{load_flagged_pairs, FlaggedPairs};
+use crate::views::game::i18n::{ui_text, Lang, UiText};
+use crate::views::game::persistence::generate_session_id;
+use crate::views::game::types::{CardType, DealProof, GameCard, GameEvent, GameMode, GameScreen, GameState, ImposterMode, Player, SealedDeal, Settings};
+use crate::views::game::utils::{
+    all_builtin_pairs, apply_imposter_mode, challenge_bonus, generate_cards_for_category, generate_cards_for_custom_pairs, generate_cards_for_scenario,
+    imposter_weights, imposter_win_threshold, new_turn_order, normalize_name, random_category_index, random_u64, remember_pair, round_speed_bonus, round_start_screen, seat_for_roll, seed_commitment, twist_imposter_word,
+    CIVILIAN_WIN_POINTS, IMPOSTER_WIN_POINTS,
+};
+
+/// Most screen changes `GameState::event_log` keeps; older ones are dropped first
+pub const EVENT_LOG_CAP: usize = 500;
+
+// Game rules with no UI attached. Each step takes the whole GameState, so the
+// screens only decide *when* a step happens and Game() applies the result.
+
+/// Where a confirmed eviction left the game
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum EvictionOutcome {
+    ImposterCaught, // Civilians win the round
+    ImposterWins, // Too few players left to outvote the imposter (or the imposter's last guess was right)
+    ImposterGuessing, // The imposter is out but gets one guess at the civilian word first
+    Continue, // A civilian went; the group votes again
+}
+
+impl EvictionOutcome {
+    pub fn game_over(self) -> bool {
+        matches!(self, EvictionOutcome::ImposterCaught | EvictionOutcome::ImposterWins)
+    }
+}
+
+/// The word an evicted imposter has to guess, when the comeback rule is on and
+/// the round has a civilian word to guess
+pub fn comeback_word(settings: &Settings, round_words: Option<&(String, String)>) -> Option<String> {
+    let (civilian_word, _) = round_words?;
+    (settings.comeback_enabled && !civilian_word.is_empty()).then(|| civilian_word.clone())
+}
+
+/// Deal the round's cards from the current settings.
+/// Returns false (and changes nothing) when cards are already dealt, there are no
+/// players, or a word-pair round has no category to deal from yet.
+pub fn deal_round(state: &mut GameState) -> bool {
+    let player_count = state.players.len();
+    // A sealed deal for a different number of players is stale, so it's dealt again
+    let sealed = state.sealed_deal.as_ref().is_some_and(|deal| deal.civilian_cards.len() == player_count);
+    if !state.cards.is_empty() || sealed || player_count == 0 {
+        return false;
+    }
+    let settings = state.settings.clone();
+    let weights = if settings.fair_rotation { imposter_weights(&state.times_imposter, player_count) } else { Vec::new() };
+    let flagged = if settings.skip_flagged_pairs { load_flagged_pairs() } else { FlaggedPairs::new() };
+    let mut twist_active = false;
+
+    // Location rounds deal a scenario instead of a word pair
+    let (cards, imposter_index, round_words, category, dealt_pair) = if settings.game_mode == GameMode::Location {
+        let (cards, imposter_index, location, icon) = generate_cards_for_scenario(player_count, &weights);
+        let category = (ui_text(settings.lang).location_category.to_string(), icon);
+        (cards, imposter_index, (location, String::new()), category, None)
+    } else {
+        // Players' own pairs take priority over the built-in categories
+        let (mut cards, imposter_index, name, icon, dealt) = if !settings.custom_pairs.is_empty() {
+            generate_cards_for_custom_pairs(player_count, &settings.custom_pairs, &state.recent_pairs, &weights, &flagged, settings.lang)
+        } else {
+            if settings.random_category_per_round {
+                state.selected_category_index = Some(random_category_index(settings.word_pack.as_deref()));
+            }
+            let Some(category_index) = state.selected_category_index else {
+                return false;
+            };
+            generate_cards_for_category(player_count, category_index, settings.difficulty, &state.recent_pairs, &weights, &flagged)
+        };
+        remember_pair(&mut state.recent_pairs, dealt);
+        let word_of = |cards: &[GameCard], card_type: CardType| {
+            cards.iter().find(|c| c.card_type == card_type).map(|c| c.word.clone()).unwrap_or_default()
+        };
+        // The pair as dealt, before the imposter's card loses its word, so it can be flagged
+        let dealt_pair = (word_of(&cards, CardType::Normal), word_of(&cards, CardType::Imposter));
+        // Only a similar-word imposter keeps a word to twist; the other modes blank it anyway
+        if settings.twist_round && settings.imposter_mode == ImposterMode::SimilarWord {
+            let pool = if settings.custom_pairs.is_empty() { all_builtin_pairs() } else { settings.custom_pairs.clone() };
+            twist_active = twist_imposter_word(&mut cards, &pool);
+        }
+        apply_imposter_mode(&mut cards, settings.imposter_mode);
+
+        // Remember this round's words (civilian word, imposter word) for the round-end reveal
+        let round_words = (word_of(&cards, CardType::Normal), word_of(&cards, CardType::Imposter));
+        (cards, imposter_index, round_words, (name, icon), Some(dealt_pair))
+    };
+
+    state.opened_deal = None;
+    if settings.blind_deal {
+        state.sealed_deal = Some(seal_deal(cards, imposter_index, weights));
+    } else {
+        state.sealed_deal = None;
+        state.times_imposter.resize(player_count, 0);
+        state.times_imposter[imposter_index] += 1;
+        state.cards = cards;
+        state.imposter_index = imposter_index;
+    }
+    state.current_round_words = Some(round_words);
+    state.dealt_pair = dealt_pair;
+    state.twist_active = twist_active;
+    state.current_category = Some(category);
+    // The warm-up vote opens the game, not every round after it
+    state.grace_round_pending = settings.grace_round && state.round_number <= 1;
+    state.history.clear();
+    state.hidden_catch = None;
+    state.skipped_players.clear();
+    state.turn_order = new_turn_order(player_count, &settings);
+    true
+}
+
+/// Set a fresh deal aside for blind dealing: the dealt imposter card is kept, but its
+/// seat is forgotten and left to a new seed, so it isn't known until the cards are opened
+fn seal_deal(mut cards: Vec<GameCard>, imposter_index: usize, weights: Vec<u32>) -> SealedDeal {
+    let imposter_card = cards[imposter_index].clone();
+    // Whoever ends up on the dealt imposter's seat as a civilian gets a neighbour's card
+    cards[imposter_index] = cards[(imposter_index + 1) % cards.len()].clone();
+    let seed = random_u64();
+    SealedDeal { seed, commitment: seed_commitment(seed), imposter_card, civilian_cards: cards, weights }
+}
+
+/// The seat a sealed deal gives the imposter card to; fixed by its seed
+fn sealed_imposter_seat(deal: &SealedDeal) -> usize {
+    seat_for_roll(deal.seed, deal.civilian_cards.len(), &deal.weights)
+}
+
+/// The card a sealed deal shows one seat, worked out for that seat alone
+pub fn sealed_card(deal: &SealedDeal, seat: usize) -> Option<GameCard> {
+    if seat == sealed_imposter_seat(deal) {
+        Some(deal.imposter_card.clone())
+    } else {
+        deal.civilian_cards.get(seat).cloned()
+    }
+}
+
+/// A seat's card this round, whether the deal is open or still sealed
+pub fn card_for_seat(state: &GameState, seat: usize) -> Option<GameCard> {
+    match &state.sealed_deal {
+        Some(deal) => sealed_card(deal, seat),
+        None => state.cards.get(seat).cloned(),
+    }
+}
+
+/// Everyone has looked: write a sealed deal into the round's cards and imposter.
+/// Does nothing for a deal that was never sealed.
+pub fn open_sealed_deal(state: &mut GameState) {
+    let Some(deal) = state.sealed_deal.take() else {
+        return;
+    };
+    let seat = sealed_imposter_seat(&deal);
+    let mut cards = deal.civilian_cards;
+    cards[seat] = deal.imposter_card;
+    state.times_imposter.resize(cards.len(), 0);
+    state.times_imposter[seat] += 1;
+    state.cards = cards;
+    state.imposter_index = seat;
+    state.opened_deal = Some(DealProof { seed: deal.seed, commitment: deal.commitment });
+}
+
+/// Carry out a confirmed eviction: mark the player out, award points if the
+/// game is decided, and move to the next screen.
+/// With `reveal_role_on_eviction` off, catching the imposter doesn't end the round:
+/// voting carries on blind until the imposter-wins threshold, and the catch is
+/// settled then, as if the round had ended when it happened.
+/// In the single-vote challenge the first eviction always ends the round.
+pub fn resolve_eviction(state: &mut GameState, eliminated_index: usize) -> EvictionOutcome {
+    // A real eviction ends any run of skipped votes
+    state.consecutive_skips = 0;
+    if let Some(player) = state.players.get_mut(eliminated_index) {
+        player.is_eliminated = true;
+    }
+    let remaining = state.players.iter().filter(|p| !p.is_eliminated).count();
+    let last_vote = state.settings.single_vote_challenge
+        || remaining <= imposter_win_threshold(&state.settings, state.players.len());
+
+    if eliminated_index == state.imposter_index {
+        if state.settings.reveal_role_on_eviction || last_vote {
+            return imposter_caught(state, state.history.len());
+        }
+        state.hidden_catch = Some(state.history.len());
+        return continue_voting(state, eliminated_index);
+    }
+
+    // Check if the group is down to the imposter-wins threshold (or used its one vote)
+    if last_vote {
+        if let Some(earlier_evictions) = state.hidden_catch {
+            state.history.push(eliminated_index);
+            return imposter_caught(state, earlier_evictions);
+        }
+        if let Some(imposter) = state.players.get_mut(state.imposter_index) {
+            award(imposter, IMPOSTER_WIN_POINTS);
+        }
+        state.game_screen = GameScreen::RoundEnd { imposter_found: false, game_over: true };
+        return EvictionOutcome::ImposterWins;
+    }
+
+    continue_voting(state, eliminated_index)
+}
+
+/// Continue to next voting round, remembering the eviction so it can be reverted
+fn continue_voting(state: &mut GameState, eliminated_index: usize) -> EvictionOutcome {
+    state.history.push(eliminated_index);
+    state.round_number += 1;
+    state.game_screen = GameScreen::Voting;
+    EvictionOutcome::Continue
+}
+
+/// The imposter went out after `earlier_evictions` civilians: the civilians win,
+/// unless the comeback rule gives the imposter a guess first
+fn imposter_caught(state: &mut GameState, earlier_evictions: usize) -> EvictionOutcome {
+    // Nobody scores until the imposter has had their last guess
+    if let Some(civilian_word) = comeback_word(&state.settings, state.current_round_words.as_ref()) {
+        state.game_screen = GameScreen::ImposterGuess { civilian_word };
+        return EvictionOutcome::ImposterGuessing;
+    }
+    let bonus = round_speed_bonus(&state.settings, earlier_evictions);
+    pay_civilians(state, bonus, earlier_evictions);
+    state.game_screen = GameScreen::RoundEnd { imposter_found: true, game_over: true };
+    EvictionOutcome::ImposterCaught
+}
+
+/// Settle an evicted imposter's one guess at the civilian word. Case and spacing
+/// don't matter; a right guess wins the round for the imposter, anything else
+/// (including no guess) is a normal civilian win.
+pub fn resolve_imposter_guess(state: &mut GameState, civilian_word: &str, guess: &str) -> EvictionOutcome {
+    let canonical = |word: &str| normalize_name(word).to_lowercase();
+    let guessed = !canonical(guess).is_empty() && canonical(guess) == canonical(civilian_word);
+    if guessed {
+        if let Some(imposter) = state.players.get_mut(state.imposter_index) {
+            award(imposter, IMPOSTER_WIN_POINTS);
+        }
+        state.game_screen = GameScreen::RoundEnd { imposter_found: false, game_over: true };
+        return EvictionOutcome::ImposterWins;
+    }
+    let earlier_evictions = state.hidden_catch.unwrap_or(state.history.len());
+    let bonus = round_speed_bonus(&state.settings, earlier_evictions);
+    pay_civilians(state, bonus, earlier_evictions);
+    state.game_screen = GameScreen::RoundEnd { imposter_found: true, game_over: true };
+    EvictionOutcome::ImposterCaught
+}
+
+/// Award the civilians still in when the imposter went out, with the challenge
+/// bonus when it was one; players evicted before that get nothing. Anyone voted
+/// out blind after the catch (history past `earlier_evictions`) was still in when
+/// it happened, so they're paid too.
+fn pay_civilians(state: &mut GameState, bonus: i32, earlier_evictions: usize) {
+    let out_after_catch = state.history.get(earlier_evictions..).unwrap_or_default();
+    for (i, player) in state.players.iter_mut().enumerate() {
+        if i != state.imposter_index && (!player.is_eliminated || out_after_catch.contains(&i)) {
+            award(player, CIVILIAN_WIN_POINTS + bonus + challenge_bonus(&state.settings));
+        }
+    }
+}
+
+/// Add round points to a player's score, keeping track of this round's share
+fn award(player: &mut Player, points: i32) {
+    player.score += points;
+    player.last_round_delta += points;
+}
+
+/// Bring everyone back, pass the first turn to the next player and head for a new deal
+pub fn start_next_round(state: &mut GameState) {
+    for player in state.players.iter_mut() {
+        player.is_eliminated = false;
+        player.last_round_delta = 0;
+    }
+    state.cards.clear();
+    state.sealed_deal = None;
+    state.round_number += 1;
+    if !state.players.is_empty() {
+        state.starting_player_index = (state.starting_player_index + 1) % state.players.len();
+    }
+    state.game_screen = round_start_screen(&state.settings);
+}
+
+/// Clear the finished game and head back to setup. Everything is reset to its
+/// default except what the table set up and wants to keep: names, settings
+/// (display choices and moderator mode included) and the event log. The new game gets its own session, so the
+/// finished one stays saved and can be picked again from setup.
+pub fn start_new_game(state: &mut GameState) {
+    let old = std::mem::take(state);
+    *state = GameState {
+        schema_version: old.schema_version,
+        session_id: generate_session_id(),
+        game_screen: GameScreen::Setup,
+        player_count_input: old.player_count_input,
+        player_names: old.player_names,
+        round_number: 1,
+        settings: old.settings,
+        event_log: old.event_log,
+        ..GameState::default()
+    };
+}
+
+/// How a finished round went for the imposter
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RoundEnding {
+    Caught,
+    Escaped, // Never evicted
+    WordGuessed, // Evicted, then won with the civilian word (the comeback rule)
+}
+
+impl RoundEnding {
+    /// Read from the round-end result and the imposter's seat: only a comeback win
+    /// leaves the winning imposter evicted
+    pub fn of(imposter_found: bool, imposter: Option<&Player>) -> RoundEnding {
+        match (imposter_found, imposter.is_some_and(|p| p.is_eliminated)) {
+            (true, _) => RoundEnding::Caught,
+            (false, true) => RoundEnding::WordGuessed,
+            (false, false) => RoundEnding::Escaped,
+        }
+    }
+}
+
+/// Evicting votes a round took; a blind catch counts up to the vote that found the imposter
+pub fn votes_taken(history: &[usize], hidden_catch: Option<usize>) -> usize {
+    hidden_catch.unwrap_or(history.len()) + 1
+}
+
+/// One end-of-round insight: fits rounds that ended this way within `max_votes`
+/// votes (any number when None)
+struct InsightRule {
+    ending: RoundEnding,
+    max_votes: Option<usize>,
+    message: fn(&UiText) -> fn(usize) -> String,
+}
+
+/// Checked in order; the first rule that fits the round is the one shown
+const INSIGHT_RULES: &[InsightRule] = &[
+    InsightRule { ending: RoundEnding::Caught, max_votes: Some(1), message: |t| t.insight_first_vote },
+    InsightRule { ending: RoundEnding::Caught, max_votes: Some(2), message: |t| t.insight_quick_catch },
+    InsightRule { ending: RoundEnding::Caught, max_votes: None, message: |t| t.insight_slow_catch },
+    InsightRule { ending: RoundEnding::WordGuessed, max_votes: None, message: |t| t.insight_stolen_word },
+    InsightRule { ending: RoundEnding::Escaped, max_votes: Some(1), message: |t| t.insight_one_vote_escape },
+    InsightRule { ending: RoundEnding::Escaped, max_votes: Some(2), message: |t| t.insight_quick_escape },
+    InsightRule { ending: RoundEnding::Escaped, max_votes: None, message: |t| t.insight_long_escape },
+];
+
+/// A line on how the round went, from how it ended and how many evicting votes it took.
+/// The same round always gets the same line.
+pub fn insight(ending: RoundEnding, votes: usize, lang: Lang) -> String {
+    let t = ui_text(lang);
+    INSIGHT_RULES.iter()
+        .find(|rule| rule.ending == ending && rule.max_votes.is_none_or(|max| votes <= max))
+        .map(|rule| (rule.message)(t)(votes))
+        .unwrap_or_default()
+}
+
+/// Add a screen change to the event log, dropping the oldest entries beyond `EVENT_LOG_CAP`
+pub fn record_event(log: &mut Vec<GameEvent>, event: GameEvent) {
+    log.push(event);
+    if log.len() > EVENT_LOG_CAP {
+        log.drain(..log.len() - EVENT_LOG_CAP);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::types::{players, RoundRecord, Settings};
+    use crate::views::game::utils::{speed_bonus, SINGLE_VOTE_BONUS};
+
+    fn game(player_count: usize) -> GameState {
+        let mut state = GameState {
+            players: players(player_count),
+            settings: Settings {
+                custom_pairs: vec![("Sun".to_string(), "Moon".to_string())],
+                speed_bonus: false,
+                ..Settings::default()
+            },
+            round_number: 1,
+            ..GameState::default()
+        };
+        assert!(deal_round(&mut state));
+        state
+    }
+
+    fn first_civilian(state: &GameState) -> usize {
+        (0..state.players.len()).find(|&i| i != state.imposter_index).unwrap()
+    }
+
+    fn first_civilian_still_in(state: &GameState) -> usize {
+        (0..state.players.len())
+            .find(|&i| i != state.imposter_index && !state.players[i].is_eliminated)
+            .unwrap()
+    }
+
+    #[test]
+    fn dealing_gives_one_imposter_and_is_idempotent() {
+        let mut state = game(5);
+        assert_eq!(state.cards.len(), 5);
+        let imposters = state.cards.iter().filter(|c| c.card_type == CardType::Imposter).count();
+        assert_eq!(imposters, 1);
+        assert_eq!(state.cards[state.imposter_index].card_type, CardType::Imposter);
+        assert!(state.current_round_words.is_some());
+
+        let dealt = state.cards.clone();
+        assert!(!deal_round(&mut state));
+        assert_eq!(state.cards, dealt);
+    }
+
+    #[test]
+    fn every_deal_counts_towards_fair_rotation() {
+        let mut state = game(4);
+        state.settings.fair_rotation = true;
+        for _ in 0..5 {
+            start_next_round(&mut state);
+            assert!(deal_round(&mut state));
+        }
+        assert_eq!(state.times_imposter.len(), 4);
+        assert_eq!(state.times_imposter.iter().sum::<i32>(), 6);
+        assert!(!state.recent_pairs.is_empty());
+
+        start_new_game(&mut state);
+        assert!(state.times_imposter.is_empty());
+        assert!(state.recent_pairs.is_empty(), "a new game may deal any pair");
+    }
+
+    #[test]
+    fn a_blind_deal_settles_the_imposter_only_when_opened() {
+        let mut state = game(4);
+        start_next_round(&mut state);
+        state.settings.blind_deal = true;
+        assert!(deal_round(&mut state));
+        assert!(state.cards.is_empty(), "nothing is fixed while the cards go round");
+        assert!(state.times_imposter.iter().sum::<i32>() == 1, "only the first, open deal is counted yet");
+        let deal = state.sealed_deal.clone().unwrap();
+        assert_eq!(deal.commitment, seed_commitment(deal.seed));
+        assert!(!deal_round(&mut state), "a sealed deal isn't dealt twice");
+
+        let revealed: Vec<GameCard> = (0..4).map(|seat| card_for_seat(&state, seat).unwrap()).collect();
+        assert_eq!(revealed.iter().filter(|c| c.card_type == CardType::Imposter).count(), 1);
+
+        open_sealed_deal(&mut state);
+        assert!(state.sealed_deal.is_none());
+        assert_eq!(state.opened_deal, Some(DealProof { seed: deal.seed, commitment: deal.commitment.clone() }));
+        assert_eq!(state.cards, revealed, "opening gives every seat the card it was shown");
+        assert_eq!(state.cards[state.imposter_index].card_type, CardType::Imposter);
+        assert_eq!(state.times_imposter.iter().sum::<i32>(), 2);
+
+        // A deal sealed for another table size is replaced
+        state.cards.clear();
+        state.sealed_deal = Some(deal);
+        state.players.pop();
+        assert!(deal_round(&mut state));
+        assert_eq!(state.sealed_deal.unwrap().civilian_cards.len(), 3);
+    }
+
+    #[test]
+    fn word_round_without_a_category_waits() {
+        let mut state = GameState {
+            players: game(3).players,
+            ..GameState::default()
+        };
+        assert!(!deal_round(&mut state));
+        assert!(state.cards.is_empty());
+    }
+
+    #[test]
+    fn catching_the_imposter_pays_the_civilians() {
+        let mut state = game(4);
+        let imposter = state.imposter_index;
+        let outcome = resolve_eviction(&mut state, imposter);
+        assert_eq!(outcome, EvictionOutcome::ImposterCaught);
+        assert!(outcome.game_over());
+        assert_eq!(state.game_screen, GameScreen::RoundEnd { imposter_found: true, game_over: true });
+        for (i, player) in state.players.iter().enumerate() {
+            let expected = if i == imposter { 0 } else { CIVILIAN_WIN_POINTS };
+            assert_eq!(player.score, expected, "{}", player.name);
+        }
+    }
+
+    #[test]
+    fn an_evicted_imposter_can_steal_the_round_with_the_word() {
+        let mut state = game(4);
+        state.settings.comeback_enabled = true;
+        let imposter = state.imposter_index;
+        // Repeated pairs swap sides, so the civilians may be on either word
+        let (civilian_word, _) = state.current_round_words.clone().unwrap();
+        assert_eq!(resolve_eviction(&mut state, imposter), EvictionOutcome::ImposterGuessing);
+        assert_eq!(state.game_screen, GameScreen::ImposterGuess { civilian_word });
+        assert!(state.players.iter().all(|p| p.score == 0), "nobody scores before the guess");
+
+        let mut right = state.clone();
+        assert_eq!(resolve_imposter_guess(&mut right, "Sun", "  sUN "), EvictionOutcome::ImposterWins);
+        assert_eq!(right.game_screen, GameScreen::RoundEnd { imposter_found: false, game_over: true });
+        assert_eq!(right.players[imposter].score, IMPOSTER_WIN_POINTS);
+
+        let mut wrong = state.clone();
+        assert_eq!(resolve_imposter_guess(&mut wrong, "Sun", "Star"), EvictionOutcome::ImposterCaught);
+        assert_eq!(wrong.players[imposter].score, 0);
+        assert!(wrong.players.iter().enumerate().all(|(i, p)| i == imposter || p.score == CIVILIAN_WIN_POINTS));
+
+        let mut silent = state;
+        assert_eq!(resolve_imposter_guess(&mut silent, "Sun", ""), EvictionOutcome::ImposterCaught);
+    }
+
+    #[test]
+    fn imposter_wins_at_the_threshold() {
+        let mut state = game(4);
+        let civilian = first_civilian(&state);
+        assert_eq!(resolve_eviction(&mut state, civilian), EvictionOutcome::Continue);
+        assert_eq!(state.game_screen, GameScreen::Voting);
+        assert_eq!(state.history, vec![civilian]);
+        assert_eq!(state.round_number, 2);
+
+        let next_civilian = first_civilian_still_in(&state);
+        assert_eq!(resolve_eviction(&mut state, next_civilian), EvictionOutcome::ImposterWins);
+        assert_eq!(state.game_screen, GameScreen::RoundEnd { imposter_found: false, game_over: true });
+        assert_eq!(state.players[state.imposter_index].score, IMPOSTER_WIN_POINTS);
+        assert!(state.players.iter().enumerate().all(|(i, p)| i == state.imposter_index || p.score == 0));
+    }
+
+    #[test]
+    fn a_threshold_one_below_the_table_still_leaves_the_first_vote_open() {
+        let mut state = game(6);
+        state.settings.imposter_win_threshold = 5;
+        let civilian = first_civilian(&state);
+        assert_eq!(resolve_eviction(&mut state, civilian), EvictionOutcome::Continue);
+    }
+
+    #[test]
+    fn the_single_vote_challenge_ends_on_its_one_eviction() {
+        let mut state = game(6);
+        state.settings.single_vote_challenge = true;
+        let civilian = first_civilian(&state);
+        assert_eq!(resolve_eviction(&mut state, civilian), EvictionOutcome::ImposterWins);
+        assert_eq!(state.game_screen, GameScreen::RoundEnd { imposter_found: false, game_over: true });
+        assert_eq!(state.players[state.imposter_index].score, IMPOSTER_WIN_POINTS);
+
+        // Even with roles hidden, a catch on the one vote is settled straight away, with the bonus
+        let mut state = game(6);
+        state.settings.single_vote_challenge = true;
+        state.settings.reveal_role_on_eviction = false;
+        let imposter = state.imposter_index;
+        assert_eq!(resolve_eviction(&mut state, imposter), EvictionOutcome::ImposterCaught);
+        assert_eq!(state.players[first_civilian(&state)].score, CIVILIAN_WIN_POINTS + SINGLE_VOTE_BONUS);
+    }
+
+    #[test]
+    fn civilians_evicted_earlier_in_the_round_score_nothing() {
+        let mut state = game(5);
+        state.settings.speed_bonus = true;
+        let evicted_early = first_civilian(&state);
+        assert_eq!(resolve_eviction(&mut state, evicted_early), EvictionOutcome::Continue);
+
+        let imposter = state.imposter_index;
+        assert_eq!(resolve_eviction(&mut state, imposter), EvictionOutcome::ImposterCaught);
+        let reward = CIVILIAN_WIN_POINTS + speed_bonus(2);
+        for (i, player) in state.players.iter().enumerate() {
+            let expected = if i == imposter || i == evicted_early { 0 } else { reward };
+            assert_eq!(player.score, expected, "{}", player.name);
+        }
+    }
+
+    #[test]
+    fn blind_evictions_settle_a_catch_when_the_round_runs_out() {
+        let mut state = game(5);
+        state.settings.reveal_role_on_eviction = false;
+        let evicted_early = first_civilian(&state);
+        assert_eq!(resolve_eviction(&mut state, evicted_early), EvictionOutcome::Continue);
+        let imposter = state.imposter_index;
+        assert_eq!(resolve_eviction(&mut state, imposter), EvictionOutcome::Continue);
+        assert_eq!(state.game_screen, GameScreen::Voting);
+        assert_eq!(state.hidden_catch, Some(1));
+        assert!(state.players.iter().all(|p| p.score == 0), "nothing is settled while roles are hidden");
+
+        // Voted out after the catch, so still owed the win
+        let evicted_late = first_civilian_still_in(&state);
+        assert_eq!(resolve_eviction(&mut state, evicted_late), EvictionOutcome::ImposterCaught);
+        assert_eq!(state.game_screen, GameScreen::RoundEnd { imposter_found: true, game_over: true });
+        for (i, player) in state.players.iter().enumerate() {
+            let expected = if i == imposter || i == evicted_early { 0 } else { CIVILIAN_WIN_POINTS };
+            assert_eq!(player.score, expected, "{}", player.name);
+        }
+
+        // Without a catch the round runs out the usual way
+        let mut missed = game(4);
+        missed.settings.reveal_role_on_eviction = false;
+        let civilian = first_civilian(&missed);
+        assert_eq!(resolve_eviction(&mut missed, civilian), EvictionOutcome::Continue);
+        let next = first_civilian_still_in(&missed);
+        assert_eq!(resolve_eviction(&mut missed, next), EvictionOutcome::ImposterWins);
+    }
+
+    /// The insight round end would show for this state
+    fn insight_for(state: &GameState) -> String {
+        let imposter_found = matches!(state.game_screen, GameScreen::RoundEnd { imposter_found: true, .. });
+        let ending = RoundEnding::of(imposter_found, state.players.get(state.imposter_index));
+        insight(ending, votes_taken(&state.history, state.hidden_catch), state.settings.lang)
+    }
+
+    #[test]
+    fn insights_follow_the_winner_and_the_votes_taken() {
+        let mut state = game(6);
+        state.settings.lang = Lang::English;
+        let imposter = state.imposter_index;
+        let mut first_vote = state.clone();
+        resolve_eviction(&mut first_vote, imposter);
+        assert!(insight_for(&first_vote).contains("first vote"), "{}", insight_for(&first_vote));
+
+        let civilian = first_civilian(&state);
+        resolve_eviction(&mut state, civilian);
+        let mut third_vote = state.clone();
+        let next = first_civilian_still_in(&third_vote);
+        resolve_eviction(&mut third_vote, next);
+        resolve_eviction(&mut third_vote, imposter);
+        assert!(insight_for(&third_vote).contains("It took 3 votes"), "{}", insight_for(&third_vote));
+
+        loop {
+            let next = first_civilian_still_in(&state);
+            if resolve_eviction(&mut state, next) != EvictionOutcome::Continue {
+                break;
+            }
+        }
+        assert!(insight_for(&state).contains("survived"), "{}", insight_for(&state));
+        assert_eq!(insight_for(&state), insight_for(&state.clone()));
+    }
+
+    #[test]
+    fn insights_for_one_vote_losses_and_stolen_words() {
+        let mut challenge = game(6);
+        challenge.settings.single_vote_challenge = true;
+        let civilian = first_civilian(&challenge);
+        assert_eq!(resolve_eviction(&mut challenge, civilian), EvictionOutcome::ImposterWins);
+        let line = insight_for(&challenge);
+        assert!(line.contains("One vote") && !line.contains("1 votes"), "{line}");
+
+        let mut comeback = game(6);
+        comeback.settings.comeback_enabled = true;
+        let imposter = comeback.imposter_index;
+        resolve_eviction(&mut comeback, imposter);
+        let (civilian_word, _) = comeback.current_round_words.clone().unwrap();
+        resolve_imposter_guess(&mut comeback, &civilian_word, &civilian_word);
+        let line = insight_for(&comeback);
+        assert!(line.contains("stole the round"), "{line}");
+    }
+
+    #[test]
+    fn an_eviction_in_an_earlier_round_does_not_carry_over() {
+        let mut state = game(4);
+        let evicted = first_civilian(&state);
+        resolve_eviction(&mut state, evicted);
+        let next = first_civilian_still_in(&state);
+        resolve_eviction(&mut state, next);
+
+        // Back in for the next round and on the winning side
+        start_next_round(&mut state);
+        assert!(deal_round(&mut state));
+        let before: Vec<i32> = state.players.iter().map(|p| p.score).collect();
+        let imposter = state.imposter_index;
+        assert_eq!(resolve_eviction(&mut state, imposter), EvictionOutcome::ImposterCaught);
+        for (i, player) in state.players.iter().enumerate() {
+            let gained = if i == imposter { 0 } else { CIVILIAN_WIN_POINTS };
+            assert_eq!(player.score - before[i], gained, "{}", player.name);
+        }
+    }
+
+    #[test]
+    fn new_game_leaves_nothing_from_the_last_one() {
+        let mut state = game(4);
+        state.session_id = "abc".to_string();
+        state.player_names = vec!["Ana".to_string(), "Ben".to_string()];
+        state.settings.speed_bonus = true;
+        let imposter = state.imposter_index;
+        resolve_eviction(&mut state, imposter);
+        state.round_log.push(RoundRecord {
+            round_number: 1,
+            civilian_word: "Sun".to_string(),
+            imposter_word: "Moon".to_string(),
+            imposter_name: "P0".to_string(),
+            civilians_won: true,
+            seats: Vec::new(),
+            evicted: Vec::new(),
+        });
+
+        start_new_game(&mut state);
+        assert_eq!(state.game_screen, GameScreen::Setup);
+        assert!(state.players.is_empty());
+        assert!(state.cards.is_empty());
+        assert_eq!(state.round_number, 1);
+        assert_eq!(state.imposter_index, 0);
+        assert!(state.round_log.is_empty() && state.history.is_empty());
+        assert_eq!(state.current_round_words, None);
+        assert!(!state.session_id.is_empty() && state.session_id != "abc", "the old session should be left as it was");
+        // What the table chose stays for the next game
+        assert_eq!(state.player_names, vec!["Ana".to_string(), "Ben".to_string()]);
+        assert!(state.settings.speed_bonus);
+    }
+
+    #[test]
+    fn event_log_keeps_only_the_newest_entries() {
+        let mut log = Vec::new();
+        for i in 0..EVENT_LOG_CAP + 3 {
+            let event = GameEvent {
+                ts: i as f64,
+                session_id: "abc".to_string(),
+                from: GameScreen::Voting,
+                to: GameScreen::GameScore,
+            };
+            record_event(&mut log, event);
+        }
+        assert_eq!(log.len(), EVENT_LOG_CAP);
+        assert_eq!(log.first().map(|e| e.ts), Some(3.0));
+        assert_eq!(log.last().map(|e| e.ts), Some((EVENT_LOG_CAP + 2) as f64));
+    }
+
+    #[test]
+    fn each_round_shows_only_its_own_points() {
+        let mut state = game(4);
+        let imposter = state.imposter_index;
+        resolve_eviction(&mut state, imposter);
+        start_next_round(&mut state);
+        assert!(state.players.iter().all(|p| p.last_round_delta == 0), "last round's points carried over");
+
+        assert!(deal_round(&mut state));
+        let imposter = state.imposter_index;
+        resolve_eviction(&mut state, imposter);
+        for (i, player) in state.players.iter().enumerate() {
+            let expected = if i == imposter { 0 } else { CIVILIAN_WIN_POINTS };
+            assert_eq!(player.last_round_delta, expected, "{}", player.name);
+        }
+    }
+
+    #[test]
+    fn a_twist_round_deals_the_imposter_a_word_from_another_pair() {
+        let mut state = game(4);
+        state.settings.twist_round = true;
+        start_next_round(&mut state);
+        assert!(deal_round(&mut state));
+        assert!(!state.twist_active, "a lone pair has nothing unrelated to twist to");
+
+        state.settings.custom_pairs.push(("Coffee".to_string(), "Tea".to_string()));
+        for _ in 0..20 {
+            start_next_round(&mut state);
+            assert!(deal_round(&mut state));
+            assert!(state.twist_active);
+            let (civilian, imposter) = state.current_round_words.clone().unwrap();
+            let (first, second) = state.dealt_pair.clone().unwrap();
+            assert!(civilian == first && [&first, &second].iter().all(|w| **w != imposter), "{civilian} / {imposter}");
+            assert!(["Sun", "Moon", "Coffee", "Tea"].contains(&imposter.as_str()));
+        }
+    }
+
+    #[test]
+    fn next_round_resets_and_rotates() {
+        let mut state = game(3);
+        let civilian = first_civilian(&state);
+        assert_eq!(resolve_eviction(&mut state, civilian), EvictionOutcome::ImposterWins);
+        start_next_round(&mut state);
+        assert!(state.players.iter().all(|p| !p.is_eliminated));
+        assert!(state.cards.is_empty());
+        assert_eq!(state.round_number, 2);
+        assert_eq!(state.starting_player_index, 1);
+        assert_eq!(state.game_screen, GameScreen::CardView { current_player_index: 0 });
+        assert!(deal_round(&mut state));
+    }
+
+    #[test]
+    fn the_grace_vote_opens_only_the_first_round() {
+        let mut state = game(4);
+        state.settings.grace_round = true;
+        state.cards.clear();
+        assert!(deal_round(&mut state));
+        assert!(state.grace_round_pending);
+
+        let imposter = state.imposter_index;
+        resolve_eviction(&mut state, imposter);
+        start_next_round(&mut state);
+        assert!(deal_round(&mut state));
+        assert!(!state.grace_round_pending);
+    }
+}
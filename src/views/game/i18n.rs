@@ -0,0 +1,1337 @@
+use serde::{Deserialize, Serialize};
+
+/// Language the interface is shown in. Word pairs and locations stay as written in the YAML files.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum Lang {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::English, Lang::Spanish];
+
+    /// Short code used as the language picker's value
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::English => "en",
+            Lang::Spanish => "es",
+        }
+    }
+
+    /// Language for a picker value, falling back to English
+    pub fn from_code(code: &str) -> Lang {
+        Lang::ALL.into_iter().find(|lang| lang.code() == code).unwrap_or_default()
+    }
+
+    /// The language's name in that language
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Lang::English => "English",
+            Lang::Spanish => "Español",
+        }
+    }
+}
+
+/// Every piece of interface text. Adding a field makes each language supply it,
+/// so a missing translation is a compile error rather than a stray English string.
+pub struct UiText {
+    pub subtitle: &'static str,
+    pub language_label: &'static str,
+    pub large_text_label: &'static str,
+    pub large_text_description: &'static str,
+    pub high_contrast_label: &'static str,
+    pub high_contrast_description: &'static str,
+    pub display_mode_label: &'static str,
+    pub display_mode_phone: &'static str,
+    pub display_mode_tv: &'static str,
+    pub display_mode_description: &'static str,
+    pub number_of_players: &'static str,
+    pub minimum_players: fn(usize) -> String,
+    pub player_count_clamped: fn(usize, usize, usize) -> String,
+    pub player_count_not_a_number: fn(usize) -> String,
+    pub player_names_heading: &'static str,
+    pub enter_name_placeholder: &'static str,
+    pub duplicate_names_warning: &'static str,
+    pub number_automatically: &'static str,
+    pub start_game: &'static str,
+    pub quick_start: &'static str,
+    pub quick_start_hint: &'static str,
+    pub practice_solo: &'static str,
+    pub practice_solo_hint: &'static str,
+    pub you_label: &'static str,
+    pub numbered_player: fn(usize) -> String,
+    pub share_setup: &'static str,
+    pub show_join_link: &'static str,
+    pub join_link_label: &'static str,
+    pub join_link_hint: &'static str,
+    pub join_qr_label: &'static str,
+    pub rejoin_code_is: fn(&str) -> String,
+    pub rejoin_code_hint: &'static str,
+    pub setup_link_label: &'static str,
+    pub restore_or_import: &'static str,
+    pub saved_games_heading: &'static str,
+    pub saved_game_details: fn(&str, i32, usize) -> String,
+    pub resume_saved_game: &'static str,
+    pub delete_saved_game_label: &'static str,
+    pub delete_saved_game_title: &'static str,
+    pub delete_saved_game_message: fn(&str) -> String,
+    pub confirm_delete_saved_game: &'static str,
+    pub session_code_placeholder: &'static str,
+    pub no_saved_game: &'static str,
+    pub rejoin_code_expired: &'static str,
+    pub rejoin_server_unreachable: &'static str,
+    pub rejoin_save_unreadable: &'static str,
+    pub restore: &'static str,
+    pub import_exported: &'static str,
+    pub read_file_failed: &'static str,
+    pub import_not_an_export: fn(&str) -> String,
+    pub import_newer_version: &'static str,
+    pub import_no_game: &'static str,
+    pub dismiss: &'static str,
+    pub choose_category: &'static str,
+    pub choose_category_subtitle: &'static str,
+    pub word_pair_count: fn(usize) -> String,
+    pub category_title: &'static str,
+    pub category_hint: &'static str,
+    pub hard_mode: &'static str,
+    pub hard_mode_on: &'static str,
+    pub hard_mode_off: &'static str,
+    pub back_to_categories: &'static str,
+    pub start_round: &'static str,
+    pub location_category: &'static str,
+    pub all_cards_seen: &'static str,
+    pub almost_all_cards_seen: &'static str,
+    pub skipped_players_warning: &'static str,
+    pub show_players_card: fn(&str) -> String,
+    pub proceed_to_discussion: &'static str,
+    pub proceed_without_them: &'static str,
+    pub preparing_cards: &'static str,
+    pub pass_device_to: &'static str,
+    pub simulated_turn: fn(&str) -> String,
+    pub round_theme: fn(&str, &str) -> String,
+    pub keep_screen_private: &'static str,
+    pub name_check_prompt: &'static str,
+    pub back: &'static str,
+    pub reveal_my_card: &'static str,
+    pub skip_for_now: &'static str,
+    pub card_progress: fn(usize, usize) -> String,
+    pub reveal_key_hint: &'static str,
+    pub print_cards: &'static str,
+    pub seat_swap_nudge: &'static str,
+    pub players_card: fn(&str) -> String,
+    pub card_hidden: &'static str,
+    pub show_card_again: &'static str,
+    pub hide_card: &'static str,
+    pub next_player: &'static str,
+    pub auto_next_in: fn(u32) -> String,
+    pub pass_on_key_hint: &'static str,
+    pub cancel: &'static str,
+    pub print_cards_title: &'static str,
+    pub print_cards_warning: &'static str,
+    pub host_show_cards: &'static str,
+    pub print: &'static str,
+    pub close: &'static str,
+    pub imposter_label: &'static str,
+    pub civilian_label: &'static str,
+    pub fold_here: &'static str,
+    pub spy_card: &'static str,
+    pub spy_hint: &'static str,
+    pub spy_instruction: &'static str,
+    pub no_word_hint: &'static str,
+    pub no_word_instruction: &'static str,
+    pub category_hint_card: fn(&str) -> String,
+    pub category_hint_hint: &'static str,
+    pub category_hint_instruction: &'static str,
+    pub your_role: fn(&str) -> String,
+    pub you_are_imposter: &'static str,
+    pub you_are_civilian: &'static str,
+    pub location_instruction: &'static str,
+    pub find_odd_word: &'static str,
+    pub imposter_instruction: &'static str,
+    pub civilian_tip: &'static str,
+    pub imposter_tip: &'static str,
+    pub restart_round_title: &'static str,
+    pub restart_round_message: &'static str,
+    pub confirm_restart_round: &'static str,
+    pub end_game_title: &'static str,
+    pub end_game_message: &'static str,
+    pub confirm_end_game: &'static str,
+    pub discussion_and_voting: &'static str,
+    pub times_up: &'static str,
+    pub resume: &'static str,
+    pub pause: &'static str,
+    pub paused: &'static str,
+    pub discussion_order: &'static str,
+    pub secret_vote_hint: &'static str,
+    pub open_vote_hint: &'static str,
+    pub solo_vote_hint: &'static str,
+    pub grace_round: &'static str,
+    pub grace_round_hint: &'static str,
+    pub continue_to_real_vote: &'static str,
+    pub start_secret_ballot: &'static str,
+    pub skipped_tag: &'static str,
+    pub evict: &'static str,
+    pub confirm_evict: fn(&str) -> String,
+    pub no_evict_this_round: &'static str,
+    pub skips_left: fn(usize) -> String,
+    pub skips_used_up: &'static str,
+    pub revert_last_eviction: fn(&str) -> String,
+    pub restart_round: &'static str,
+    pub end_game: &'static str,
+    pub remind_me: &'static str,
+    pub who_needs_reminder: &'static str,
+    pub pass_device_only_to: fn(&str) -> String,
+    pub show_my_card_as: fn(&str) -> String,
+    pub card_unavailable: &'static str,
+    pub hide_and_pass_back: &'static str,
+    pub moderator_mode_label: &'static str,
+    pub moderator_mode_description: &'static str,
+    pub single_vote_label: &'static str,
+    pub single_vote_description: &'static str,
+    pub single_vote_banner: &'static str,
+    pub twist_banner: &'static str,
+    pub single_vote_bonus: fn(i32) -> String,
+    pub challenge_lost: &'static str,
+    pub challenge_vote_cast: &'static str,
+    pub moderator_hold: &'static str,
+    pub moderator_civilian_word: &'static str,
+    pub moderator_imposter_word: &'static str,
+    pub moderator_imposter: &'static str,
+    pub moderator_hide: &'static str,
+    pub all_votes_in: &'static str,
+    pub count_votes: &'static str,
+    pub the_votes: &'static str,
+    pub vote_count: fn(u32) -> String,
+    pub reveal_eviction: &'static str,
+    pub secret_ballot: &'static str,
+    pub ballot_progress: fn(usize, usize) -> String,
+    pub revote_hint: &'static str,
+    pub open_my_ballot: fn(&str) -> String,
+    pub who_is_imposter: fn(&str) -> String,
+    pub vote: &'static str,
+    pub its_a_tie: &'static str,
+    pub tied_players_intro: &'static str,
+    pub revote_explainer: &'static str,
+    pub revote: &'static str,
+    pub no_elimination_explainer: &'static str,
+    pub revotes_exhausted: fn(u32) -> String,
+    pub back_to_discussion: &'static str,
+    pub imposter: &'static str,
+    pub civilian: &'static str,
+    pub imposter_evicted: &'static str,
+    pub was_the_imposter: fn(&str) -> String,
+    pub civilians_win_round: &'static str,
+    pub comeback_pending: &'static str,
+    pub imposter_guess_heading: fn(&str) -> String,
+    pub imposter_guess_prompt: &'static str,
+    pub imposter_guess_label: &'static str,
+    pub lock_in_guess: &'static str,
+    pub no_guess: &'static str,
+    pub civilian_reward_with_bonus: fn(i32, i32) -> String,
+    pub civilian_reward: fn(i32) -> String,
+    pub civilian_evicted: &'static str,
+    pub was_a_civilian: fn(&str) -> String,
+    pub imposter_remains: &'static str,
+    pub players_remaining: fn(usize) -> String,
+    pub player_is_out: fn(&str) -> String,
+    pub roles_stay_hidden: &'static str,
+    pub undo: &'static str,
+    pub continue_label: &'static str,
+    pub role_check_for: fn(&str) -> String,
+    pub show_my_role_as: fn(&str) -> String,
+    pub you_were_imposter: &'static str,
+    pub you_were_civilian: &'static str,
+    pub your_word_was: fn(&str) -> String,
+    pub round_cannot_continue: &'static str,
+    pub round_recovery_explainer: &'static str,
+    pub redeal_round: &'static str,
+    pub back_to_setup: &'static str,
+    pub new_game_title: &'static str,
+    pub new_game_message: &'static str,
+    pub confirm_new_game: &'static str,
+    pub unknown_player: &'static str,
+    pub civilians_win: &'static str,
+    pub imposter_wins: &'static str,
+    pub the_imposter_was: fn(&str) -> String,
+    pub words_this_round: &'static str,
+    pub civilian_word: &'static str,
+    pub imposter_word: &'static str,
+    pub flag_pair: &'static str,
+    pub pair_flagged: &'static str,
+    pub civilians_get_points: fn(i32) -> String,
+    pub base_win: fn(i32) -> String,
+    pub quick_catch_on_vote: fn(usize, i32) -> String,
+    pub insight_first_vote: fn(usize) -> String,
+    pub insight_quick_catch: fn(usize) -> String,
+    pub insight_slow_catch: fn(usize) -> String,
+    pub insight_stolen_word: fn(usize) -> String,
+    pub insight_one_vote_escape: fn(usize) -> String,
+    pub insight_quick_escape: fn(usize) -> String,
+    pub insight_long_escape: fn(usize) -> String,
+    pub imposter_gets_points: fn(i32) -> String,
+    pub see_everyones_card: &'static str,
+    pub imposter_reveal: &'static str,
+    pub imposter_named: fn(&str) -> String,
+    pub imposters_so_far: &'static str,
+    pub round_imposter: fn(i32, &str) -> String,
+    pub next_round: &'static str,
+    pub view_scores: &'static str,
+    pub new_game: &'static str,
+    pub out_tag: &'static str,
+    pub roster_heading: &'static str,
+    pub roster_count: fn(usize, usize) -> String,
+    pub scoreboard: &'static str,
+    pub card_view_toggle: &'static str,
+    pub compact_view_toggle: &'static str,
+    pub player_column: &'static str,
+    pub points_column: &'static str,
+    pub points_total: fn(i32) -> String,
+    pub play_next_round: &'static str,
+    pub edit_players: &'static str,
+    pub new_game_plain: &'static str,
+    pub player_stats: &'static str,
+    pub export_game: &'static str,
+    pub copy_results: &'static str,
+    pub results_copied: &'static str,
+    pub copy_results_fallback: &'static str,
+    pub results_label: &'static str,
+    pub results_imposter_caught: fn(&str, i32) -> String,
+    pub results_imposter_escaped: fn(&str, i32) -> String,
+    pub results_winner: fn(&str, i32) -> String,
+    pub results_final_scores: &'static str,
+    pub after_round: fn(i32) -> String,
+    pub games_column: &'static str,
+    pub imposter_column: &'static str,
+    pub imposter_wins_column: &'static str,
+    pub civilian_wins_column: &'static str,
+    pub stats_hint: &'static str,
+    pub stats_empty: &'static str,
+    pub back_to_scoreboard: &'static str,
+    pub round_history: &'static str,
+    pub round_history_hint: &'static str,
+    pub round_history_empty: &'static str,
+    pub replay_last_round: &'static str,
+    pub replay_title: fn(i32) -> String,
+    pub replay_progress: fn(usize, usize) -> String,
+    pub replay_eviction: fn(usize) -> String,
+    pub replay_evicted: fn(&str) -> String,
+    pub replay_previous: &'static str,
+    pub replay_next: &'static str,
+    pub round_column: &'static str,
+    pub words_column: &'static str,
+    pub winner_column: &'static str,
+    pub civilians_won_cell: &'static str,
+    pub imposter_won_cell: &'static str,
+    pub edit_players_hint: &'static str,
+    pub points_short: fn(i32) -> String,
+    pub remove_player: fn(&str) -> String,
+    pub names_must_differ: &'static str,
+    pub rename_player: fn(&str) -> String,
+    pub rename_heading: &'static str,
+    pub name_cannot_be_empty: &'static str,
+    pub save_name: &'static str,
+    pub new_player_placeholder: &'static str,
+    pub add_player: &'static str,
+    pub player_range: fn(usize, usize) -> String,
+    pub save_and_play_next_round: &'static str,
+    pub game_settings: &'static str,
+    pub game_mode_label: &'static str,
+    pub word_pairs_mode: &'static str,
+    pub location_mode: &'static str,
+    pub location_mode_description: &'static str,
+    pub word_pairs_description: &'static str,
+    pub word_pack_label: &'static str,
+    pub mixed_pack: &'static str,
+    pub difficulty_label: &'static str,
+    pub any_difficulty_option: &'static str,
+    pub easy_option: &'static str,
+    pub medium_option: &'static str,
+    pub hard_option: &'static str,
+    pub difficulty_description: &'static str,
+    pub imposter_card_label: &'static str,
+    pub similar_word_option: &'static str,
+    pub no_word_option: &'static str,
+    pub category_hint_option: &'static str,
+    pub custom_pairs_label: &'static str,
+    pub custom_pairs_empty: &'static str,
+    pub custom_pairs_in_use: fn(usize) -> String,
+    pub skip_flagged_label: &'static str,
+    pub skip_flagged_description: &'static str,
+    pub custom_category: &'static str,
+    pub custom_pair_line_error: fn(usize) -> String,
+    pub twist_round_label: &'static str,
+    pub twist_round_description: &'static str,
+    pub timer_label: &'static str,
+    pub timer_off: &'static str,
+    pub one_minute: &'static str,
+    pub two_minutes: &'static str,
+    pub three_minutes: &'static str,
+    pub timer_description: &'static str,
+    pub reveal_timeout_label: &'static str,
+    pub seconds_option: fn(u32) -> String,
+    pub reveal_timeout_description: &'static str,
+    pub pass_delay_label: &'static str,
+    pub pass_delay_description: &'static str,
+    pub get_ready_in: fn(u32) -> String,
+    pub win_threshold_label: &'static str,
+    pub players_left: fn(usize) -> String,
+    pub win_threshold_description: &'static str,
+    pub skip_cap_label: &'static str,
+    pub skip_cap_off: &'static str,
+    pub skip_cap_description: &'static str,
+    pub private_game_label: &'static str,
+    pub private_game_description: &'static str,
+    pub event_log_label: &'static str,
+    pub event_log_description: &'static str,
+    pub role_check_label: &'static str,
+    pub role_check_description: &'static str,
+    pub reveal_imposter_label: &'static str,
+    pub reveal_imposter_description: &'static str,
+    pub animations_label: &'static str,
+    pub animations_description: &'static str,
+    pub name_check_label: &'static str,
+    pub name_check_description: &'static str,
+    pub tips_label: &'static str,
+    pub tips_description: &'static str,
+    pub blind_deal_label: &'static str,
+    pub blind_deal_description: &'static str,
+    pub sealed_deal_code: fn(&str) -> String,
+    pub sealed_deal_seed: fn(&str, u64) -> String,
+    pub sealed_deal_matches: &'static str,
+    pub sealed_deal_mismatch: &'static str,
+    pub random_category_label: &'static str,
+    pub random_category_description: &'static str,
+    pub nudge_label: &'static str,
+    pub nudge_description: &'static str,
+    pub shuffle_order_label: &'static str,
+    pub shuffle_order_description: &'static str,
+    pub fair_rotation_label: &'static str,
+    pub fair_rotation_description: &'static str,
+    pub secret_ballot_label: &'static str,
+    pub secret_ballot_description: &'static str,
+    pub hide_live_tally_label: &'static str,
+    pub hide_live_tally_description: &'static str,
+    pub votes_so_far: &'static str,
+    pub tied_ballots_label: &'static str,
+    pub revote_option: &'static str,
+    pub no_elimination_option: &'static str,
+    pub quick_catch_label: &'static str,
+    pub quick_catch_description: &'static str,
+    pub grace_round_label: &'static str,
+    pub grace_round_description: &'static str,
+    pub comeback_label: &'static str,
+    pub comeback_description: &'static str,
+    pub reveal_role_label: &'static str,
+    pub reveal_role_description: &'static str,
+    pub session_label: fn(&str) -> String,
+    pub saving: &'static str,
+    pub saved: &'static str,
+    pub not_saved: &'static str,
+    pub cannot_save: &'static str,
+    pub viewing_only: &'static str,
+    pub saving_unavailable: &'static str,
+    pub private_not_saved: &'static str,
+    pub announce_setup: &'static str,
+    pub announce_category_selection: &'static str,
+    pub announce_category: fn(&str) -> String,
+    pub announce_cards_done: &'static str,
+    pub announce_card_turn: fn(&str) -> String,
+    pub announce_voting: fn(usize) -> String,
+    pub announce_secret_ballot: fn(usize, usize) -> String,
+    pub announce_tie: fn(usize) -> String,
+    pub announce_vote_reveal: &'static str,
+    pub announce_evicted: fn(&str) -> String,
+    pub announce_game_over: &'static str,
+    pub announce_imposter_caught: &'static str,
+    pub announce_imposter_escaped: &'static str,
+    pub announce_scoreboard: &'static str,
+    pub announce_stats: &'static str,
+    pub announce_round_history: &'static str,
+    pub announce_replay: &'static str,
+    pub announce_edit_players: &'static str,
+    pub announce_imposter_guess: &'static str,
+    pub mute_sound: &'static str,
+    pub unmute_sound: &'static str,
+    pub toggle_sound_label: &'static str,
+    pub dark_theme: &'static str,
+    pub light_theme: &'static str,
+    pub toggle_theme_label: &'static str,
+}
+
+/// Interface text for the given language
+pub fn ui_text(lang: Lang) -> &'static UiText {
+    match lang {
+        Lang::English => &ENGLISH,
+        Lang::Spanish => &SPANISH,
+    }
+}
+
+static ENGLISH: UiText = UiText {
+    subtitle: "The Social Deduction Game",
+    language_label: "🌐 Language",
+    large_text_label: "🔠 Large text",
+    large_text_description: "Bigger text on every screen",
+    high_contrast_label: "◐ High contrast",
+    high_contrast_description: "Solid backgrounds and stronger outlines for easier reading",
+    display_mode_label: "📺 Showing on",
+    display_mode_phone: "A phone passed around",
+    display_mode_tv: "A TV or big screen",
+    display_mode_description: "Bigger cards and text, with lists spread across a wide screen",
+    number_of_players: "👥 Number of Players",
+    minimum_players: |n| format!("(minimum {n})"),
+    player_count_clamped: |min, max, count| format!("Pick {min} to {max} players - this will become {count}."),
+    player_count_not_a_number: |count| format!("That isn't a number - still {count} players."),
+    player_names_heading: "✏️ Player Names",
+    enter_name_placeholder: "Enter name...",
+    duplicate_names_warning: "⚠️ Each player needs a different name - the highlighted names are taken twice.",
+    number_automatically: "Number them automatically",
+    start_game: "🚀 Start Game",
+    quick_start: "⚡ Quick Start",
+    quick_start_hint: "Skip names and play as Player 1, Player 2…",
+    practice_solo: "🧑 Practice Solo",
+    practice_solo_hint: "Learn the game alone: you're the first player and the app passes everyone else's cards.",
+    you_label: "You",
+    numbered_player: |n| format!("Player {n}"),
+    share_setup: "🔗 Share this setup",
+    show_join_link: "📲 Join link",
+    join_link_label: "Join link for this game",
+    join_link_hint: "Open it on another device to follow this game's scoreboard. It needs the server, so private games can't be joined.",
+    join_qr_label: "QR code for the join link",
+    rejoin_code_is: |code| format!("🔑 Rejoin code: {code}"),
+    rejoin_code_hint: "If this device dies, enter the code under \"Restore or import a game\" on another device to carry on.",
+    setup_link_label: "Setup link",
+    restore_or_import: "🔑 Restore or import a game",
+    saved_games_heading: "📂 Saved games on this device",
+    saved_game_details: |code, round, players| format!("{code} · round {round} · {players} players"),
+    resume_saved_game: "Resume",
+    delete_saved_game_label: "Delete this saved game",
+    delete_saved_game_title: "Delete Saved Game?",
+    delete_saved_game_message: |code| format!("Game {code} will be removed from this device. Any copy on the server is kept."),
+    confirm_delete_saved_game: "Yes, Delete",
+    session_code_placeholder: "Paste session code...",
+    no_saved_game: "No saved game found for that code",
+    rejoin_code_expired: "That code has expired: the game hasn't been played for over 30 days",
+    rejoin_server_unreachable: "Couldn't reach the server to look up that code. Check the connection and try again",
+    rejoin_save_unreadable: "A game is saved under that code, but it couldn't be read",
+    restore: "Restore",
+    import_exported: "📂 Import an exported game",
+    read_file_failed: "Couldn't read that file",
+    import_not_an_export: |detail| format!("That file isn't a valid game export ({detail})"),
+    import_newer_version: "That game was exported from a newer version of the app",
+    import_no_game: "That file doesn't contain a game",
+    dismiss: "Dismiss",
+    choose_category: "🎯 Choose Your Category",
+    choose_category_subtitle: "Pick a theme for this round",
+    word_pair_count: |n| format!("{n} word pairs"),
+    category_title: "Category",
+    category_hint: "All players will receive words related to this category",
+    hard_mode: "🔥 Hard Mode",
+    hard_mode_on: "The imposter won't know they're the imposter!",
+    hard_mode_off: "Enable to hide the imposter's identity from themselves",
+    back_to_categories: "← Back to Categories",
+    start_round: "▶️ Start Round",
+    location_category: "Location",
+    all_cards_seen: "All players have seen their cards!",
+    almost_all_cards_seen: "Almost everyone has seen their card",
+    skipped_players_warning: "⚠️ These players skipped and haven't seen their card yet:",
+    show_players_card: |name| format!("👀 Show {name}'s card"),
+    proceed_to_discussion: "Proceed to Discussion",
+    proceed_without_them: "Proceed Without Them",
+    preparing_cards: "Preparing cards...",
+    pass_device_to: "Pass device to:",
+    simulated_turn: |name| format!("{name} is looking at their card…"),
+    round_theme: |icon, name| format!("This round's theme: {icon} {name}"),
+    keep_screen_private: "⚠️ Make sure other players can't see the screen!",
+    name_check_prompt: "Type the first letter of your name to unlock",
+    back: "← Back",
+    reveal_my_card: "Reveal My Card",
+    skip_for_now: "⏭️ Skip for now",
+    card_progress: |n, total| format!("Player {n} of {total}"),
+    reveal_key_hint: "Tip: press Space to reveal",
+    print_cards: "🖨️ Print cards (host only - reveals roles)",
+    seat_swap_nudge: "👀 That was quick! Make sure the device is being passed to each player.",
+    players_card: |name| format!("{name}'s Card"),
+    card_hidden: "Card hidden",
+    show_card_again: "👁️ Show my card again",
+    hide_card: "🙈 Hide my card",
+    next_player: "Next Player",
+    auto_next_in: |n| format!("Passing on in {n}s…"),
+    pass_on_key_hint: "Tip: press Space to hide your card and pass on",
+    cancel: "Cancel",
+    print_cards_title: "🖨️ Print Cards",
+    print_cards_warning: "This shows every player's word and who the imposter is. Only the host should continue.",
+    host_show_cards: "I'm the host - show cards",
+    print: "Print",
+    close: "Close",
+    imposter_label: "🎭 Imposter",
+    civilian_label: "👥 Civilian",
+    fold_here: "✂ fold here before handing out",
+    spy_card: "🕵️ SPY",
+    spy_hint: "You don't know the location!",
+    spy_instruction: "Listen carefully and work out where everyone is without giving yourself away.",
+    no_word_hint: "You have NO word - blend in!",
+    no_word_instruction: "Listen to the others, work out their word and bluff like you know it.",
+    category_hint_card: |hint| format!("Category: {hint}"),
+    category_hint_hint: "You only know the category - blend in!",
+    category_hint_instruction: "Find your word to blend in: listen for what fits the category and bluff like you know it.",
+    your_role: |role| format!("Your role: {role}"),
+    you_are_imposter: "🎭 You are the IMPOSTER!",
+    you_are_civilian: "👥 You are a civilian",
+    location_instruction: "Find the spy! Ask questions that only someone at this location could answer.",
+    find_odd_word: "Find the player with the different word!",
+    imposter_instruction: "Try to blend in! Don't let others know you have the odd word.",
+    civilian_tip: "💡 Tip: give a clue only someone with your word would get, without giving the word away.",
+    imposter_tip: "💡 Tip: let others go first and keep your clue vague enough to fit whatever they describe.",
+    restart_round_title: "Restart Round?",
+    restart_round_message: "This will reset the current round and go back to category selection. Progress will be lost.",
+    confirm_restart_round: "Yes, Restart Round",
+    end_game_title: "End the Game Here?",
+    end_game_message: "This round won't be scored. Everyone keeps the points they have and you go straight to the scoreboard.",
+    confirm_end_game: "Yes, Show Scores",
+    discussion_and_voting: "🗳️ Discussion & Voting",
+    times_up: "Time's up! Wrap up and vote.",
+    resume: "▶️ Resume",
+    pause: "⏸️ Pause",
+    paused: "Paused",
+    discussion_order: "💬 Discussion order (everyone explains their word):",
+    secret_vote_hint: "After discussion, pass the device around and vote in secret.",
+    open_vote_hint: "After discussion, tap on the player card you all agreed to evict.",
+    solo_vote_hint: "Practice round: you make the call for the whole table.",
+    grace_round: "🕊️ Grace Round",
+    grace_round_hint: "Discuss and gather information - nobody is evicted this time.",
+    continue_to_real_vote: "Continue to Real Vote",
+    start_secret_ballot: "🗳️ Start Secret Ballot",
+    skipped_tag: "🙈 Hasn't seen their card",
+    evict: "Evict",
+    confirm_evict: |name| format!("Tap again to confirm: evict {name}?"),
+    no_evict_this_round: "🤷 No Evict This Round",
+    skips_left: |n| if n == 1 { "1 skip left before you have to vote someone out.".to_string() } else { format!("{n} skips left before you have to vote someone out.") },
+    skips_used_up: "You've skipped enough - this vote has to evict someone.",
+    revert_last_eviction: |name| format!("⏪ Revert Last Eviction ({name})"),
+    restart_round: "🔄 Restart Round",
+    end_game: "🏁 End Game",
+    remind_me: "🔁 Remind me of my word",
+    who_needs_reminder: "Who needs a reminder?",
+    pass_device_only_to: |name| format!("Pass the device to {name} only."),
+    show_my_card_as: |name| format!("I'm {name} - show my card"),
+    card_unavailable: "This card is no longer available.",
+    hide_and_pass_back: "Hide & Pass Back",
+    moderator_mode_label: "🛡️ Moderator mode",
+    moderator_mode_description: "For a moderator who isn't playing: a hold-to-open panel on the voting screen shows both words and the imposter. Anyone can switch this on, so only use it with a table you trust",
+    single_vote_label: "🎯 Single-vote challenge",
+    single_vote_description: "Co-op: the group gets one vote to find the imposter. A wrong eviction ends the round as an imposter win; a right one earns every civilian a bonus.",
+    single_vote_banner: "🎯 Single-vote challenge: you have one vote, make it count.",
+    twist_banner: "🌀 Twist round: the imposter's word comes from a different pair.",
+    single_vote_bonus: |points| format!("Single-vote challenge: +{points}"),
+    challenge_lost: "🎯 That was the only vote, so the imposter wins the round.",
+    challenge_vote_cast: "🎯 That was the only vote, so the round ends here and the roles are revealed.",
+    moderator_hold: "🛡️ Moderator: press and hold",
+    moderator_civilian_word: "Civilian word",
+    moderator_imposter_word: "Imposter word",
+    moderator_imposter: "Imposter",
+    moderator_hide: "🙈 Hide",
+    all_votes_in: "All votes are in!",
+    count_votes: "Count the Votes",
+    the_votes: "🗳️ The Votes",
+    vote_count: |n| if n == 1 { "1 vote".to_string() } else { format!("{n} votes") },
+    reveal_eviction: "Reveal Eviction",
+    secret_ballot: "🗳️ Secret Ballot",
+    ballot_progress: |n, total| format!("Vote {n} of {total}"),
+    revote_hint: "Revote: only the tied players can be chosen.",
+    open_my_ballot: |name| format!("I'm {name} - Open My Ballot"),
+    who_is_imposter: |name| format!("{name}, who is the imposter?"),
+    vote: "Vote",
+    its_a_tie: "⚖️ It's a Tie!",
+    tied_players_intro: "These players received the same number of votes:",
+    revote_explainer: "Everyone votes again, choosing only among the tied players.",
+    revote: "🗳️ Revote",
+    no_elimination_explainer: "Nobody is evicted this time. Discuss again and hold a new vote.",
+    revotes_exhausted: |n| format!("Still tied after {n} revotes, so nobody is evicted this time. Discuss again and hold a new vote."),
+    back_to_discussion: "Back to Discussion",
+    imposter: "Imposter",
+    civilian: "Civilian",
+    imposter_evicted: "🎉 Imposter Evicted!",
+    was_the_imposter: |name| format!("🎊 {name} was the IMPOSTER!"),
+    civilians_win_round: "🏆 Civilians win this round!",
+    comeback_pending: "…unless they can name the civilians' word with their one guess!",
+    imposter_guess_heading: |name| format!("🎯 {name}, last chance!"),
+    imposter_guess_prompt: "Guess the civilians' word. Get it right and you still win the round.",
+    imposter_guess_label: "Your guess",
+    lock_in_guess: "🎯 Lock In Guess",
+    no_guess: "No Guess",
+    civilian_reward_with_bonus: |total, bonus| format!("Remaining civilians receive +{total} points (including a +{bonus} quick-catch bonus)"),
+    civilian_reward: |points| format!("Remaining civilians receive +{points} points"),
+    civilian_evicted: "😔 Civilian Evicted",
+    was_a_civilian: |name| format!("💔 {name} was a CIVILIAN"),
+    imposter_remains: "😈 The imposter remains among you...",
+    players_remaining: |n| format!("{n} players remaining"),
+    player_is_out: |name| format!("{name} is out"),
+    roles_stay_hidden: "🤫 Roles stay hidden until the round ends. The round continues...",
+    undo: "↩️ Undo",
+    continue_label: "Continue",
+    role_check_for: |name| format!("🔒 Private role check for {name}"),
+    show_my_role_as: |name| format!("I'm {name} - show my role"),
+    you_were_imposter: "🎭 You were the imposter.",
+    you_were_civilian: "👥 You were a civilian.",
+    your_word_was: |word| format!("Your word was \"{word}\". You're out - sit back and enjoy the show!"),
+    round_cannot_continue: "⚠️ This round can't be continued",
+    round_recovery_explainer: "The saved round doesn't match the current players, so it has to be dealt again. Scores are kept.",
+    redeal_round: "Re-deal this round",
+    back_to_setup: "Back to setup",
+    new_game_title: "Start New Game?",
+    new_game_message: "Start a new game? Current scores will be lost.",
+    confirm_new_game: "Yes, Start New Game",
+    unknown_player: "Unknown",
+    civilians_win: "✅ Civilians Win!",
+    imposter_wins: "😈 Imposter Wins!",
+    the_imposter_was: |name| format!("The imposter was: {name}"),
+    words_this_round: "📝 Words This Round:",
+    civilian_word: "👥 Civilian Word:",
+    imposter_word: "🎭 Imposter Word:",
+    flag_pair: "🚩 Flag this pair",
+    pair_flagged: "🚩 Flagged (tap to undo)",
+    civilians_get_points: |points| format!("🎉 Remaining civilians get {points} points!"),
+    base_win: |points| format!("Base win: +{points}"),
+    quick_catch_on_vote: |vote, bonus| format!("Quick catch on vote {vote}: +{bonus}"),
+    insight_first_vote: |_| "🧠 Caught on the first vote — great deduction!".to_string(),
+    insight_quick_catch: |votes| format!("🔍 Found in {votes} votes — sharp work."),
+    insight_slow_catch: |votes| format!("😅 It took {votes} votes, but you got there in the end!"),
+    insight_stolen_word: |_| "🎯 Caught, but the imposter named the word and stole the round!".to_string(),
+    insight_one_vote_escape: |_| "🥷 One vote, one wrong call — the imposter walks away!".to_string(),
+    insight_quick_escape: |votes| format!("🥷 The imposter slipped through in just {votes} votes — smooth operator!"),
+    insight_long_escape: |votes| format!("🎭 The imposter survived {votes} votes — your group struggled!"),
+    imposter_gets_points: |points| format!("😈 The imposter gets {points} points!"),
+    see_everyones_card: "🃏 See everyone's card",
+    imposter_reveal: "🕵️ Imposter Reveal",
+    imposter_named: |name| format!("Imposter: {name}"),
+    imposters_so_far: "🕵️ Imposters so far",
+    round_imposter: |round, name| format!("Round {round}: {name}"),
+    next_round: "▶️ Next Round",
+    view_scores: "🏆 View Scores",
+    new_game: "🔄 New Game",
+    out_tag: "(out)",
+    roster_heading: "Who's still in",
+    roster_count: |left, total| format!("{left} of {total} still in"),
+    scoreboard: "🏆 Scoreboard",
+    card_view_toggle: "🃏 Card View",
+    compact_view_toggle: "📋 Compact View",
+    player_column: "Player",
+    points_column: "Points",
+    points_total: |points| format!("{points} points"),
+    play_next_round: "Play Next Round",
+    edit_players: "👥 Edit Players",
+    new_game_plain: "New Game",
+    player_stats: "📊 Player Stats",
+    export_game: "💾 Export Game",
+    copy_results: "📋 Copy Results",
+    results_copied: "✅ Results copied - paste them anywhere",
+    copy_results_fallback: "Copying isn't allowed here. The results are selected below, so copy them by hand.",
+    results_label: "Game results",
+    results_imposter_caught: |name, round| format!("Imposter was {name}, caught in round {round}."),
+    results_imposter_escaped: |name, round| format!("Imposter was {name}, who got away in round {round}."),
+    results_winner: |names, points| format!("{names} won with {points} pts."),
+    results_final_scores: "Final scores",
+    after_round: |round| format!("After Round {round}"),
+    games_column: "Games",
+    imposter_column: "Imposter",
+    imposter_wins_column: "Imposter wins",
+    civilian_wins_column: "Civilian wins",
+    stats_hint: "Totals from every game played on this device. Tap a column to sort.",
+    stats_empty: "No finished games yet - stats appear after the first game ends.",
+    back_to_scoreboard: "← Back to Scoreboard",
+    round_history: "📜 Round History",
+    round_history_hint: "Every round played this session, most recent first.",
+    round_history_empty: "No rounds finished yet.",
+    replay_last_round: "🎬 Replay Last Round",
+    replay_title: |round| format!("🎬 Round {round} Replay"),
+    replay_progress: |step, total| format!("Step {step} of {total}"),
+    replay_eviction: |n| format!("Eviction {n}"),
+    replay_evicted: |name| format!("{name} was voted out:"),
+    replay_previous: "← Previous",
+    replay_next: "Next →",
+    round_column: "Round",
+    words_column: "Words",
+    winner_column: "Winner",
+    civilians_won_cell: "👥 Civilians",
+    imposter_won_cell: "🎭 Imposter",
+    edit_players_hint: "Scores are kept for everyone who stays. New players start on 0.",
+    points_short: |points| format!("{points} pts"),
+    remove_player: |name| format!("Remove {name}"),
+    names_must_differ: "⚠️ Each player needs a different name.",
+    rename_player: |name| format!("Rename {name}"),
+    rename_heading: "✏️ Fix a name",
+    name_cannot_be_empty: "⚠️ The name can't be empty.",
+    save_name: "Save name",
+    new_player_placeholder: "New player's name...",
+    add_player: "➕ Add",
+    player_range: |min, max| format!("{min}-{max} players"),
+    save_and_play_next_round: "Save & Play Next Round",
+    game_settings: "⚙️ Game Settings",
+    game_mode_label: "🃏 Game mode",
+    word_pairs_mode: "Word pairs",
+    location_mode: "Location & roles (Spyfall-style)",
+    location_mode_description: "Everyone gets a location and a role - the spy gets nothing and must guess where you are",
+    word_pairs_description: "Civilians share a word, the imposter gets a similar one",
+    word_pack_label: "📦 Word pack",
+    mixed_pack: "🎲 Mixed (all categories)",
+    difficulty_label: "🎯 Word difficulty",
+    any_difficulty_option: "Any",
+    easy_option: "Easy (clearly different words)",
+    medium_option: "Medium",
+    hard_option: "Hard (near-identical words)",
+    difficulty_description: "How close the imposter's word is to everyone else's. Categories without pairs of that level use all their pairs.",
+    imposter_card_label: "🎭 Imposter's card",
+    similar_word_option: "A similar word",
+    no_word_option: "No word at all (hard)",
+    category_hint_option: "Just the category (medium)",
+    custom_pairs_label: "✍️ Custom word pairs",
+    custom_pairs_empty: "One pair per line, separated by a comma. Leave empty to use the built-in words",
+    custom_pairs_in_use: |n| format!("Using {n} custom pair(s) instead of the built-in words"),
+    skip_flagged_label: "🚩 Skip flagged pairs",
+    skip_flagged_description: "Leave out the word pairs flagged at the end of a round on this device. If every pair is flagged, they're all dealt again.",
+    custom_category: "Custom Words",
+    custom_pair_line_error: |line| format!("Line {line}: expected two words separated by a comma"),
+    twist_round_label: "Twist round",
+    twist_round_description: "In similar-word rounds, deal the imposter a word from an unrelated pair. Everyone is told a twist is on, but not who has it.",
+    timer_label: "⏱️ Discussion timer",
+    timer_off: "Off",
+    one_minute: "1 minute",
+    two_minutes: "2 minutes",
+    three_minutes: "3 minutes",
+    timer_description: "Count down each discussion; the vote is never forced when time runs out",
+    reveal_timeout_label: "⏩ Auto-pass after reveal",
+    seconds_option: |n| format!("{n} seconds"),
+    reveal_timeout_description: "Once a card is revealed, move on to the next player after this long. Next Player still works early.",
+    pass_delay_label: "✋ Pause before each reveal",
+    pass_delay_description: "After a card has been seen, Reveal My Card waits this long for the next player, so the last one has time to look away.",
+    get_ready_in: |n| format!("Get ready… {n}"),
+    win_threshold_label: "🏁 Imposter wins at",
+    players_left: |n| format!("{n} players left"),
+    win_threshold_description: "The imposter wins if they survive until this few players remain",
+    skip_cap_label: "🤷 Skips in a row",
+    skip_cap_off: "Never (always evict)",
+    skip_cap_description: "How many votes in a row may end with \"No Evict This Round\" before the group has to evict someone.",
+    private_game_label: "🕶️ Private game",
+    private_game_description: "Don't save anything on this device - reloading the page starts fresh",
+    event_log_label: "📊 Record screen changes",
+    event_log_description: "Keep a timestamped log of every screen change in this game's save (and on the server, unless the game is private) for studying how games play out",
+    role_check_label: "🔒 Private role check",
+    role_check_description: "Evicted players can privately check whether they were the imposter",
+    reveal_imposter_label: "🕵️ Reveal the imposter after each round",
+    reveal_imposter_description: "Turn off to keep the imposter's name hidden at round end. The scoreboard lists each round's imposter instead.",
+    animations_label: "🎉 Animations",
+    animations_description: "Confetti when a round is won and a card flip on each eviction",
+    name_check_label: "🔤 Name check before reveal",
+    name_check_description: "Players must type the first letter of their name to unlock their card",
+    tips_label: "💡 Strategy tips",
+    tips_description: "Show new players a one-line tip for their role under each revealed card",
+    blind_deal_label: "🙈 Blind deal",
+    blind_deal_description: "The imposter is only settled as each card is revealed, so nobody can see it on screen ahead of time. The round end shows the seed so the table can check the deal wasn't changed. Print-and-play is off for these rounds",
+    sealed_deal_code: |code| format!("🔒 Sealed deal {code}"),
+    sealed_deal_seed: |code, seed| format!("🔒 Sealed deal {code}: the seed was {seed}"),
+    sealed_deal_matches: "✓ Its SHA-256 matches the code shown before the first reveal",
+    sealed_deal_mismatch: "✗ Its SHA-256 doesn't match the code shown before the first reveal",
+    random_category_label: "🎲 Random category each round",
+    random_category_description: "Skip category selection and draw a surprise theme every round",
+    nudge_label: "👀 Pass-the-device nudge",
+    nudge_description: "Show a gentle reminder when cards are revealed suspiciously fast",
+    shuffle_order_label: "🔀 Random pass order",
+    shuffle_order_description: "Shuffle who sees their card and who speaks first each round, instead of going around the table.",
+    fair_rotation_label: "⚖️ Fair imposter rotation",
+    fair_rotation_description: "Players who've been the imposter least this game are likelier to get it next (still random, so nobody can be sure)",
+    secret_ballot_label: "🗳️ Secret ballot",
+    secret_ballot_description: "Pass the device so each player votes privately; most votes is evicted",
+    hide_live_tally_label: "Hide counts until all votes are in",
+    hide_live_tally_description: "Off: a running count of the ballots so far is shown while the device goes round",
+    votes_so_far: "Votes so far",
+    tied_ballots_label: "⚖️ Tied ballots",
+    revote_option: "Revote among the tied players",
+    no_elimination_option: "Nobody is evicted this round",
+    quick_catch_label: "⚡ Quick-catch bonus",
+    quick_catch_description: "Civilians earn extra points for catching the imposter in fewer votes",
+    grace_round_label: "🕊️ Grace round",
+    grace_round_description: "Open the game with a discussion-only vote where nobody is evicted",
+    comeback_label: "🎯 Imposter's last guess",
+    comeback_description: "An evicted imposter gets one guess at the civilians' word; getting it right still wins the round",
+    reveal_role_label: "🕵️ Evictions reveal roles",
+    reveal_role_description: "Turn off to keep every eviction a mystery: voting goes on until the round runs out, and roles are only shown at the end",
+    session_label: |id| format!("Session: {id}"),
+    saving: "Saving…",
+    saved: "Saved ✓",
+    not_saved: "Not saved",
+    cannot_save: "Can't save",
+    viewing_only: "Viewing only",
+    saving_unavailable: "Saving unavailable in this browser mode. The game plays as normal, but reloading the page starts over.",
+    private_not_saved: "Private — not saved",
+    announce_setup: "Game setup.",
+    announce_category_selection: "Choose a category.",
+    announce_category: |name| format!("Category: {name}."),
+    announce_cards_done: "Everyone has seen their card. Ready for discussion.",
+    announce_card_turn: |name| format!("Cards. Pass the device to {name}."),
+    announce_voting: |n| format!("Voting. {n} players remaining."),
+    announce_secret_ballot: |n, total| format!("Secret ballot. Vote {n} of {total}."),
+    announce_tie: |n| format!("Tie between {n} players."),
+    announce_vote_reveal: "Votes counted.",
+    announce_evicted: |name| format!("{name} was evicted."),
+    announce_game_over: "Game over.",
+    announce_imposter_caught: "Round over. The imposter was caught.",
+    announce_imposter_escaped: "Round over. The imposter got away.",
+    announce_scoreboard: "Scoreboard.",
+    announce_stats: "Player stats.",
+    announce_round_history: "Round history.",
+    announce_replay: "Round replay.",
+    announce_edit_players: "Edit players.",
+    announce_imposter_guess: "The imposter gets one guess at the word",
+    mute_sound: "🔊 Sound on",
+    unmute_sound: "🔇 Muted",
+    toggle_sound_label: "Toggle sound effects",
+    dark_theme: "🌙 Dark",
+    light_theme: "☀️ Light",
+    toggle_theme_label: "Switch between light and dark theme",
+};
+
+static SPANISH: UiText = UiText {
+    subtitle: "El juego de deducción social",
+    language_label: "🌐 Idioma",
+    large_text_label: "🔠 Texto grande",
+    large_text_description: "Texto más grande en todas las pantallas",
+    high_contrast_label: "◐ Alto contraste",
+    high_contrast_description: "Fondos sólidos y contornos más marcados para leer mejor",
+    display_mode_label: "📺 Se muestra en",
+    display_mode_phone: "Un móvil que se pasa",
+    display_mode_tv: "Una tele o pantalla grande",
+    display_mode_description: "Cartas y texto más grandes, con las listas repartidas a lo ancho",
+    number_of_players: "👥 Número de jugadores",
+    minimum_players: |n| format!("(mínimo {n})"),
+    player_count_clamped: |min, max, count| format!("Elige entre {min} y {max} jugadores: se quedará en {count}."),
+    player_count_not_a_number: |count| format!("Eso no es un número: siguen siendo {count} jugadores."),
+    player_names_heading: "✏️ Nombres de los jugadores",
+    enter_name_placeholder: "Escribe un nombre...",
+    duplicate_names_warning: "⚠️ Cada jugador necesita un nombre distinto: los nombres resaltados están repetidos.",
+    number_automatically: "Numerarlos automáticamente",
+    start_game: "🚀 Empezar partida",
+    quick_start: "⚡ Inicio rápido",
+    quick_start_hint: "Sin nombres: jugad como Jugador 1, Jugador 2…",
+    practice_solo: "🧑 Practicar solo",
+    practice_solo_hint: "Aprende a jugar solo: eres el primer jugador y la app pasa las cartas del resto.",
+    you_label: "Tú",
+    numbered_player: |n| format!("Jugador {n}"),
+    share_setup: "🔗 Compartir esta configuración",
+    show_join_link: "📲 Enlace para unirse",
+    join_link_label: "Enlace para unirse a esta partida",
+    join_link_hint: "Ábrelo en otro dispositivo para seguir el marcador de esta partida. Necesita el servidor, así que las partidas privadas no admiten unirse.",
+    join_qr_label: "Código QR del enlace para unirse",
+    rejoin_code_is: |code| format!("🔑 Código para volver: {code}"),
+    rejoin_code_hint: "Si este dispositivo se apaga, introduce el código en «Restaurar o importar una partida» en otro dispositivo para seguir.",
+    setup_link_label: "Enlace de configuración",
+    restore_or_import: "🔑 Recuperar o importar una partida",
+    saved_games_heading: "📂 Partidas guardadas en este dispositivo",
+    saved_game_details: |code, round, players| format!("{code} · ronda {round} · {players} jugadores"),
+    resume_saved_game: "Continuar",
+    delete_saved_game_label: "Borrar esta partida guardada",
+    delete_saved_game_title: "¿Borrar la partida guardada?",
+    delete_saved_game_message: |code| format!("La partida {code} se borrará de este dispositivo. Si hay una copia en el servidor, se conserva."),
+    confirm_delete_saved_game: "Sí, borrar",
+    session_code_placeholder: "Pega el código de sesión...",
+    no_saved_game: "No hay ninguna partida guardada con ese código",
+    rejoin_code_expired: "Ese código ha caducado: la partida lleva más de 30 días sin jugarse",
+    rejoin_server_unreachable: "No se pudo contactar con el servidor para buscar ese código. Revisa la conexión e inténtalo de nuevo",
+    rejoin_save_unreadable: "Hay una partida guardada con ese código, pero no se pudo leer",
+    restore: "Recuperar",
+    import_exported: "📂 Importar una partida exportada",
+    read_file_failed: "No se pudo leer ese archivo",
+    import_not_an_export: |detail| format!("Ese archivo no es una exportación de partida válida ({detail})"),
+    import_newer_version: "Esa partida se exportó desde una versión más reciente de la app",
+    import_no_game: "Ese archivo no contiene ninguna partida",
+    dismiss: "Cerrar",
+    choose_category: "🎯 Elige tu categoría",
+    choose_category_subtitle: "Escoge un tema para esta ronda",
+    word_pair_count: |n| format!("{n} parejas de palabras"),
+    category_title: "Categoría",
+    category_hint: "Todos recibirán palabras relacionadas con esta categoría",
+    hard_mode: "🔥 Modo difícil",
+    hard_mode_on: "¡El impostor no sabrá que es el impostor!",
+    hard_mode_off: "Actívalo para ocultarle al impostor su propio papel",
+    back_to_categories: "← Volver a las categorías",
+    start_round: "▶️ Empezar ronda",
+    location_category: "Lugar",
+    all_cards_seen: "¡Todos han visto su carta!",
+    almost_all_cards_seen: "Casi todos han visto su carta",
+    skipped_players_warning: "⚠️ Estos jugadores se saltaron su turno y aún no han visto su carta:",
+    show_players_card: |name| format!("👀 Mostrar la carta de {name}"),
+    proceed_to_discussion: "Pasar al debate",
+    proceed_without_them: "Seguir sin ellos",
+    preparing_cards: "Preparando las cartas...",
+    pass_device_to: "Pasa el dispositivo a:",
+    simulated_turn: |name| format!("{name} está mirando su carta…"),
+    round_theme: |icon, name| format!("Tema de esta ronda: {icon} {name}"),
+    keep_screen_private: "⚠️ ¡Asegúrate de que nadie más vea la pantalla!",
+    name_check_prompt: "Escribe la primera letra de tu nombre para desbloquear",
+    back: "← Atrás",
+    reveal_my_card: "Ver mi carta",
+    skip_for_now: "⏭️ Saltar por ahora",
+    card_progress: |n, total| format!("Jugador {n} de {total}"),
+    reveal_key_hint: "Consejo: pulsa Espacio para ver tu carta",
+    print_cards: "🖨️ Imprimir cartas (solo el anfitrión: muestra los papeles)",
+    seat_swap_nudge: "👀 ¡Qué rápido! Asegúrate de que el dispositivo pasa por cada jugador.",
+    players_card: |name| format!("Carta de {name}"),
+    card_hidden: "Carta oculta",
+    show_card_again: "👁️ Volver a ver mi carta",
+    hide_card: "🙈 Ocultar mi carta",
+    next_player: "Siguiente jugador",
+    auto_next_in: |n| format!("Pasando en {n} s…"),
+    pass_on_key_hint: "Consejo: pulsa Espacio para ocultar tu carta y pasar el dispositivo",
+    cancel: "Cancelar",
+    print_cards_title: "🖨️ Imprimir cartas",
+    print_cards_warning: "Esto muestra la palabra de cada jugador y quién es el impostor. Solo el anfitrión debería continuar.",
+    host_show_cards: "Soy el anfitrión: mostrar cartas",
+    print: "Imprimir",
+    close: "Cerrar",
+    imposter_label: "🎭 Impostor",
+    civilian_label: "👥 Civil",
+    fold_here: "✂ dobla por aquí antes de repartir",
+    spy_card: "🕵️ ESPÍA",
+    spy_hint: "¡No sabes cuál es el lugar!",
+    spy_instruction: "Escucha con atención y descubre dónde estáis sin delatarte.",
+    no_word_hint: "No tienes palabra: ¡disimula!",
+    no_word_instruction: "Escucha a los demás, adivina su palabra y finge que la conoces.",
+    category_hint_card: |hint| format!("Categoría: {hint}"),
+    category_hint_hint: "Solo conoces la categoría: ¡disimula!",
+    category_hint_instruction: "Encuentra tu palabra para pasar desapercibido: escucha lo que encaja en la categoría y finge que la conoces.",
+    your_role: |role| format!("Tu papel: {role}"),
+    you_are_imposter: "🎭 ¡Eres el IMPOSTOR!",
+    you_are_civilian: "👥 Eres civil",
+    location_instruction: "¡Encuentra al espía! Haz preguntas que solo alguien en este lugar sabría responder.",
+    find_odd_word: "¡Encuentra al jugador con la palabra distinta!",
+    imposter_instruction: "¡Intenta pasar desapercibido! Que nadie note que tu palabra es distinta.",
+    civilian_tip: "💡 Consejo: da una pista que solo entienda quien tenga tu palabra, sin revelarla.",
+    imposter_tip: "💡 Consejo: deja hablar primero a los demás y da una pista tan vaga que encaje con lo que describan.",
+    restart_round_title: "¿Reiniciar la ronda?",
+    restart_round_message: "Se reiniciará la ronda actual y volverás a elegir categoría. Se perderá el progreso.",
+    confirm_restart_round: "Sí, reiniciar la ronda",
+    end_game_title: "¿Terminar la partida aquí?",
+    end_game_message: "Esta ronda no contará. Todos conservan sus puntos y pasáis directamente al marcador.",
+    confirm_end_game: "Sí, ver el marcador",
+    discussion_and_voting: "🗳️ Debate y votación",
+    times_up: "¡Se acabó el tiempo! Terminad y votad.",
+    resume: "▶️ Reanudar",
+    pause: "⏸️ Pausar",
+    paused: "En pausa",
+    discussion_order: "💬 Orden del debate (cada uno describe su palabra):",
+    secret_vote_hint: "Tras el debate, pasad el dispositivo y votad en secreto.",
+    open_vote_hint: "Tras el debate, tocad la tarjeta del jugador que acordéis expulsar.",
+    solo_vote_hint: "Ronda de práctica: tú decides por toda la mesa.",
+    grace_round: "🕊️ Ronda de cortesía",
+    grace_round_hint: "Debatid y recabad pistas: esta vez nadie es expulsado.",
+    continue_to_real_vote: "Pasar a la votación de verdad",
+    start_secret_ballot: "🗳️ Empezar votación secreta",
+    skipped_tag: "🙈 No ha visto su carta",
+    evict: "Expulsar",
+    confirm_evict: |name| format!("Toca otra vez para confirmar: ¿expulsar a {name}?"),
+    no_evict_this_round: "🤷 Nadie sale esta ronda",
+    skips_left: |n| if n == 1 { "Queda 1 salto antes de tener que expulsar a alguien.".to_string() } else { format!("Quedan {n} saltos antes de tener que expulsar a alguien.") },
+    skips_used_up: "Ya habéis saltado bastante: esta votación tiene que expulsar a alguien.",
+    revert_last_eviction: |name| format!("⏪ Deshacer la última expulsión ({name})"),
+    restart_round: "🔄 Reiniciar ronda",
+    end_game: "🏁 Terminar partida",
+    remind_me: "🔁 Recordarme mi palabra",
+    who_needs_reminder: "¿Quién necesita recordarla?",
+    pass_device_only_to: |name| format!("Pasa el dispositivo solo a {name}."),
+    show_my_card_as: |name| format!("Soy {name}: mostrar mi carta"),
+    card_unavailable: "Esta carta ya no está disponible.",
+    hide_and_pass_back: "Ocultar y devolver",
+    moderator_mode_label: "🛡️ Modo moderador",
+    moderator_mode_description: "Para un moderador que no juega: un panel que se abre manteniendo pulsado en la pantalla de votación muestra las dos palabras y el impostor. Cualquiera puede activarlo, así que úsalo solo con un grupo de confianza",
+    single_vote_label: "🎯 Reto de un solo voto",
+    single_vote_description: "Cooperativo: el grupo tiene un solo voto para encontrar al impostor. Una expulsión equivocada da la ronda al impostor; una acertada da un extra a cada civil.",
+    single_vote_banner: "🎯 Reto de un solo voto: solo tenéis un voto, que cuente.",
+    twist_banner: "🌀 Ronda con giro: la palabra del impostor viene de otra pareja.",
+    single_vote_bonus: |points| format!("Reto de un solo voto: +{points}"),
+    challenge_lost: "🎯 Era el único voto, así que el impostor gana la ronda.",
+    challenge_vote_cast: "🎯 Era el único voto, así que la ronda termina aquí y se revelan los roles.",
+    moderator_hold: "🛡️ Moderador: mantén pulsado",
+    moderator_civilian_word: "Palabra de los civiles",
+    moderator_imposter_word: "Palabra del impostor",
+    moderator_imposter: "Impostor",
+    moderator_hide: "🙈 Ocultar",
+    all_votes_in: "¡Ya están todos los votos!",
+    count_votes: "Contar los votos",
+    the_votes: "🗳️ Los votos",
+    vote_count: |n| if n == 1 { "1 voto".to_string() } else { format!("{n} votos") },
+    reveal_eviction: "Revelar la expulsión",
+    secret_ballot: "🗳️ Votación secreta",
+    ballot_progress: |n, total| format!("Voto {n} de {total}"),
+    revote_hint: "Nueva votación: solo se puede elegir a los empatados.",
+    open_my_ballot: |name| format!("Soy {name}: abrir mi papeleta"),
+    who_is_imposter: |name| format!("{name}, ¿quién es el impostor?"),
+    vote: "Votar",
+    its_a_tie: "⚖️ ¡Empate!",
+    tied_players_intro: "Estos jugadores recibieron el mismo número de votos:",
+    revote_explainer: "Todos vuelven a votar, eligiendo solo entre los empatados.",
+    revote: "🗳️ Volver a votar",
+    no_elimination_explainer: "Esta vez nadie es expulsado. Volved a debatir y votad de nuevo.",
+    revotes_exhausted: |n| format!("Sigue el empate tras {n} votaciones más, así que esta vez nadie es expulsado. Volved a debatir y votad de nuevo."),
+    back_to_discussion: "Volver al debate",
+    imposter: "Impostor",
+    civilian: "Civil",
+    imposter_evicted: "🎉 ¡Impostor expulsado!",
+    was_the_imposter: |name| format!("🎊 ¡{name} era el IMPOSTOR!"),
+    civilians_win_round: "🏆 ¡Los civiles ganan esta ronda!",
+    comeback_pending: "…¡salvo que adivine la palabra de los civiles en su único intento!",
+    imposter_guess_heading: |name| format!("🎯 {name}, ¡última oportunidad!"),
+    imposter_guess_prompt: "Adivina la palabra de los civiles. Si aciertas, ganas la ronda igualmente.",
+    imposter_guess_label: "Tu respuesta",
+    lock_in_guess: "🎯 Confirmar respuesta",
+    no_guess: "Sin respuesta",
+    civilian_reward_with_bonus: |total, bonus| format!("Los civiles restantes reciben +{total} puntos (incluye +{bonus} por pillarlo rápido)"),
+    civilian_reward: |points| format!("Los civiles restantes reciben +{points} puntos"),
+    civilian_evicted: "😔 Civil expulsado",
+    was_a_civilian: |name| format!("💔 {name} era CIVIL"),
+    imposter_remains: "😈 El impostor sigue entre vosotros...",
+    players_remaining: |n| format!("Quedan {n} jugadores"),
+    player_is_out: |name| format!("{name} queda fuera"),
+    roles_stay_hidden: "🤫 Los roles siguen ocultos hasta que termine la ronda. La ronda continúa...",
+    undo: "↩️ Deshacer",
+    continue_label: "Continuar",
+    role_check_for: |name| format!("🔒 Comprobación privada de papel para {name}"),
+    show_my_role_as: |name| format!("Soy {name}: mostrar mi papel"),
+    you_were_imposter: "🎭 Eras el impostor.",
+    you_were_civilian: "👥 Eras civil.",
+    your_word_was: |word| format!("Tu palabra era «{word}». Estás fuera: ¡relájate y disfruta del espectáculo!"),
+    round_cannot_continue: "⚠️ Esta ronda no puede continuar",
+    round_recovery_explainer: "La ronda guardada no coincide con los jugadores actuales, así que hay que repartir de nuevo. Se conservan las puntuaciones.",
+    redeal_round: "Repartir de nuevo",
+    back_to_setup: "Volver a la configuración",
+    new_game_title: "¿Empezar una partida nueva?",
+    new_game_message: "¿Empezar una partida nueva? Se perderán las puntuaciones actuales.",
+    confirm_new_game: "Sí, empezar partida nueva",
+    unknown_player: "Desconocido",
+    civilians_win: "✅ ¡Ganan los civiles!",
+    imposter_wins: "😈 ¡Gana el impostor!",
+    the_imposter_was: |name| format!("El impostor era: {name}"),
+    words_this_round: "📝 Palabras de esta ronda:",
+    civilian_word: "👥 Palabra civil:",
+    imposter_word: "🎭 Palabra del impostor:",
+    flag_pair: "🚩 Marcar esta pareja",
+    pair_flagged: "🚩 Marcada (toca para deshacer)",
+    civilians_get_points: |points| format!("🎉 ¡Los civiles restantes ganan {points} puntos!"),
+    base_win: |points| format!("Victoria: +{points}"),
+    quick_catch_on_vote: |vote, bonus| format!("Pillado en la votación {vote}: +{bonus}"),
+    insight_first_vote: |_| "🧠 Pillado a la primera votación: ¡gran deducción!".to_string(),
+    insight_quick_catch: |votes| format!("🔍 Descubierto en {votes} votaciones: buen trabajo."),
+    insight_slow_catch: |votes| format!("😅 Hicieron falta {votes} votaciones, ¡pero al final lo conseguisteis!"),
+    insight_stolen_word: |_| "🎯 Pillado, ¡pero el impostor adivinó la palabra y se llevó la ronda!".to_string(),
+    insight_one_vote_escape: |_| "🥷 Una votación y un error: ¡el impostor se sale con la suya!".to_string(),
+    insight_quick_escape: |votes| format!("🥷 El impostor se escapó en solo {votes} votaciones: ¡qué habilidad!"),
+    insight_long_escape: |votes| format!("🎭 El impostor sobrevivió a {votes} votaciones: ¡os ha costado!"),
+    imposter_gets_points: |points| format!("😈 ¡El impostor gana {points} puntos!"),
+    see_everyones_card: "🃏 Ver las cartas de todos",
+    imposter_reveal: "🕵️ El impostor era",
+    imposter_named: |name| format!("Impostor: {name}"),
+    imposters_so_far: "🕵️ Impostores hasta ahora",
+    round_imposter: |round, name| format!("Ronda {round}: {name}"),
+    next_round: "▶️ Siguiente ronda",
+    view_scores: "🏆 Ver puntuaciones",
+    new_game: "🔄 Partida nueva",
+    out_tag: "(fuera)",
+    roster_heading: "Quién sigue en juego",
+    roster_count: |left, total| format!("Siguen {left} de {total}"),
+    scoreboard: "🏆 Marcador",
+    card_view_toggle: "🃏 Vista de tarjetas",
+    compact_view_toggle: "📋 Vista compacta",
+    player_column: "Jugador",
+    points_column: "Puntos",
+    points_total: |points| format!("{points} puntos"),
+    play_next_round: "Jugar la siguiente ronda",
+    edit_players: "👥 Editar jugadores",
+    new_game_plain: "Partida nueva",
+    player_stats: "📊 Estadísticas",
+    export_game: "💾 Exportar partida",
+    copy_results: "📋 Copiar resultados",
+    results_copied: "✅ Resultados copiados: pégalos donde quieras",
+    copy_results_fallback: "Aquí no se puede copiar automáticamente. Los resultados están seleccionados abajo para copiarlos a mano.",
+    results_label: "Resultados de la partida",
+    results_imposter_caught: |name, round| format!("El impostor era {name}, descubierto en la ronda {round}."),
+    results_imposter_escaped: |name, round| format!("El impostor era {name} y escapó en la ronda {round}."),
+    results_winner: |names, points| format!("Victoria para {names} con {points} pts."),
+    results_final_scores: "Puntuación final",
+    after_round: |round| format!("Tras la ronda {round}"),
+    games_column: "Partidas",
+    imposter_column: "Impostor",
+    imposter_wins_column: "Victorias de impostor",
+    civilian_wins_column: "Victorias de civil",
+    stats_hint: "Totales de todas las partidas jugadas en este dispositivo. Toca una columna para ordenar.",
+    stats_empty: "Aún no hay partidas terminadas: las estadísticas aparecen al acabar la primera.",
+    back_to_scoreboard: "← Volver al marcador",
+    round_history: "📜 Historial de rondas",
+    round_history_hint: "Todas las rondas de esta sesión, de la más reciente a la más antigua.",
+    round_history_empty: "Aún no ha terminado ninguna ronda.",
+    replay_last_round: "🎬 Repetir la última ronda",
+    replay_title: |round| format!("🎬 Repetición de la ronda {round}"),
+    replay_progress: |step, total| format!("Paso {step} de {total}"),
+    replay_eviction: |n| format!("Expulsión {n}"),
+    replay_evicted: |name| format!("{name} fue expulsado:"),
+    replay_previous: "← Anterior",
+    replay_next: "Siguiente →",
+    round_column: "Ronda",
+    words_column: "Palabras",
+    winner_column: "Ganador",
+    civilians_won_cell: "👥 Civiles",
+    imposter_won_cell: "🎭 Impostor",
+    edit_players_hint: "Se conservan las puntuaciones de quienes se quedan. Los nuevos empiezan con 0.",
+    points_short: |points| format!("{points} pts"),
+    remove_player: |name| format!("Quitar a {name}"),
+    names_must_differ: "⚠️ Cada jugador necesita un nombre distinto.",
+    rename_player: |name| format!("Cambiar el nombre de {name}"),
+    rename_heading: "✏️ Corregir un nombre",
+    name_cannot_be_empty: "⚠️ El nombre no puede quedar vacío.",
+    save_name: "Guardar nombre",
+    new_player_placeholder: "Nombre del nuevo jugador...",
+    add_player: "➕ Añadir",
+    player_range: |min, max| format!("De {min} a {max} jugadores"),
+    save_and_play_next_round: "Guardar y jugar la siguiente ronda",
+    game_settings: "⚙️ Ajustes de la partida",
+    game_mode_label: "🃏 Modo de juego",
+    word_pairs_mode: "Parejas de palabras",
+    location_mode: "Lugar y papeles (estilo Spyfall)",
+    location_mode_description: "Todos reciben un lugar y un papel; el espía no recibe nada y debe adivinar dónde estáis",
+    word_pairs_description: "Los civiles comparten palabra y el impostor recibe una parecida",
+    word_pack_label: "📦 Paquete de palabras",
+    mixed_pack: "🎲 Mezcla (todas las categorías)",
+    difficulty_label: "🎯 Dificultad de las palabras",
+    any_difficulty_option: "Cualquiera",
+    easy_option: "Fácil (palabras muy distintas)",
+    medium_option: "Media",
+    hard_option: "Difícil (palabras casi iguales)",
+    difficulty_description: "Lo parecida que es la palabra del impostor a la del resto. Las categorías sin parejas de ese nivel usan todas sus parejas.",
+    imposter_card_label: "🎭 Carta del impostor",
+    similar_word_option: "Una palabra parecida",
+    no_word_option: "Ninguna palabra (difícil)",
+    category_hint_option: "Solo la categoría (media)",
+    custom_pairs_label: "✍️ Parejas propias",
+    custom_pairs_empty: "Una pareja por línea, separada por una coma. Déjalo vacío para usar las palabras incluidas",
+    custom_pairs_in_use: |n| format!("Usando {n} pareja(s) propia(s) en lugar de las palabras incluidas"),
+    skip_flagged_label: "🚩 Saltar parejas marcadas",
+    skip_flagged_description: "Deja fuera las parejas marcadas al final de una ronda en este dispositivo. Si todas están marcadas, se vuelven a repartir todas.",
+    custom_category: "Palabras propias",
+    custom_pair_line_error: |line| format!("Línea {line}: se esperaban dos palabras separadas por una coma"),
+    twist_round_label: "Ronda con giro",
+    twist_round_description: "En las rondas de palabra parecida, el impostor recibe una palabra de otra pareja sin relación. Todos saben que hay giro, pero no quién lo tiene.",
+    timer_label: "⏱️ Temporizador del debate",
+    timer_off: "Desactivado",
+    one_minute: "1 minuto",
+    two_minutes: "2 minutos",
+    three_minutes: "3 minutos",
+    timer_description: "Cuenta atrás en cada debate; nunca se obliga a votar cuando se acaba el tiempo",
+    reveal_timeout_label: "⏩ Pasar solo tras ver la carta",
+    seconds_option: |n| format!("{n} segundos"),
+    reveal_timeout_description: "Tras ver una carta, pasa al siguiente jugador pasado este tiempo. «Siguiente jugador» sigue funcionando antes.",
+    pass_delay_label: "✋ Pausa antes de cada carta",
+    pass_delay_description: "Después de ver una carta, «Ver mi carta» espera este tiempo al siguiente jugador, para que el anterior pueda apartar la vista.",
+    get_ready_in: |n| format!("Prepárate… {n}"),
+    win_threshold_label: "🏁 El impostor gana con",
+    players_left: |n| format!("{n} jugadores restantes"),
+    win_threshold_description: "El impostor gana si sobrevive hasta que queden tan pocos jugadores",
+    skip_cap_label: "🤷 Saltos seguidos",
+    skip_cap_off: "Nunca (siempre se expulsa)",
+    skip_cap_description: "Cuántas votaciones seguidas pueden acabar con «Nadie sale esta ronda» antes de tener que expulsar a alguien.",
+    private_game_label: "🕶️ Partida privada",
+    private_game_description: "No guardar nada en este dispositivo: al recargar la página se empieza de cero",
+    event_log_label: "📊 Registrar cambios de pantalla",
+    event_log_description: "Guardar un registro con hora de cada cambio de pantalla en la partida guardada (y en el servidor, salvo en partidas privadas) para estudiar cómo se desarrollan las partidas",
+    role_check_label: "🔒 Comprobación privada de papel",
+    role_check_description: "Los expulsados pueden comprobar en privado si eran el impostor",
+    reveal_imposter_label: "🕵️ Revelar al impostor tras cada ronda",
+    reveal_imposter_description: "Desactívalo para no mostrar el nombre del impostor al final de la ronda. El marcador muestra el impostor de cada ronda.",
+    animations_label: "🎉 Animaciones",
+    animations_description: "Confeti al ganar una ronda y carta girada en cada expulsión",
+    name_check_label: "🔤 Comprobar el nombre antes de mostrar",
+    name_check_description: "Cada jugador debe escribir la primera letra de su nombre para desbloquear su carta",
+    tips_label: "💡 Consejos de estrategia",
+    tips_description: "Muestra a los nuevos un consejo breve para su papel bajo cada carta revelada",
+    blind_deal_label: "🙈 Reparto a ciegas",
+    blind_deal_description: "El impostor solo se decide al revelar cada carta, así que nadie puede verlo en pantalla antes de tiempo. Al final de la ronda se muestra la semilla para que la mesa compruebe que el reparto no cambió. La opción de imprimir cartas no está disponible en estas rondas",
+    sealed_deal_code: |code| format!("🔒 Reparto sellado {code}"),
+    sealed_deal_seed: |code, seed| format!("🔒 Reparto sellado {code}: la semilla era {seed}"),
+    sealed_deal_matches: "✓ Su SHA-256 coincide con el código mostrado antes de la primera carta",
+    sealed_deal_mismatch: "✗ Su SHA-256 no coincide con el código mostrado antes de la primera carta",
+    random_category_label: "🎲 Categoría aleatoria en cada ronda",
+    random_category_description: "Saltar la elección de categoría y sacar un tema sorpresa en cada ronda",
+    nudge_label: "👀 Aviso de pasar el dispositivo",
+    nudge_description: "Mostrar un recordatorio cuando las cartas se ven sospechosamente rápido",
+    shuffle_order_label: "🔀 Orden de paso aleatorio",
+    shuffle_order_description: "Baraja cada ronda quién ve su carta y quién habla primero, en lugar de seguir el orden de la mesa.",
+    fair_rotation_label: "⚖️ Rotación justa del impostor",
+    fair_rotation_description: "Quien menos veces ha sido impostor en esta partida tiene más probabilidades de serlo (sigue siendo al azar, nadie puede estar seguro)",
+    secret_ballot_label: "🗳️ Votación secreta",
+    secret_ballot_description: "Pasad el dispositivo para votar en privado; el más votado es expulsado",
+    hide_live_tally_label: "Ocultar el recuento hasta el final",
+    hide_live_tally_description: "Desactivado: se muestra el recuento de las papeletas mientras el dispositivo pasa de mano",
+    votes_so_far: "Votos hasta ahora",
+    tied_ballots_label: "⚖️ Empates",
+    revote_option: "Votar de nuevo entre los empatados",
+    no_elimination_option: "Nadie es expulsado esta ronda",
+    quick_catch_label: "⚡ Bonus por pillarlo rápido",
+    quick_catch_description: "Los civiles ganan puntos extra si pillan al impostor en menos votaciones",
+    grace_round_label: "🕊️ Ronda de cortesía",
+    grace_round_description: "Empezar la partida con una votación solo de debate en la que nadie es expulsado",
+    comeback_label: "🎯 Última oportunidad del impostor",
+    comeback_description: "El impostor expulsado tiene un intento para adivinar la palabra de los civiles; si acierta, gana la ronda igualmente",
+    reveal_role_label: "🕵️ Las expulsiones revelan el rol",
+    reveal_role_description: "Desactívalo para que cada expulsión sea un misterio: se sigue votando hasta que la ronda termina y los roles solo se muestran al final",
+    session_label: |id| format!("Sesión: {id}"),
+    saving: "Guardando…",
+    saved: "Guardado ✓",
+    not_saved: "Sin guardar",
+    cannot_save: "No se puede guardar",
+    viewing_only: "Solo lectura",
+    saving_unavailable: "No se puede guardar en este modo del navegador. La partida funciona igual, pero al recargar la página empieza de nuevo.",
+    private_not_saved: "Privada: sin guardar",
+    announce_setup: "Configuración de la partida.",
+    announce_category_selection: "Elige una categoría.",
+    announce_category: |name| format!("Categoría: {name}."),
+    announce_cards_done: "Todos han visto su carta. Listos para el debate.",
+    announce_card_turn: |name| format!("Cartas. Pasa el dispositivo a {name}."),
+    announce_voting: |n| format!("Votación. Quedan {n} jugadores."),
+    announce_secret_ballot: |n, total| format!("Votación secreta. Voto {n} de {total}."),
+    announce_tie: |n| format!("Empate entre {n} jugadores."),
+    announce_vote_reveal: "Votos contados.",
+    announce_evicted: |name| format!("{name} ha sido expulsado."),
+    announce_game_over: "Fin de la partida.",
+    announce_imposter_caught: "Fin de la ronda. Han pillado al impostor.",
+    announce_imposter_escaped: "Fin de la ronda. El impostor se ha escapado.",
+    announce_scoreboard: "Marcador.",
+    announce_stats: "Estadísticas de los jugadores.",
+    announce_round_history: "Historial de rondas.",
+    announce_replay: "Repetición de la ronda.",
+    announce_edit_players: "Editar jugadores.",
+    announce_imposter_guess: "El impostor tiene un intento para adivinar la palabra",
+    mute_sound: "🔊 Sonido activado",
+    unmute_sound: "🔇 Silenciado",
+    toggle_sound_label: "Activar o desactivar los sonidos",
+    dark_theme: "🌙 Oscuro",
+    light_theme: "☀️ Claro",
+    toggle_theme_label: "Cambiar entre tema claro y oscuro",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_round_trip() {
+        for lang in Lang::ALL {
+            assert_eq!(Lang::from_code(lang.code()), lang);
+        }
+        assert_eq!(Lang::from_code("xx"), Lang::English);
+    }
+
+    #[test]
+    fn spanish_chrome_is_translated() {
+        let (en, es) = (ui_text(Lang::English), ui_text(Lang::Spanish));
+        assert_ne!(en.start_game, es.start_game);
+        assert_ne!(en.reveal_my_card, es.reveal_my_card);
+        assert_eq!((es.announce_card_turn)("Ana"), "Cartas. Pasa el dispositivo a Ana.");
+    }
+}
@@ -1,28 +1,83 @@
-use crate::views::game::types::GameState;
+use dioxus::prelude::*;
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::{CardType, GameEvent, GameScreen, GameState, SCHEMA_VERSION};
+use crate::views::game::utils::{assign_missing_colors, random_u64, read_player_count, sleep_ms, PlayerCountEntry, MAX_PLAYERS, MIN_PLAYERS};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 
 #[cfg(target_arch = "wasm32")]
 use once_cell::sync::Lazy;
-#[cfg(target_arch = "wasm32")]
-use std::sync::Mutex;
 
 // ============================================================================
 // Session Management & Persistence Functions
 // ============================================================================
 
-/// Generate a unique session ID
+/// localStorage key prefix for saved games; the session code follows it
+#[cfg(target_arch = "wasm32")]
+const GAME_KEY_PREFIX: &str = "ultimate_imposter_game_";
+
+/// Letters and digits that can't be mistaken for each other when read aloud or
+/// copied by hand (no 0/O, 1/I/L)
+const SESSION_CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+/// Characters in a session code: 31^6 is close to 900 million codes
+pub const SESSION_CODE_LEN: usize = 6;
+
+/// Generate a short, readable session code such as "K7QX3M"
 pub fn generate_session_id() -> String {
-    use uuid::Uuid;
-    Uuid::new_v4().to_string()
+    (0..SESSION_CODE_LEN)
+        .map(|_| SESSION_CODE_ALPHABET[(random_u64() % SESSION_CODE_ALPHABET.len() as u64) as usize] as char)
+        .collect()
+}
+
+/// Tidy a typed session code. Short codes are stored in capitals, so a code typed
+/// in lower case still finds its save; older UUID sessions are left exactly as typed.
+pub fn normalize_session_code(code: &str) -> String {
+    let code = code.trim();
+    if code.len() == SESSION_CODE_LEN && code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        code.to_ascii_uppercase()
+    } else {
+        code.to_string()
+    }
+}
+
+/// Key written and removed again to find out whether localStorage takes writes
+#[cfg(target_arch = "wasm32")]
+const STORAGE_PROBE_KEY: &str = "ultimate_imposter_storage_probe";
+
+/// This page's localStorage, or None when the browser won't hand it over: no
+/// window, access throwing (site data blocked, some private modes) or no storage at all
+#[cfg(target_arch = "wasm32")]
+pub fn browser_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Whether saves can be kept in this browser. Checked once per page load by writing
+/// a probe key, since some private modes hand out a storage that rejects every write.
+/// Outside the browser there is no localStorage to miss.
+pub fn storage_available() -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        static AVAILABLE: Lazy<bool> = Lazy::new(|| {
+            let Some(storage) = browser_storage() else {
+                return false;
+            };
+            let writable = storage.set_item(STORAGE_PROBE_KEY, "1").is_ok();
+            let _ = storage.remove_item(STORAGE_PROBE_KEY);
+            writable
+        });
+        *AVAILABLE
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        true
+    }
 }
 
 /// Load session ID from localStorage
 pub fn load_session_id() -> Option<String> {
     #[cfg(target_arch = "wasm32")]
     {
-        use web_sys::window;
-        
-        let window = window()?;
-        let storage = window.local_storage().ok()??;
+        let storage = browser_storage()?;
         storage.get_item("ultimate_imposter_session_id").ok()?
     }
     #[cfg(not(target_arch = "wasm32"))]
@@ -31,16 +86,20 @@ pub fn load_session_id() -> Option<String> {
     }
 }
 
-/// Save session ID to localStorage
-pub fn save_session_id(_session_id: &str) {
+/// Save session ID to localStorage. Private games clear it instead, so a reload
+/// starts a new session rather than looking the private one up anywhere.
+pub fn save_session_id(_session_id: &str, _persistence_enabled: bool) {
     #[cfg(target_arch = "wasm32")]
     {
-        use web_sys::window;
-        
-        if let Some(window) = window() {
-            if let Ok(Some(storage)) = window.local_storage() {
-                let _ = storage.set_item("ultimate_imposter_session_id", _session_id);
-            }
+        if !storage_available() {
+            return;
+        }
+        if let Some(storage) = browser_storage() {
+            let _ = if _persistence_enabled {
+                storage.set_item("ultimate_imposter_session_id", _session_id)
+            } else {
+                storage.remove_item("ultimate_imposter_session_id")
+            };
         }
     }
 }
@@ -49,8 +108,6 @@ pub fn save_session_id(_session_id: &str) {
 pub fn load_game_state(session_id: &str) -> Option<GameState> {
     #[cfg(target_arch = "wasm32")]
     {
-        use web_sys::window;
-
         // Fast path: return in-memory cached state if matching session
         static LAST_STATE: Lazy<Mutex<Option<GameState>>> = Lazy::new(|| Mutex::new(None));
         if let Ok(cache) = LAST_STATE.lock() {
@@ -61,11 +118,10 @@ pub fn load_game_state(session_id: &str) -> Option<GameState> {
             }
         }
         
-        let window = window()?;
-        let storage = window.local_storage().ok()??;
-        let key = format!("ultimate_imposter_game_{}", session_id);
+        let storage = browser_storage()?;
+        let key = format!("{GAME_KEY_PREFIX}{session_id}");
         let json = storage.get_item(&key).ok()??;
-        serde_json::from_str(&json).ok()
+        parse_saved_game(&json)
     }
     #[cfg(not(target_arch = "wasm32"))]
     {
@@ -74,61 +130,925 @@ pub fn load_game_state(session_id: &str) -> Option<GameState> {
     }
 }
 
-/// Save game state to localStorage and optionally to server disk
-pub fn save_game_state(_state: &GameState) {
+/// What the setup screen's session picker shows for one saved game
+#[derive(Clone, PartialEq, Debug)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub player_names: Vec<String>,
+    pub round_number: i32,
+}
+
+impl SessionSummary {
+    pub fn of(state: &GameState) -> Self {
+        // A game still on setup has no seated players yet, only the typed names
+        let player_names = if state.players.is_empty() {
+            state.player_names.iter().filter(|name| !name.trim().is_empty()).cloned().collect()
+        } else {
+            state.players.iter().map(|player| player.name.clone()).collect()
+        };
+        Self { session_id: state.session_id.clone(), player_names, round_number: state.round_number }
+    }
+}
+
+/// Every game saved in this browser, in session code order
+pub fn list_local_sessions() -> Vec<SessionSummary> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(storage) = browser_storage() else {
+            return Vec::new();
+        };
+        let count = storage.length().unwrap_or(0);
+        let mut sessions: Vec<SessionSummary> = (0..count)
+            .filter_map(|i| storage.key(i).ok().flatten())
+            .filter(|key| key.starts_with(GAME_KEY_PREFIX))
+            .filter_map(|key| storage.get_item(&key).ok().flatten())
+            .filter_map(|json| parse_saved_game(&json))
+            .map(|state| SessionSummary::of(&state))
+            .collect();
+        sessions.sort_by(|a, b| a.session_id.cmp(&b.session_id));
+        sessions
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Vec::new()
+    }
+}
+
+/// Remove a saved game from this browser
+pub fn delete_local_session(session_id: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = browser_storage() {
+            let _ = storage.remove_item(&format!("{GAME_KEY_PREFIX}{session_id}"));
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = session_id;
+    }
+}
+
+/// Outcome of the most recent auto-save, shown in the session bar
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SaveStatus {
+    Saving,
+    Saved,
+    NotSaved,
+    Private,
+    Unavailable, // This browser can't keep saves at all; the game only lives in memory
+    Viewing, // Opened from a join link: shown here, but the host's device owns the save
+}
+
+impl SaveStatus {
+    pub fn label(self, lang: Lang) -> &'static str {
+        let t = ui_text(lang);
+        match self {
+            SaveStatus::Saving => t.saving,
+            SaveStatus::Saved => t.saved,
+            SaveStatus::NotSaved => t.not_saved,
+            SaveStatus::Private => t.private_not_saved,
+            SaveStatus::Unavailable => t.cannot_save,
+            SaveStatus::Viewing => t.viewing_only,
+        }
+    }
+}
+
+/// Whether auto-save may write `state` yet. `initialized` is only set once the load
+/// path has applied everything it restores; until then the signals can hold a mix
+/// of the saved game and the defaults, and writing that would overwrite the save.
+pub fn ready_to_autosave(initialized: bool, state: &GameState) -> bool {
+    initialized && !state.session_id.is_empty()
+}
+
+/// Run a load path that has to wait (on the server, say): apply whatever `load`
+/// finds, and only then call `finish`, which lets auto-save start
+pub async fn load_then_finish(
+    load: impl std::future::Future<Output = Option<GameState>>,
+    mut apply: impl FnMut(GameState),
+    finish: impl FnOnce(),
+) {
+    if let Some(state) = load.await {
+        apply(state);
+    }
+    finish();
+}
+
+/// Save game state to localStorage.
+/// Private games are never written, and any earlier save of the session is removed
+/// so a reload really starts fresh. A browser without usable storage gets
+/// `Unavailable` straight away rather than a failed write on every change.
+pub fn save_game_state(_state: &GameState) -> SaveStatus {
     // Save to browser localStorage
     #[cfg(target_arch = "wasm32")]
     {
-        use web_sys::window;
-        
         // Avoid redundant writes if state JSON hasn't changed (saves time on mobile unlock)
         static LAST_SAVED_JSON: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
         static LAST_STATE: Lazy<Mutex<Option<GameState>>> = Lazy::new(|| Mutex::new(None));
 
-        if let Some(window) = window() {
-            if let Ok(Some(storage)) = window.local_storage() {
-                if let Ok(json) = serde_json::to_string(_state) {
-                    let mut cache = LAST_SAVED_JSON.lock().unwrap_or_else(|e| e.into_inner());
-                    let is_new = cache.as_ref().map(|prev| prev != &json).unwrap_or(true);
-                    if is_new {
-                        let key = format!("ultimate_imposter_game_{}", _state.session_id);
-                        let _ = storage.set_item(&key, &json);
-                        *cache = Some(json);
-                        if let Ok(mut state_cache) = LAST_STATE.lock() {
-                            *state_cache = Some(_state.clone());
-                        }
-                    }
-                } else if let Ok(mut state_cache) = LAST_STATE.lock() {
-                    // Even if serialization failed, keep latest state in memory for fast resume
+        if !storage_available() {
+            return if _state.settings.persistence_enabled { SaveStatus::Unavailable } else { SaveStatus::Private };
+        }
+        if let Some(storage) = browser_storage() {
+            if !_state.settings.persistence_enabled {
+                let key = format!("{GAME_KEY_PREFIX}{}", _state.session_id);
+                let _ = storage.remove_item(&key);
+                *LAST_SAVED_JSON.lock().unwrap_or_else(|e| e.into_inner()) = None;
+                return SaveStatus::Private;
+            }
+            if let Ok(json) = serde_json::to_string(_state) {
+                let mut cache = LAST_SAVED_JSON.lock().unwrap_or_else(|e| e.into_inner());
+                let is_new = cache.as_ref().map(|prev| prev != &json).unwrap_or(true);
+                if !is_new {
+                    return SaveStatus::Saved;
+                }
+                let key = format!("{GAME_KEY_PREFIX}{}", _state.session_id);
+                if storage.set_item(&key, &json).is_err() {
+                    return SaveStatus::NotSaved;
+                }
+                *cache = Some(json);
+                if let Ok(mut state_cache) = LAST_STATE.lock() {
                     *state_cache = Some(_state.clone());
                 }
+                return SaveStatus::Saved;
+            } else if let Ok(mut state_cache) = LAST_STATE.lock() {
+                // Even if serialization failed, keep latest state in memory for fast resume
+                *state_cache = Some(_state.clone());
+            }
+        }
+        if _state.settings.persistence_enabled { SaveStatus::NotSaved } else { SaveStatus::Private }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if _state.settings.persistence_enabled { SaveStatus::NotSaved } else { SaveStatus::Private }
+    }
+}
+
+/// Parse a stored game, upgrading saves from older builds. A private game is never
+/// loaded, even if a copy was written before it went private.
+pub fn parse_saved_game(json: &str) -> Option<GameState> {
+    game_state_from_json(json).ok()
+        .filter(|state| state.settings.persistence_enabled)
+        .map(migrate_game_state)
+}
+
+/// Top-level fields of saves before v3 that now live in `Settings`
+const MOVED_TO_SETTINGS: [&str; 6] = [
+    "hide_imposter_identity", "moderator_mode", "sound_enabled", "theme", "accessibility", "display_mode",
+];
+
+/// Read a saved or exported game. Fields that moved within the layout are put in
+/// their new place first, since the typed parse would just drop them where they were.
+fn game_state_from_json(json: &str) -> serde_json::Result<GameState> {
+    let mut value: serde_json::Value = serde_json::from_str(json)?;
+    if let Some(saved) = value.as_object_mut() {
+        let version = saved.get("schema_version").and_then(serde_json::Value::as_u64).unwrap_or(1);
+        if version < 3 {
+            let moved: Vec<(String, serde_json::Value)> = MOVED_TO_SETTINGS.iter()
+                .filter_map(|&key| Some((key.to_string(), saved.remove(key)?)))
+                .collect();
+            let settings = saved.entry("settings").or_insert_with(|| serde_json::json!({}));
+            if let Some(settings) = settings.as_object_mut() {
+                settings.extend(moved);
+            }
+        }
+    }
+    serde_json::from_value(value)
+}
+
+/// Bring a state saved by an older build up to `SCHEMA_VERSION`.
+/// Fields added since are already filled with defaults during deserialization;
+/// this fixes up values whose meaning depends on the rest of the save.
+pub fn migrate_game_state(mut state: GameState) -> GameState {
+    if state.schema_version < 2 {
+        // v1 kept names only for the setup screen; keep them in step with the roster
+        if !state.players.is_empty() && state.player_names.len() != state.players.len() {
+            state.player_names = state.players.iter().map(|p| p.name.clone()).collect();
+            state.player_count_input = state.players.len().to_string();
+        }
+        if !state.players.is_empty() {
+            state.starting_player_index %= state.players.len();
+        }
+        state.round_number = state.round_number.max(1);
+    }
+    // A count field saved half-typed ("" or "abc") comes back as the seats it stood for
+    if read_player_count(&state.player_count_input) == PlayerCountEntry::Invalid {
+        let seats = if state.players.is_empty() { state.player_names.len() } else { state.players.len() };
+        state.player_count_input = seats.clamp(MIN_PLAYERS, MAX_PLAYERS).to_string();
+    }
+    assign_missing_colors(&mut state.players);
+    state.schema_version = SCHEMA_VERSION;
+    state
+}
+
+/// Serialize a game for the export file
+pub fn export_game_json(state: &GameState) -> String {
+    let mut state = state.clone();
+    state.schema_version = SCHEMA_VERSION;
+    serde_json::to_string_pretty(&state).unwrap_or_default()
+}
+
+/// Why an imported file couldn't be loaded
+#[derive(Clone, PartialEq, Debug)]
+pub enum ImportError {
+    Unreadable, // The file couldn't be read at all
+    NotAnExport(String), // Not game JSON; carries the parser's detail
+    NewerVersion,
+    NoGame,
+}
+
+impl ImportError {
+    pub fn message(&self, lang: Lang) -> String {
+        let t = ui_text(lang);
+        match self {
+            ImportError::Unreadable => t.read_file_failed.to_string(),
+            ImportError::NotAnExport(detail) => (t.import_not_an_export)(detail),
+            ImportError::NewerVersion => t.import_newer_version.to_string(),
+            ImportError::NoGame => t.import_no_game.to_string(),
+        }
+    }
+}
+
+/// Parse an imported export file, rejecting files this version can't read
+pub fn import_game_json(json: &str) -> Result<GameState, ImportError> {
+    let state = game_state_from_json(json)
+        .map_err(|e| ImportError::NotAnExport(e.to_string()))?;
+    if state.schema_version > SCHEMA_VERSION {
+        return Err(ImportError::NewerVersion);
+    }
+    let state = migrate_game_state(state);
+    if state.session_id.is_empty() || state.players.is_empty() {
+        return Err(ImportError::NoGame);
+    }
+    Ok(state)
+}
+
+/// Offer the current game as a downloadable JSON file
+pub fn download_game_export(state: &GameState) {
+    let json = export_game_json(state);
+    let filename = format!("imposter-game-{}.json", state.session_id);
+    // serde_json quoting doubles as safe JS string literals
+    let script = format!(
+        "const a = document.createElement('a');\
+         a.href = 'data:application/json;charset=utf-8,' + encodeURIComponent({});\
+         a.download = {};\
+         document.body.appendChild(a); a.click(); a.remove();",
+        serde_json::to_string(&json).unwrap_or_default(),
+        serde_json::to_string(&filename).unwrap_or_default(),
+    );
+    let _ = document::eval(&script);
+}
+
+/// Check that the dealt cards and imposter index form a complete, consistent round
+fn round_secrets_intact(state: &GameState) -> bool {
+    // A blind deal is only ever sealed while the cards go round, and needs a card per seat
+    if let Some(deal) = &state.sealed_deal {
+        return matches!(state.game_screen, GameScreen::CardView { .. }) && deal.civilian_cards.len() == state.players.len();
+    }
+    !state.cards.is_empty()
+        && state.cards.len() == state.players.len()
+        && state
+            .cards
+            .get(state.imposter_index)
+            .map(|card| card.card_type == CardType::Imposter)
+            .unwrap_or(false)
+        && state.cards.iter().filter(|card| card.card_type == CardType::Imposter).count() == 1
+}
+
+/// Check that player indices stored in the current screen point at real players
+fn screen_indices_valid(state: &GameState) -> bool {
+    let in_range = |index: &usize| *index < state.players.len();
+    match &state.game_screen {
+        GameScreen::Elimination { eliminated_index, .. } => in_range(eliminated_index),
+        GameScreen::SecretVoting { votes, candidates, .. } => votes.iter().chain(candidates).all(in_range),
+        GameScreen::VoteReveal { tally, .. } => tally.iter().all(|(index, _)| in_range(index)),
+        GameScreen::TieBreak { tied_indices, .. } => tied_indices.iter().all(in_range),
+        _ => true,
+    }
+}
+
+/// Prepare a loaded state for resuming.
+///
+/// Mid-round screens depend on the secret round data (cards and imposter).
+/// If that data is missing or inconsistent, the round's dealing is restarted
+/// instead of guessing: cards are cleared, eliminations from the broken round
+/// are undone and play returns to the first card reveal. Scores and the round
+/// number are never touched. An intact card view resumes at the same player,
+/// card face down.
+pub fn prepare_resume(mut state: GameState) -> GameState {
+    let needs_secrets = matches!(
+        state.game_screen,
+        GameScreen::CardView { .. }
+            | GameScreen::Voting
+            | GameScreen::SecretVoting { .. }
+            | GameScreen::VoteReveal { .. }
+            | GameScreen::TieBreak { .. }
+            | GameScreen::Elimination { .. }
+            | GameScreen::ImposterGuess { .. }
+            | GameScreen::RoundEnd { .. }
+    );
+    if !needs_secrets || (round_secrets_intact(&state) && screen_indices_valid(&state)) {
+        return state;
+    }
+
+    match state.game_screen {
+        // The round is already scored, so only the recap is lost
+        GameScreen::RoundEnd { .. } => {
+            state.game_screen = GameScreen::GameScore;
+        }
+        // Cards are dealt lazily on the first reveal, so an empty hand is expected here
+        GameScreen::CardView { .. } if state.cards.is_empty() => {}
+        _ => {
+            for player in state.players.iter_mut() {
+                player.is_eliminated = false;
             }
+            state.timer_remaining = None;
+            state.timer_paused = false;
+            state.history.clear();
+            state.hidden_catch = None;
+            state.skipped_players.clear();
+            state.game_screen = GameScreen::CardView { current_player_index: 0 };
         }
     }
+    state.cards.clear();
+    state.sealed_deal = None;
+    state.imposter_index = 0;
+    state.current_round_words = None;
+    state.dealt_pair = None;
+    state.twist_active = false;
+    state
 }
 
 // ============================================================================
 // Server Functions (for fullstack mode with disk persistence)
 // ============================================================================
 
-// Uncomment these when running in fullstack mode with server feature
-/*
-#[server(SaveGameToDisk)]
+// The bodies only run on the server; the client calls them over HTTP and falls
+// back to localStorage when no server is reachable.
+
+#[server]
 async fn save_game_to_disk(session_id: String, game_state: String) -> Result<(), ServerFnError> {
     crate::server::save_game_to_disk(&session_id, &game_state)
-        .map_err(|e| ServerFnError::ServerError(e))
+        .map_err(ServerFnError::new)
+}
+
+#[server]
+async fn delete_game_from_disk(session_id: String) -> Result<(), ServerFnError> {
+    crate::server::delete_saved_game(&session_id)
+        .map_err(ServerFnError::new)
 }
 
-#[server(LoadGameFromDisk)]
+#[server]
 async fn load_game_from_disk(session_id: String) -> Result<String, ServerFnError> {
-    crate::server::load_game_from_disk(&session_id)
-        .map_err(|e| ServerFnError::ServerError(e))
+    crate::server::load_game_from_disk(&session_id).map_err(RejoinError::into_server_error)
+}
+
+/// The game saved under a rejoin code, for picking it up on another device
+#[server]
+pub async fn resume_game(code: String) -> Result<GameState, ServerFnError> {
+    let json = crate::server::load_game_from_disk(&normalize_session_code(&code))
+        .map_err(RejoinError::into_server_error)?;
+    parse_saved_game(&json).ok_or_else(|| RejoinError::Unreadable.into_server_error())
+}
+
+#[server]
+async fn append_event(session_id: String, event: String) -> Result<(), ServerFnError> {
+    crate::server::append_event_to_disk(&session_id, &event)
+        .map_err(ServerFnError::new)
+}
+
+/// Add one screen change to the session's event file on the server; false if it can't be reached
+pub async fn save_event_to_server(event: &GameEvent) -> bool {
+    let Ok(json) = serde_json::to_string(event) else {
+        return false;
+    };
+    append_event(event.session_id.clone(), json).await.is_ok()
+}
+
+/// How long a server save waits for newer changes before it's sent
+const SERVER_SAVE_DEBOUNCE_MS: u32 = 400;
+
+/// A write the server hasn't been sent yet
+enum ServerWrite {
+    Save { session_id: String, json: String },
+    Delete { session_id: String },
+}
+
+/// The newest write not yet sent; a newer one replaces it, so only the latest state goes out
+static SERVER_WRITE_PENDING: Mutex<Option<ServerWrite>> = Mutex::new(None);
+/// Set while a writer is sending, so there's only ever one request in flight
+static SERVER_WRITER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Hand `write` to the server, replacing any write still waiting. The first call
+/// becomes the writer: it keeps sending the newest pending write until none is left,
+/// then answers whether the last one reached the server. Calls that only queued
+/// behind it answer None straight away; the writer reports for them.
+async fn send_to_server(write: ServerWrite) -> Option<bool> {
+    *SERVER_WRITE_PENDING.lock().unwrap_or_else(|e| e.into_inner()) = Some(write);
+    if SERVER_WRITER_RUNNING.swap(true, Ordering::SeqCst) {
+        return None;
+    }
+    let mut sent = true;
+    loop {
+        sleep_ms(SERVER_SAVE_DEBOUNCE_MS).await;
+        let next = SERVER_WRITE_PENDING.lock().unwrap_or_else(|e| e.into_inner()).take();
+        sent = match next {
+            Some(ServerWrite::Save { session_id, json }) => save_game_to_disk(session_id, json).await.is_ok(),
+            Some(ServerWrite::Delete { session_id }) => delete_game_from_disk(session_id).await.is_ok(),
+            None => break,
+        };
+    }
+    SERVER_WRITER_RUNNING.store(false, Ordering::SeqCst);
+    Some(sent)
+}
+
+/// Mirror the state to the server's save directory. Some(false) if the server can't
+/// be reached; None if this save was queued behind one already on its way (see `send_to_server`)
+pub async fn save_game_state_to_server(state: &GameState) -> Option<bool> {
+    let Ok(json) = serde_json::to_string(state) else {
+        return Some(false);
+    };
+    send_to_server(ServerWrite::Save { session_id: state.session_id.clone(), json }).await
+}
+
+/// Remove a session's server copy once it goes private, after any save still on its way,
+/// so a reload can't bring the game back
+pub async fn delete_game_state_from_server(session_id: &str) {
+    send_to_server(ServerWrite::Delete { session_id: session_id.to_string() }).await;
+}
+
+/// Load a session's state from the server's save directory
+pub async fn load_game_state_from_server(session_id: &str) -> Option<GameState> {
+    let json = load_game_from_disk(session_id.to_string()).await.ok()?;
+    parse_saved_game(&json)
 }
 
-#[server(ListSavedGames)]
-async fn list_saved_games() -> Result<Vec<String>, ServerFnError> {
-    crate::server::list_saved_games()
-        .map_err(|e| ServerFnError::ServerError(e))
+/// Why the server didn't hand back a saved game
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RejoinError {
+    Unknown, // No save under that code
+    Expired, // Saved, but last written more than `server::REJOIN_EXPIRY` ago
+    Unreadable, // A save is there but couldn't be read or parsed
+    Unreachable, // The server couldn't be asked
 }
-*/
 
+impl RejoinError {
+    /// HTTP status the server answers with, one per reason so the client can tell them apart
+    #[cfg(any(feature = "server", test))]
+    fn status(self) -> u16 {
+        match self {
+            RejoinError::Unknown => 404,
+            RejoinError::Expired => 410,
+            RejoinError::Unreadable => 422,
+            RejoinError::Unreachable => 500,
+        }
+    }
+
+    fn from_status(status: Option<u16>) -> RejoinError {
+        match status {
+            Some(404) => RejoinError::Unknown,
+            Some(410) => RejoinError::Expired,
+            Some(422) => RejoinError::Unreadable,
+            _ => RejoinError::Unreachable,
+        }
+    }
+
+    #[cfg(feature = "server")]
+    fn into_server_error(self) -> ServerFnError {
+        ServerFnError::ServerError { message: format!("{:?}", self), code: self.status(), details: None }
+    }
+}
+
+/// Fetch the game saved on the server under a rejoin code
+pub async fn rejoin_server_game(code: &str) -> Result<GameState, RejoinError> {
+    let code = normalize_session_code(code);
+    if code.is_empty() {
+        return Err(RejoinError::Unknown);
+    }
+    resume_game(code).await.map_err(|error| {
+        let status = match &error {
+            ServerFnError::ServerError { code, .. } => Some(*code),
+            ServerFnError::Request(request) => request.status_code(),
+            _ => None,
+        };
+        RejoinError::from_status(status)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::types::{A11yConfig, DisplayMode, GameCard, player, Player, SealedDeal, Settings, Theme};
+    use crate::views::game::utils::CUSTOM_PAIRS_KEY;
+
+    #[test]
+    fn rejoin_errors_survive_the_trip_from_the_server() {
+        for error in [RejoinError::Unknown, RejoinError::Expired, RejoinError::Unreadable] {
+            assert_eq!(RejoinError::from_status(Some(error.status())), error);
+        }
+        assert_eq!(RejoinError::from_status(None), RejoinError::Unreachable);
+    }
+
+    #[test]
+    fn session_summaries_name_the_players() {
+        let mut state = dealt_state(GameScreen::Voting);
+        state.round_number = 4;
+        let summary = SessionSummary::of(&state);
+        assert_eq!(summary.player_names.len(), 4);
+        assert_eq!(summary.round_number, 4);
+
+        // Back on setup before anyone is seated: the typed names, skipping blanks
+        let setup = GameState {
+            session_id: "K7QX3M".to_string(),
+            player_names: vec!["Ana".to_string(), " ".to_string(), "Ben".to_string()],
+            ..GameState::default()
+        };
+        assert_eq!(SessionSummary::of(&setup).player_names, vec!["Ana".to_string(), "Ben".to_string()]);
+    }
+
+    #[test]
+    fn autosave_waits_until_the_load_has_applied() {
+        use std::cell::{Cell, RefCell};
+        use std::future::Future;
+        use std::task::{Context, Poll, Waker};
+
+        let saved = prepare_resume(dealt_state(GameScreen::Voting));
+        // The page before the server answers: the session id is in, the rest is still the defaults
+        let signals = RefCell::new(GameState { session_id: saved.session_id.clone(), ..GameState::default() });
+        let initialized = Cell::new(false);
+        let saves = RefCell::new(Vec::<GameScreen>::new());
+        // What the auto-save effect does each time a signal changes
+        let autosave = || {
+            let state = signals.borrow().clone();
+            if ready_to_autosave(initialized.get(), &state) {
+                saves.borrow_mut().push(state.game_screen);
+            }
+        };
+
+        // A server that answers on the second poll
+        let mut answered = false;
+        let server = std::future::poll_fn(|_| {
+            if std::mem::replace(&mut answered, true) {
+                Poll::Ready(Some(saved.clone()))
+            } else {
+                Poll::Pending
+            }
+        });
+        let mut load = std::pin::pin!(load_then_finish(
+            server,
+            |state| {
+                *signals.borrow_mut() = state;
+                autosave();
+            },
+            || {
+                initialized.set(true);
+                autosave();
+            },
+        ));
+        let mut cx = Context::from_waker(Waker::noop());
+
+        assert!(load.as_mut().poll(&mut cx).is_pending());
+        autosave();
+        assert!(saves.borrow().is_empty(), "the defaults must not be written while the server is still asked");
+
+        assert!(load.as_mut().poll(&mut cx).is_ready());
+        assert_eq!(*saves.borrow(), vec![GameScreen::Voting], "only the restored game is written, once the load has finished");
+        assert!(!ready_to_autosave(true, &GameState::default()), "no session, nothing to save under");
+    }
+
+    #[test]
+    fn session_codes_are_short_and_unambiguous() {
+        let codes: Vec<String> = (0..200).map(|_| generate_session_id()).collect();
+        for code in &codes {
+            assert_eq!(code.len(), SESSION_CODE_LEN);
+            assert!(code.bytes().all(|b| SESSION_CODE_ALPHABET.contains(&b)), "unexpected character in {code}");
+        }
+        let distinct: std::collections::HashSet<&String> = codes.iter().collect();
+        assert_eq!(distinct.len(), codes.len());
+
+        assert_eq!(normalize_session_code(" k7qx3m "), "K7QX3M");
+        // Sessions from before short codes keep their exact id
+        let uuid = "3f2b9c1e-8a4d-4c2e-9b7a-1d5e6f708192";
+        assert_eq!(normalize_session_code(uuid), uuid);
+    }
+
+    fn dealt_state(screen: GameScreen) -> GameState {
+        let players = (0..4)
+            .map(|i| Player { score: i * 10, is_eliminated: i == 1, ..player(&format!("Player {}", i + 1)) })
+            .collect::<Vec<_>>();
+        let cards = (0..4)
+            .map(|i| GameCard {
+                card_type: if i == 2 { CardType::Imposter } else { CardType::Normal },
+                word: if i == 2 { "Tea".to_string() } else { "Coffee".to_string() },
+                role: None,
+                hint: None,
+            })
+            .collect();
+        GameState {
+            session_id: "test".to_string(),
+            game_screen: screen,
+            players,
+            player_count_input: "4".to_string(),
+            round_number: 3,
+            cards,
+            imposter_index: 2,
+            current_category: Some(("Food".to_string(), "🍕".to_string())),
+            selected_category_index: Some(0),
+            current_round_words: Some(("Coffee".to_string(), "Tea".to_string())),
+            ..Default::default()
+        }
+    }
+
+    fn mid_round_screens() -> Vec<GameScreen> {
+        vec![
+            GameScreen::CardView { current_player_index: 2 },
+            GameScreen::Voting,
+            GameScreen::Elimination { eliminated_index: 3, was_imposter: false },
+            GameScreen::RoundEnd { imposter_found: true, game_over: true },
+        ]
+    }
+
+    #[test]
+    fn intact_secrets_resume_unchanged() {
+        for screen in mid_round_screens() {
+            let state = dealt_state(screen.clone());
+            let resumed = prepare_resume(state.clone());
+            assert_eq!(resumed.game_screen, screen);
+            assert_eq!(resumed.cards, state.cards);
+            assert_eq!(resumed.imposter_index, 2);
+            assert_eq!(resumed.players, state.players);
+        }
+    }
+
+    #[test]
+    fn lost_secrets_restart_dealing_without_touching_scores() {
+        for screen in mid_round_screens() {
+            let mut state = dealt_state(screen.clone());
+            state.cards.clear();
+            let resumed = prepare_resume(state.clone());
+
+            let expected = match screen {
+                GameScreen::RoundEnd { .. } => GameScreen::GameScore,
+                GameScreen::CardView { .. } => screen.clone(),
+                _ => GameScreen::CardView { current_player_index: 0 },
+            };
+            assert_eq!(resumed.game_screen, expected);
+            assert!(resumed.cards.is_empty());
+            assert_eq!(resumed.round_number, state.round_number);
+            let scores: Vec<i32> = resumed.players.iter().map(|p| p.score).collect();
+            assert_eq!(scores, vec![0, 10, 20, 30]);
+        }
+    }
+
+    #[test]
+    fn tampered_imposter_index_restarts_round() {
+        let mut state = dealt_state(GameScreen::Voting);
+        state.imposter_index = 9;
+        state.skipped_players = vec![2];
+        let resumed = prepare_resume(state);
+        assert_eq!(resumed.game_screen, GameScreen::CardView { current_player_index: 0 });
+        assert!(resumed.cards.is_empty());
+        assert!(resumed.players.iter().all(|p| !p.is_eliminated));
+        assert!(resumed.skipped_players.is_empty());
+    }
+
+    #[test]
+    fn partially_dealt_card_view_is_redealt() {
+        let mut state = dealt_state(GameScreen::CardView { current_player_index: 1 });
+        state.cards.truncate(2);
+        let resumed = prepare_resume(state);
+        assert_eq!(resumed.game_screen, GameScreen::CardView { current_player_index: 0 });
+        assert!(resumed.cards.is_empty());
+    }
+
+    #[test]
+    fn a_sealed_deal_resumes_mid_reveal_but_nowhere_else() {
+        let mut state = dealt_state(GameScreen::CardView { current_player_index: 2 });
+        let cards = std::mem::take(&mut state.cards);
+        state.sealed_deal = Some(SealedDeal {
+            seed: 7,
+            commitment: String::new(),
+            imposter_card: cards[2].clone(),
+            civilian_cards: vec![cards[0].clone(); 4],
+            weights: Vec::new(),
+        });
+        let resumed = prepare_resume(state.clone());
+        assert_eq!(resumed.game_screen, GameScreen::CardView { current_player_index: 2 });
+        assert!(resumed.sealed_deal.is_some());
+
+        state.game_screen = GameScreen::Voting;
+        let resumed = prepare_resume(state);
+        assert_eq!(resumed.game_screen, GameScreen::CardView { current_player_index: 0 });
+        assert!(resumed.sealed_deal.is_none(), "a deal is always opened before the vote");
+    }
+
+    #[test]
+    fn screens_without_secrets_are_untouched() {
+        for screen in [GameScreen::Setup, GameScreen::CategorySelection, GameScreen::GameScore] {
+            let mut state = dealt_state(screen.clone());
+            state.cards.clear();
+            assert_eq!(prepare_resume(state).game_screen, screen);
+        }
+    }
+
+    #[test]
+    fn saved_json_round_trips_every_field() {
+        let mut state = dealt_state(GameScreen::SecretVoting {
+            current_voter_index: 1,
+            votes: vec![2],
+            candidates: vec![0, 2],
+            revotes: 1,
+        });
+        state.settings.custom_pairs = vec![("Sun".to_string(), "Moon".to_string())];
+        state.settings.timer_seconds = Some(120);
+        state.settings.secret_voting = true;
+        state.grace_round_pending = true;
+        state.timer_remaining = Some(42);
+        state.skipped_players = vec![3];
+        state.cards[0].role = Some("Chef".to_string());
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: GameState = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    }
+
+    #[test]
+    fn exports_import_back() {
+        let state = dealt_state(GameScreen::GameScore);
+        let imported = import_game_json(&export_game_json(&state)).unwrap();
+        assert_eq!(imported.schema_version, SCHEMA_VERSION);
+        assert_eq!(imported.session_id, state.session_id);
+        assert_eq!(imported.players.len(), 4);
+    }
+
+    #[test]
+    fn bad_imports_are_rejected() {
+        let not_json = import_game_json("not json").unwrap_err();
+        assert!(matches!(not_json, ImportError::NotAnExport(_)));
+        assert!(not_json.message(Lang::Spanish).starts_with("Ese archivo no es"));
+        assert!(import_game_json("{}").is_err());
+
+        let mut future = dealt_state(GameScreen::GameScore);
+        future.schema_version = SCHEMA_VERSION + 1;
+        let json = serde_json::to_string(&future).unwrap();
+        assert_eq!(import_game_json(&json).unwrap_err(), ImportError::NewerVersion);
+    }
+
+    #[test]
+    fn out_of_range_eviction_restarts_round() {
+        let state = dealt_state(GameScreen::Elimination { eliminated_index: 7, was_imposter: false });
+        let resumed = prepare_resume(state);
+        assert_eq!(resumed.game_screen, GameScreen::CardView { current_player_index: 0 });
+        assert!(resumed.cards.is_empty());
+    }
+
+    #[test]
+    fn imposter_beyond_shrunken_roster_skips_recap() {
+        let mut state = dealt_state(GameScreen::RoundEnd { imposter_found: true, game_over: true });
+        state.players.truncate(2);
+        let resumed = prepare_resume(state);
+        assert_eq!(resumed.game_screen, GameScreen::GameScore);
+    }
+
+    /// A mid-game save exactly as the first release wrote it
+    const V1_VOTING_SAVE: &str = r#"{
+        "session_id": "old-session",
+        "game_screen": "Voting",
+        "players": [
+            {"name": "Ana", "score": 10, "is_eliminated": false},
+            {"name": "Ben", "score": 0, "is_eliminated": true},
+            {"name": "Cy", "score": 20, "is_eliminated": false},
+            {"name": "Dee", "score": 0, "is_eliminated": false}
+        ],
+        "player_count_input": "4",
+        "player_names": ["Ana", "Ben", "Cy", "Dee"],
+        "round_number": 3,
+        "cards": [
+            {"card_type": "Normal", "word": "Coffee"},
+            {"card_type": "Normal", "word": "Coffee"},
+            {"card_type": "Imposter", "word": "Tea"},
+            {"card_type": "Normal", "word": "Coffee"}
+        ],
+        "imposter_index": 2,
+        "current_category": ["Food", "🍕"],
+        "selected_category_index": 0,
+        "hide_imposter_identity": false,
+        "current_round_words": ["Coffee", "Tea"],
+        "starting_player_index": 6
+    }"#;
+
+    #[test]
+    fn v1_save_loads_and_resumes_mid_game() {
+        let state = parse_saved_game(V1_VOTING_SAVE).expect("v1 save should load");
+        assert_eq!(state.schema_version, SCHEMA_VERSION);
+        assert_eq!(state.settings, Settings::default());
+        assert_eq!(state.starting_player_index, 2);
+        assert!(state.cards.iter().all(|c| c.role.is_none()));
+
+        let resumed = prepare_resume(state);
+        assert_eq!(resumed.game_screen, GameScreen::Voting);
+        assert_eq!(resumed.cards.len(), 4);
+        assert_eq!(resumed.players[2].score, 20);
+        assert!(resumed.players.iter().all(|p| !p.color.is_empty()));
+        assert!(resumed.players[1].is_eliminated);
+    }
+
+    #[test]
+    fn v1_save_missing_fields_still_loads() {
+        let json = r#"{"session_id": "old", "game_screen": "GameScore",
+            "players": [{"name": "Ana", "score": 5, "is_eliminated": false}]}"#;
+        let state = parse_saved_game(json).expect("partial save should load");
+        assert_eq!(state.game_screen, GameScreen::GameScore);
+        assert_eq!(state.player_names, vec!["Ana".to_string()]);
+        assert_eq!(state.round_number, 1);
+        assert!(state.settings.sound_enabled, "older saves should keep sound on");
+        assert_eq!(state.settings.difficulty, None, "older saves should deal from every pair");
+        assert_eq!(state.settings.reveal_timeout_secs, None, "older saves should wait for Next Player");
+        assert_eq!(state.settings.pass_delay_secs, None, "older saves should unlock reveals right away");
+        assert!(state.settings.reveal_imposter_at_round_end, "older saves should keep the round-end reveal");
+        assert!(!state.settings.record_events && state.event_log.is_empty(), "older saves should not record events");
+        assert_eq!(state.settings.accessibility, A11yConfig::default(), "older saves should keep the standard display");
+        assert_eq!(state.settings.display_mode, DisplayMode::Phone, "older saves should keep the phone layout");
+        assert!(!state.settings.show_tips, "older saves should reveal cards without tips");
+        assert!(state.settings.skip_flagged_pairs && state.dealt_pair.is_none(), "older saves should skip flagged pairs");
+        assert!(!state.settings.single_vote_challenge, "older saves should allow more than one vote");
+        assert!(state.settings.hide_live_tally, "older saves should keep ballot counts hidden until the end");
+        assert!(!state.settings.twist_round && !state.twist_active, "older saves should deal the imposter the pair's other word");
+        assert!(!state.settings.comeback_enabled, "older saves should end the round when the imposter is caught");
+        assert!(state.settings.reveal_role_on_eviction, "older saves should reveal each evicted player's role");
+        assert_eq!(state.hidden_catch, None);
+        assert!(!state.settings.moderator_mode, "older saves have no moderator");
+        assert!(!state.settings.blind_deal, "older saves deal in the open");
+        assert_eq!(state.sealed_deal, None);
+        assert!(!state.settings.fair_rotation && state.times_imposter.is_empty(), "older saves should pick imposters at random");
+    }
+
+    #[test]
+    fn v2_table_options_move_into_settings() {
+        let json = r#"{"schema_version": 2, "session_id": "v2", "game_screen": "GameScore",
+            "players": [{"name": "Ana", "score": 5, "is_eliminated": false}],
+            "settings": {"grace_round": true},
+            "hide_imposter_identity": true, "moderator_mode": true, "sound_enabled": false,
+            "theme": "Dark", "accessibility": {"large_text": true}, "display_mode": "Tv"}"#;
+        for state in [parse_saved_game(json).unwrap(), import_game_json(json).unwrap()] {
+            assert_eq!(state.schema_version, SCHEMA_VERSION);
+            assert!(state.settings.grace_round, "settings already in the save stay");
+            assert!(state.settings.hide_imposter_identity && state.settings.moderator_mode);
+            assert!(!state.settings.sound_enabled);
+            assert_eq!(state.settings.theme, Theme::Dark);
+            assert!(state.settings.accessibility.large_text);
+            assert_eq!(state.settings.display_mode, DisplayMode::Tv);
+        }
+
+        // A v1 save has no settings object to move them into
+        let v1 = r#"{"session_id": "v1", "game_screen": "GameScore",
+            "players": [{"name": "Ana", "score": 5, "is_eliminated": false}], "hide_imposter_identity": true}"#;
+        assert!(parse_saved_game(v1).unwrap().settings.hide_imposter_identity);
+    }
+
+    #[test]
+    fn recent_pairs_without_their_list_are_dropped() {
+        let json = r#"{"session_id": "old", "game_screen": "GameScore",
+            "players": [{"name": "Ana", "score": 5, "is_eliminated": false}], "recent_pairs": [3, 1]}"#;
+        let state = parse_saved_game(json).expect("bare pair positions shouldn't break the save");
+        assert!(state.recent_pairs.is_empty());
+
+        let mut state = dealt_state(GameScreen::GameScore);
+        state.recent_pairs = vec![(2, 3), (CUSTOM_PAIRS_KEY, 0)];
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(parse_saved_game(&json).unwrap().recent_pairs, state.recent_pairs);
+    }
+
+    #[test]
+    fn a_half_typed_player_count_loads_as_the_seats() {
+        let mut state = dealt_state(GameScreen::GameScore);
+        state.schema_version = SCHEMA_VERSION;
+        state.player_count_input = "abc".to_string();
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(parse_saved_game(&json).unwrap().player_count_input, "4");
+
+        // Out of range is still a number the setup screen can explain, so it's left alone
+        state.player_count_input = "25".to_string();
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(parse_saved_game(&json).unwrap().player_count_input, "25");
+    }
+
+    #[test]
+    fn private_games_are_never_saved() {
+        let mut state = dealt_state(GameScreen::Voting);
+        state.settings.persistence_enabled = false;
+        assert_eq!(save_game_state(&state), SaveStatus::Private);
+    }
+
+    #[test]
+    fn a_private_game_never_loads_back() {
+        // A copy written before the game went private (say, on the server) stays unloaded
+        let mut state = dealt_state(GameScreen::Voting);
+        state.settings.persistence_enabled = false;
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(parse_saved_game(&json).is_none());
+
+        state.settings.persistence_enabled = true;
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(parse_saved_game(&json).is_some());
+    }
+}
@@ -0,0 +1,338 @@
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::{GameScreen, GameState, Player, RoundRecord, Settings};
+use crate::views::game::utils::{normalize_name, MAX_PLAYERS, MIN_PLAYERS};
+use base64::{engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD}, Engine as _};
+use qrcode::{render::svg, QrCode};
+use serde::{Deserialize, Serialize};
+
+const SETUP_FRAGMENT_PREFIX: &str = "setup=";
+const MAX_NAME_LEN: usize = 32;
+const SESSION_QUERY_KEY: &str = "session";
+const MAX_SESSION_ID_LEN: usize = 64;
+
+/// A shareable game setup (not an in-progress game)
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SetupConfig {
+    pub player_names: Vec<String>,
+    #[serde(default)]
+    pub settings: Settings,
+}
+
+/// Encode a setup into `base_url#setup=<base64>`
+pub fn encode_setup_to_url(config: &SetupConfig, base_url: &str) -> String {
+    let json = serde_json::to_string(config).unwrap_or_default();
+    let base = base_url.split('#').next().unwrap_or(base_url);
+    format!("{}#{}{}", base, SETUP_FRAGMENT_PREFIX, URL_SAFE_NO_PAD.encode(json))
+}
+
+/// Decode a setup from a full URL or just its fragment.
+/// Returns `None` for anything that isn't a well-formed setup link.
+pub fn decode_setup_from_url(url: &str) -> Option<SetupConfig> {
+    let fragment = url.rsplit_once('#').map(|(_, f)| f).unwrap_or(url);
+    let encoded = fragment.strip_prefix(SETUP_FRAGMENT_PREFIX)?;
+    let bytes = URL_SAFE_NO_PAD.decode(encoded.trim()).ok()?;
+    let config: SetupConfig = serde_json::from_slice(&bytes).ok()?;
+    Some(sanitize_setup(config))
+}
+
+/// Clean up decoded input: strip control characters, cap name length and keep
+/// the roster within the supported player range
+fn sanitize_setup(mut config: SetupConfig) -> SetupConfig {
+    config.player_names = config
+        .player_names
+        .into_iter()
+        .map(|name| {
+            normalize_name(&name)
+                .chars()
+                .take(MAX_NAME_LEN)
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .take(MAX_PLAYERS)
+        .collect();
+    while config.player_names.len() < MIN_PLAYERS {
+        config.player_names.push(String::new());
+    }
+    config
+}
+
+/// Take a shared setup's game settings while keeping this device's own sound,
+/// theme and display preferences
+pub fn shared_settings(shared: Settings, local: &Settings) -> Settings {
+    Settings {
+        sound_enabled: local.sound_enabled,
+        theme: local.theme,
+        accessibility: local.accessibility,
+        display_mode: local.display_mode,
+        ..shared
+    }
+}
+
+/// What a device opening a join link is given: the game's scoreboard, with the
+/// cards, the imposter and the round's words left out so it can't give the round away
+pub fn spectator_view(state: GameState) -> GameState {
+    GameState {
+        game_screen: GameScreen::GameScore,
+        cards: Vec::new(),
+        imposter_index: 0,
+        current_round_words: None,
+        dealt_pair: None,
+        hidden_catch: None,
+        sealed_deal: None,
+        opened_deal: None,
+        ..state
+    }
+}
+
+/// Link that opens this session on another device: `base_url?session=<id>`
+pub fn encode_join_link(session_id: &str, base_url: &str) -> String {
+    let base = base_url.split(['?', '#']).next().unwrap_or(base_url);
+    format!("{}?{}={}", base, SESSION_QUERY_KEY, session_id)
+}
+
+/// A join link as a scannable QR code, ready for an `img` src (an SVG data URL);
+/// None if the link is too long to encode
+pub fn join_link_qr(link: &str) -> Option<String> {
+    let svg = QrCode::new(link.as_bytes()).ok()?
+        .render::<svg::Color>()
+        .min_dimensions(200, 200)
+        .build();
+    Some(format!("data:image/svg+xml;base64,{}", STANDARD.encode(svg)))
+}
+
+/// The session id from a join link's query string (with or without the leading `?`).
+/// Anything that doesn't look like a session code is ignored.
+pub fn decode_join_query(query: &str) -> Option<String> {
+    let id = query
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(SESSION_QUERY_KEY)?.strip_prefix('='))?;
+    let well_formed = !id.is_empty()
+        && id.len() <= MAX_SESSION_ID_LEN
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    well_formed.then(|| id.to_string())
+}
+
+/// Read the session to join from the page's query string, if a join link was opened
+pub fn session_from_page_url() -> Option<String> {
+    decode_join_query(&page_query()?)
+}
+
+/// The `?...` query string of the current page URL
+fn page_query() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()?.location().search().ok()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Plain-text recap of the game for pasting into a chat: how the last round went,
+/// who leads, and everyone's score
+pub fn results_summary(players: &[Player], round_log: &[RoundRecord], lang: Lang) -> String {
+    let t = ui_text(lang);
+    let mut lines = Vec::new();
+
+    let mut headline = String::from("🎮 Ultimate Imposter:");
+    if let Some(last) = round_log.last() {
+        let outcome = if last.civilians_won {
+            (t.results_imposter_caught)(&last.imposter_name, last.round_number)
+        } else {
+            (t.results_imposter_escaped)(&last.imposter_name, last.round_number)
+        };
+        headline.push(' ');
+        headline.push_str(&outcome);
+    }
+    if let Some(top) = players.iter().map(|p| p.score).max() {
+        let leaders: Vec<&str> = players.iter().filter(|p| p.score == top).map(|p| p.name.as_str()).collect();
+        headline.push(' ');
+        headline.push_str(&(t.results_winner)(&leaders.join(" & "), top));
+    }
+    lines.push(headline);
+
+    let mut ranked: Vec<&Player> = players.iter().collect();
+    ranked.sort_by_key(|p| std::cmp::Reverse(p.score));
+    let scores: Vec<String> = ranked.iter().map(|p| format!("{} {}", p.name, p.score)).collect();
+    lines.push(format!("{}: {}", t.results_final_scores, scores.join(", ")));
+    lines.join("\n")
+}
+
+/// Put text on the clipboard; false when the browser refuses (no permission, insecure page)
+pub async fn copy_to_clipboard(text: &str) -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(window) = web_sys::window() else {
+            return false;
+        };
+        let promise = window.navigator().clipboard().write_text(text);
+        wasm_bindgen_futures::JsFuture::from(promise).await.is_ok()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = text;
+        false
+    }
+}
+
+/// The current page URL without its fragment, used as the base for share links
+pub fn current_page_url() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let location = web_sys::window()?.location();
+        let origin = location.origin().ok()?;
+        let path = location.pathname().ok()?;
+        Some(format!("{}{}", origin, path))
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Read a setup from the page's URL fragment, if one was shared
+pub fn setup_from_page_url() -> Option<SetupConfig> {
+    decode_setup_from_url(&page_fragment()?)
+}
+
+/// The `#...` fragment of the current page URL
+fn page_fragment() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()?.location().hash().ok()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::types::{named_players, player, CardType, DisplayMode, GameCard, Theme};
+
+    fn config(names: &[&str]) -> SetupConfig {
+        SetupConfig {
+            player_names: names.iter().map(|n| n.to_string()).collect(),
+            settings: Settings::default(),
+        }
+    }
+
+    #[test]
+    fn setup_round_trips_through_url() {
+        let mut original = config(&["Alex", "Dana", "Sam", "Kim"]);
+        original.settings.grace_round = true;
+        let url = encode_setup_to_url(&original, "https://example.com/#old");
+        assert!(url.starts_with("https://example.com/#setup="));
+        assert_eq!(decode_setup_from_url(&url), Some(original));
+    }
+
+    #[test]
+    fn shared_setup_keeps_local_device_preferences() {
+        let mut shared = Settings { grace_round: true, sound_enabled: false, display_mode: DisplayMode::Tv, ..Settings::default() };
+        shared.accessibility.large_text = true;
+        let local = Settings { theme: Theme::Dark, ..Settings::default() };
+        let merged = shared_settings(shared, &local);
+        assert!(merged.grace_round, "game settings come from the link");
+        assert!(merged.sound_enabled);
+        assert_eq!(merged.theme, Theme::Dark);
+        assert_eq!(merged.accessibility, local.accessibility);
+        assert_eq!(merged.display_mode, DisplayMode::Phone);
+    }
+
+    #[test]
+    fn spectator_view_leaves_out_the_round_secrets() {
+        let state = GameState {
+            game_screen: GameScreen::Voting,
+            players: named_players(&["Alex", "Dana", "Sam"]),
+            cards: vec![GameCard { card_type: CardType::Normal, word: "Cat".to_string(), role: None, hint: None }; 3],
+            imposter_index: 2,
+            current_round_words: Some(("Cat".to_string(), "Lynx".to_string())),
+            dealt_pair: Some(("Cat".to_string(), "Lynx".to_string())),
+            hidden_catch: Some(1),
+            ..GameState::default()
+        };
+        let view = spectator_view(state.clone());
+        assert_eq!(view.game_screen, GameScreen::GameScore);
+        assert!(view.cards.is_empty());
+        assert_eq!(view.imposter_index, 0);
+        assert_eq!((view.current_round_words, view.dealt_pair, view.hidden_catch), (None, None, None));
+        assert_eq!(view.players, state.players, "the scores still show");
+    }
+
+    #[test]
+    fn rejects_malformed_fragments() {
+        assert_eq!(decode_setup_from_url("https://example.com/"), None);
+        assert_eq!(decode_setup_from_url("#setup=not*base64"), None);
+        let not_json = URL_SAFE_NO_PAD.encode("hello");
+        assert_eq!(decode_setup_from_url(&format!("#setup={}", not_json)), None);
+    }
+
+    #[test]
+    fn sanitizes_decoded_names() {
+        let long_name = "x".repeat(100);
+        let mut names: Vec<&str> = vec![" Al\u{7}ex ", &long_name];
+        names.extend(std::iter::repeat_n("P", MAX_PLAYERS + 5));
+        let url = encode_setup_to_url(&config(&names), "https://example.com/");
+        let decoded = decode_setup_from_url(&url).unwrap();
+        assert_eq!(decoded.player_names.len(), MAX_PLAYERS);
+        assert_eq!(decoded.player_names[0], "Alex");
+        assert_eq!(decoded.player_names[1].chars().count(), MAX_NAME_LEN);
+
+        let short = encode_setup_to_url(&config(&["Solo"]), "https://example.com/");
+        assert_eq!(decode_setup_from_url(&short).unwrap().player_names, vec!["Solo", "", ""]);
+    }
+
+    #[test]
+    fn results_summary_names_the_imposter_and_winner() {
+        let players: Vec<Player> = [("Alex", 40), ("Dana", 20), ("Sam", 30)].iter()
+            .map(|&(name, score)| Player { score, ..player(name) })
+            .collect();
+        let round_log = vec![RoundRecord {
+            round_number: 2,
+            civilian_word: "Cat".to_string(),
+            imposter_word: "Lynx".to_string(),
+            imposter_name: "Dana".to_string(),
+            civilians_won: true,
+            seats: Vec::new(),
+            evicted: Vec::new(),
+        }];
+        let summary = results_summary(&players, &round_log, Lang::English);
+        assert_eq!(
+            summary,
+            "🎮 Ultimate Imposter: Imposter was Dana, caught in round 2. Alex won with 40 pts.\nFinal scores: Alex 40, Sam 30, Dana 20"
+        );
+
+        // Ties share the win; no finished round means no imposter line
+        let tied: Vec<Player> = players.iter().map(|p| Player { score: 10, ..p.clone() }).collect();
+        let summary = results_summary(&tied, &[], Lang::English);
+        assert!(summary.starts_with("🎮 Ultimate Imposter: Alex & Dana & Sam won with 10 pts."), "{summary}");
+    }
+
+    #[test]
+    fn join_link_round_trips_the_session() {
+        let id = "3f2b9c1e-7a4d-4e8f-9b0a-1c2d3e4f5a6b";
+        let link = encode_join_link(id, "https://example.com/?old=1#setup=abc");
+        assert_eq!(link, format!("https://example.com/?session={id}"));
+        let query = link.split_once('?').unwrap().1;
+        assert_eq!(decode_join_query(query).as_deref(), Some(id));
+        assert_eq!(decode_join_query(&format!("?lang=es&session={id}")).as_deref(), Some(id));
+
+        let qr = join_link_qr(&link).expect("a join link fits in a QR code");
+        let svg = STANDARD.decode(qr.strip_prefix("data:image/svg+xml;base64,").unwrap()).unwrap();
+        assert!(String::from_utf8(svg).unwrap().contains("<svg"));
+    }
+
+    #[test]
+    fn ignores_malformed_join_queries() {
+        assert_eq!(decode_join_query(""), None);
+        assert_eq!(decode_join_query("?session="), None);
+        assert_eq!(decode_join_query("?sessions=abc"), None);
+        assert_eq!(decode_join_query("?session=../../etc/passwd"), None);
+        assert_eq!(decode_join_query(&format!("?session={}", "a".repeat(65))), None);
+    }
+}
@@ -0,0 +1,46 @@
+use dioxus::prelude::*;
+
+const REVEAL_SOUND: Asset = asset!("/assets/sounds/reveal.wav");
+const ELIMINATION_SOUND: Asset = asset!("/assets/sounds/elimination.wav");
+const WIN_SOUND: Asset = asset!("/assets/sounds/win.wav");
+const LOSE_SOUND: Asset = asset!("/assets/sounds/lose.wav");
+
+/// Short audio cues for the big moments of a round
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Sound {
+    Reveal,
+    Elimination,
+    Win,
+    Lose,
+}
+
+impl Sound {
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    fn asset(self) -> Asset {
+        match self {
+            Sound::Reveal => REVEAL_SOUND,
+            Sound::Elimination => ELIMINATION_SOUND,
+            Sound::Win => WIN_SOUND,
+            Sound::Lose => LOSE_SOUND,
+        }
+    }
+}
+
+/// Play a cue unless the group has muted the game.
+/// Only the browser build makes noise; elsewhere this does nothing.
+pub fn play(sound: Sound, enabled: bool) {
+    if !enabled {
+        return;
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        // Autoplay rules may reject playback before the first tap; a missed cue is harmless
+        if let Ok(audio) = web_sys::HtmlAudioElement::new_with_src(&sound.asset().to_string()) {
+            let _ = audio.play();
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = sound;
+    }
+}
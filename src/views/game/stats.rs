@@ -0,0 +1,108 @@
+use crate::views::game::types::Player;
+use crate::views::game::utils::normalize_name;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const STATS_STORAGE_KEY: &str = "ultimate_imposter_player_stats";
+
+/// Lifetime record for one player, kept across games and sessions
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerStats {
+    pub name: String, // Name as last entered, for display
+    pub games_played: u32,
+    pub times_imposter: u32,
+    pub imposter_wins: u32,
+    pub civilian_wins: u32,
+}
+
+/// All known players' stats, keyed by normalized name
+pub type StatsBook = BTreeMap<String, PlayerStats>;
+
+/// Key used to match the same person across games ("Alex " and "alex" are one player)
+pub fn stats_key(name: &str) -> String {
+    normalize_name(name).to_lowercase()
+}
+
+/// Add one finished game to everyone's record
+pub fn record_game(book: &mut StatsBook, players: &[Player], imposter_index: usize, imposter_won: bool) {
+    for (i, player) in players.iter().enumerate() {
+        let key = stats_key(&player.name);
+        if key.is_empty() {
+            continue;
+        }
+        let entry = book.entry(key).or_default();
+        entry.name = normalize_name(&player.name);
+        entry.games_played += 1;
+        if i == imposter_index {
+            entry.times_imposter += 1;
+            if imposter_won {
+                entry.imposter_wins += 1;
+            }
+        } else if !imposter_won {
+            entry.civilian_wins += 1;
+        }
+    }
+}
+
+/// Load the stats book from localStorage (empty when none is stored)
+pub fn load_stats() -> StatsBook {
+    #[cfg(target_arch = "wasm32")]
+    {
+        crate::views::game::persistence::browser_storage()
+            .and_then(|storage| storage.get_item(STATS_STORAGE_KEY).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = STATS_STORAGE_KEY;
+        StatsBook::new()
+    }
+}
+
+/// Save the stats book to localStorage
+pub fn save_stats(_book: &StatsBook) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = crate::views::game::persistence::browser_storage() {
+            if let Ok(json) = serde_json::to_string(_book) {
+                let _ = storage.set_item(STATS_STORAGE_KEY, &json);
+            }
+        }
+    }
+}
+
+/// Record a finished game in the stored stats
+pub fn record_finished_game(players: &[Player], imposter_index: usize, imposter_won: bool) {
+    let mut book = load_stats();
+    record_game(&mut book, players, imposter_index, imposter_won);
+    save_stats(&book);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::types::named_players;
+
+    #[test]
+    fn games_accumulate_per_normalized_name() {
+        let mut book = StatsBook::new();
+        record_game(&mut book, &named_players(&["Alex", "Sam", "Kim"]), 0, true);
+        record_game(&mut book, &named_players(&["alex ", "Sam", "Kim"]), 1, false);
+
+        let alex = &book["alex"];
+        assert_eq!(alex.name, "alex");
+        assert_eq!(alex.games_played, 2);
+        assert_eq!(alex.times_imposter, 1);
+        assert_eq!(alex.imposter_wins, 1);
+        assert_eq!(alex.civilian_wins, 1);
+
+        let sam = &book["sam"];
+        assert_eq!(sam.times_imposter, 1);
+        assert_eq!(sam.imposter_wins, 0);
+        assert_eq!(sam.civilian_wins, 0);
+
+        assert_eq!(book["kim"].civilian_wins, 1);
+    }
+}
@@ -1,10 +1,35 @@
 use serde::{Deserialize, Serialize};
+use crate::views::game::i18n::Lang;
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Player {
     pub name: String,
     pub score: i32,
     pub is_eliminated: bool,
+    #[serde(default)]
+    pub color: String, // Hex accent shown wherever the player appears
+    #[serde(default)]
+    pub last_round_delta: i32, // Points won in the latest round, shown beside the score; cleared when the next round starts
+}
+
+/// A player for tests: no points yet, still in the round, no accent colour
+#[cfg(test)]
+pub fn player(name: &str) -> Player {
+    Player { name: name.to_string(), score: 0, is_eliminated: false, color: String::new(), last_round_delta: 0 }
+}
+
+/// A table of `count` test players, P0, P1, ..., each in their seat's colour
+#[cfg(test)]
+pub fn players(count: usize) -> Vec<Player> {
+    (0..count)
+        .map(|i| Player { color: crate::views::game::utils::player_color(i), ..player(&format!("P{i}")) })
+        .collect()
+}
+
+/// Test players with these names, as `player` makes them
+#[cfg(test)]
+pub fn named_players(names: &[&str]) -> Vec<Player> {
+    names.iter().map(|name| player(name)).collect()
 }
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
@@ -17,22 +42,157 @@ pub enum CardType {
 pub struct GameCard {
     pub card_type: CardType,
     pub word: String,
+    #[serde(default)]
+    pub role: Option<String>, // Location mode: the civilian's role at the location
+    #[serde(default)]
+    pub hint: Option<String>, // Category-hint mode: what the imposter is told instead of a word
+}
+
+/// A blind deal still being revealed. Nobody's seat is fixed as the imposter yet:
+/// each revealed card is worked out from `seed`, and the round is only written into
+/// `GameState::cards` once everyone has looked.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SealedDeal {
+    pub seed: u64,
+    pub commitment: String, // SHA-256 of the seed (`utils::seed_commitment`), shown while the cards go round
+    pub imposter_card: GameCard,
+    pub civilian_cards: Vec<GameCard>, // One per seat: the card that seat gets if it isn't the imposter
+    pub weights: Vec<u32>, // Fair-rotation weights when dealt; empty for an even draw
 }
 
+/// The seed and commitment of an opened blind deal, kept until the next deal so the
+/// round end can show the seed and check it against the commitment
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DealProof {
+    pub seed: u64,
+    pub commitment: String,
+}
+
+/// What kind of secret the cards carry
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    #[default]
+    WordPairs, // Civilians share a word, the imposter gets a similar one
+    Location, // Spyfall-style: civilians get a location + role, the imposter gets nothing
+}
+
+/// What the imposter's card shows in word-pair games
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum ImposterMode {
+    #[default]
+    SimilarWord, // The imposter gets the other word of the pair
+    NoWord, // The imposter gets a blank card and must bluff entirely
+    CategoryHint, // The imposter gets only a hint at the pair's category (e.g. "Drink")
+}
+
+/// How close a word pair's two words are; closer words make the imposter harder to spot.
+/// Written in lowercase in words.yaml (`difficulty: hard`).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy, // Clearly different words, e.g. Cat / Dog
+    #[default]
+    Medium, // Untagged pairs land here
+    Hard, // Near-synonyms, e.g. Sea / Ocean
+}
+
+/// Colour scheme for the whole app
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark, // Dim-room palette, applied through the theme-dark class on the root container
+}
+
+/// Display aids for players who need them, applied as classes on the root container
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct A11yConfig {
+    pub large_text: bool, // Scale every screen's text up by a quarter
+    pub high_contrast: bool, // Solid backgrounds, plain black-and-white text and heavier outlines
+}
+
+/// Screen the game is shown on, applied as a class on the root container
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum DisplayMode {
+    #[default]
+    Phone, // One device passed around the table
+    Tv, // Cast to a big screen: larger cards and text, grids spread across the width
+}
+
+/// How a tied secret ballot is resolved
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub enum TieBreakMode {
+    #[default]
+    Revote, // Vote again, choosing only among the tied players
+    NoElimination, // Nobody is evicted; discussion starts over
+}
+
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
 pub enum GameScreen {
+    #[default]
     Setup,
     CategorySelection,
     CategoryReveal { category_name: String, category_icon: String },
     CardView { current_player_index: usize },
     Voting,
+    SecretVoting {
+        current_voter_index: usize,
+        votes: Vec<usize>,
+        // Players who may receive votes; empty means every active player
+        #[serde(default)]
+        candidates: Vec<usize>,
+        // Tie-break revotes held before this ballot, this one included
+        #[serde(default)]
+        revotes: u32,
+    },
+    VoteReveal {
+        tally: Vec<(usize, u32)>, // Secret ballot counts (player, votes), most first, before anyone is evicted
+        #[serde(default)]
+        revotes: u32,
+    },
+    TieBreak {
+        tied_indices: Vec<usize>,
+        #[serde(default)]
+        revotes: u32,
+    },
     Elimination { eliminated_index: usize, was_imposter: bool },
+    ImposterGuess { civilian_word: String }, // An evicted imposter's one try at the word (comeback rule)
     RoundEnd { imposter_found: bool, game_over: bool },
     GameScore,
+    Stats,
+    RoundHistory,
+    Replay { step: usize }, // Stepping through the last finished round: cards, evictions, then the outcome
+    EditPlayers,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+/// Version of the saved `GameState` layout; bump when a change needs migrating
+/// (see `persistence::migrate_game_state`, or `persistence::game_state_from_json`
+/// for fields that moved)
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Saves from before versioning have no `schema_version` and use the original layout
+fn original_schema_version() -> u32 {
+    1
+}
+
+/// A dealt word pair as (word list, position in that list): a category index, or
+/// `utils::CUSTOM_PAIRS_KEY` for the players' own pairs
+pub type RecentPair = (usize, usize);
+
+/// Older saves kept bare pair positions without their list; those say nothing about
+/// any particular list any more, so they're dropped
+fn recent_pairs_keyed_by_list<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<RecentPair>, D::Error> {
+    let entries: Vec<serde_json::Value> = Vec::deserialize(deserializer)?;
+    Ok(entries.into_iter().filter_map(|entry| serde_json::from_value(entry).ok()).collect())
+}
+
+// Missing fields fall back to their defaults so saves from older builds still load
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GameState {
+    #[serde(default = "original_schema_version")]
+    pub schema_version: u32,
     pub session_id: String,
     pub game_screen: GameScreen,
     pub players: Vec<Player>,
@@ -43,9 +203,168 @@ pub struct GameState {
     pub imposter_index: usize,
     pub current_category: Option<(String, String)>, // (name, icon)
     pub selected_category_index: Option<usize>, // Selected category index for the round
-    pub hide_imposter_identity: bool, // Toggle to hide imposter from themselves
     pub current_round_words: Option<(String, String)>, // (civilian_word, imposter_word)
+    #[serde(default)]
+    pub dealt_pair: Option<(String, String)>, // Both words of the round's pair, even when the imposter's card shows neither
+    #[serde(default)]
+    pub twist_active: bool, // This round's imposter word came from an unrelated pair
     pub starting_player_index: usize, // Rotates each round to determine who picks card first
+    #[serde(default)]
+    pub settings: Settings,
+    #[serde(default)]
+    pub grace_round_pending: bool, // The game's first round is still in its no-elimination warm-up vote
+    #[serde(default)]
+    pub timer_remaining: Option<u32>, // Seconds left on the discussion timer; None until voting starts
+    #[serde(default)]
+    pub timer_paused: bool, // Countdown is frozen at timer_remaining
+    #[serde(default)]
+    pub history: Vec<usize>, // Players evicted so far this round, most recent last
+    #[serde(default)]
+    pub hidden_catch: Option<usize>, // Blind evictions: the imposter is out, after this many earlier evictions; settled at round end
+    #[serde(default, deserialize_with = "recent_pairs_keyed_by_list")]
+    pub recent_pairs: Vec<RecentPair>, // Latest word pairs dealt this game, oldest first, skipped while others are left
+    #[serde(default)]
+    pub skipped_players: Vec<usize>, // Seats that passed on viewing their card this round, in skip order
+    #[serde(default)]
+    pub turn_order: Vec<usize>, // Shuffled seat order for this round's hand-off and votes; empty follows seating order
+    #[serde(default)]
+    pub round_log: Vec<RoundRecord>, // One entry per finished round this session, oldest first
+    #[serde(default)]
+    pub consecutive_skips: usize, // Votes in a row that ended with "No Evict This Round"
+    #[serde(default)]
+    pub times_imposter: Vec<i32>, // Rounds each seat has been dealt the imposter card this game
+    #[serde(default)]
+    pub event_log: Vec<GameEvent>, // Screen changes while `record_events` is on, oldest first, capped
+    #[serde(default)]
+    pub solo: bool, // Practice game: only the first seat is a real player, the app passes the others' cards
+    #[serde(default)]
+    pub sealed_deal: Option<SealedDeal>, // Blind deal: cards and imposter stay unset until everyone has looked
+    #[serde(default)]
+    pub opened_deal: Option<DealProof>, // The round's blind deal once opened, for the check at round end
+}
+
+/// One screen change, recorded when `Settings::record_events` is on
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct GameEvent {
+    pub ts: f64, // Milliseconds since the Unix epoch
+    pub session_id: String,
+    pub from: GameScreen,
+    pub to: GameScreen,
+}
+
+/// Outcome of one finished round, kept for the round history screen and the replay
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct RoundRecord {
+    pub round_number: i32,
+    pub civilian_word: String,
+    pub imposter_word: String, // Empty when the imposter had no word
+    pub imposter_name: String,
+    pub civilians_won: bool,
+    #[serde(default)]
+    pub seats: Vec<SeatRecord>, // Everyone's card in seat order; empty for rounds logged before replays
+    #[serde(default)]
+    pub evicted: Vec<usize>, // Seats voted out, in order, the deciding eviction last
+}
+
+/// One player's card in a finished round
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct SeatRecord {
+    pub name: String,
+    pub card: GameCard,
+}
+
+/// Optional gameplay settings chosen on the setup screen
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub eliminated_role_check: bool, // Let evicted players privately check their own role
+    pub animations: bool, // Celebration effects at round end (reduced-motion is always respected)
+    pub compact_scoreboard: bool, // Dense table layout for the scoreboard (handy for large groups)
+    pub reveal_name_check: bool, // Require the first letter of the player's name before revealing
+    pub show_tips: bool, // One-line strategy tip under each revealed card, different for civilians and the imposter
+    pub blind_deal: bool, // The imposter's seat is only settled card by card, so even the device's owner can't look ahead
+    pub skip_flagged_pairs: bool, // Leave out word pairs flagged on this device at round end
+    pub twist_round: bool, // Similar-word rounds: the imposter's word comes from an unrelated pair instead
+    pub random_category_per_round: bool, // Skip category selection and draw a random theme each round
+    pub seat_swap_warning: bool, // Nudge when consecutive reveals happen implausibly fast
+    pub shuffle_pass_order: bool, // Deal and vote in a freshly shuffled order each round instead of seating order
+    pub fair_rotation: bool, // Lean the imposter pick towards players who've had it least this game
+    pub grace_round: bool, // The game's first vote is discussion-only, nobody is evicted
+    pub comeback_enabled: bool, // An evicted imposter who guesses the civilian word still wins
+    pub reveal_role_on_eviction: bool, // Off: evictions don't say who was the imposter, and voting goes on until the round runs out
+    pub single_vote_challenge: bool, // Co-op: the round's first eviction decides it, and a wrong one is an imposter win
+    pub game_mode: GameMode,
+    pub word_pack: Option<String>, // Themed pack name; None plays the mixed list of all categories
+    pub custom_pairs: Vec<(String, String)>, // Player-entered pairs, used instead of the built-in lists when set
+    pub timer_seconds: Option<u32>, // Discussion countdown per voting round; None turns the timer off
+    pub reveal_timeout_secs: Option<u32>, // Revealed cards pass on by themselves after this long; None waits for Next Player
+    pub pass_delay_secs: Option<u32>, // Reveal stays locked this long after a hand-off, so the last player can look away
+    pub secret_voting: bool, // Pass the device for private ballots instead of agreeing on an eviction
+    pub tie_break: TieBreakMode, // How a tied secret ballot is resolved
+    pub hide_live_tally: bool, // Secret ballot counts stay hidden until every vote is in, so nobody follows the crowd
+    pub imposter_mode: ImposterMode,
+    pub difficulty: Option<Difficulty>, // Only deal pairs of this tier; None deals from every pair
+    pub speed_bonus: bool, // Extra civilian points for catching the imposter in fewer votes
+    pub reveal_imposter_at_round_end: bool, // Off: round end shows only who won; the scoreboard names the imposters
+    pub persistence_enabled: bool, // Off for private games: nothing is written to storage
+    pub record_events: bool, // Log every screen change to `GameState::event_log` (and the server's event file)
+    pub imposter_win_threshold: usize, // Imposter wins once this many players (or fewer) remain
+    pub max_consecutive_skips: usize, // Votes in a row that may end without an eviction; 0 always evicts
+    pub lang: Lang, // Interface language
+    pub hide_imposter_identity: bool, // Hard mode: the imposter's card looks like a civilian's, so they don't know it's them
+    pub moderator_mode: bool, // A non-player runs the table and may check both words mid-round
+    // This device's own preferences; a shared setup link never carries them across
+    pub sound_enabled: bool, // Audio cues for reveals, evictions and round results
+    pub theme: Theme,
+    pub accessibility: A11yConfig,
+    pub display_mode: DisplayMode,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            eliminated_role_check: false,
+            animations: true,
+            compact_scoreboard: false,
+            reveal_name_check: false,
+            show_tips: false,
+            blind_deal: false,
+            skip_flagged_pairs: true,
+            twist_round: false,
+            random_category_per_round: false,
+            seat_swap_warning: false,
+            shuffle_pass_order: false,
+            fair_rotation: false,
+            grace_round: false,
+            comeback_enabled: false,
+            reveal_role_on_eviction: true,
+            single_vote_challenge: false,
+            game_mode: GameMode::WordPairs,
+            word_pack: None,
+            custom_pairs: Vec::new(),
+            timer_seconds: None,
+            reveal_timeout_secs: None,
+            pass_delay_secs: None,
+            secret_voting: false,
+            tie_break: TieBreakMode::Revote,
+            hide_live_tally: true,
+            imposter_mode: ImposterMode::SimilarWord,
+            difficulty: None,
+            speed_bonus: true,
+            reveal_imposter_at_round_end: true,
+            persistence_enabled: true,
+            record_events: false,
+            imposter_win_threshold: 2,
+            max_consecutive_skips: 2,
+            lang: Lang::English,
+            hide_imposter_identity: false,
+            moderator_mode: false,
+            sound_enabled: true,
+            theme: Theme::Light,
+            accessibility: A11yConfig::default(),
+            display_mode: DisplayMode::Phone,
+        }
+    }
 }
 
 // Word list structures
@@ -53,11 +372,74 @@ pub struct GameState {
 pub struct WordCategory {
     pub name: String,
     pub icon: String,
-    pub pairs: Vec<(String, String)>,
+    pub pairs: Vec<WordPair>,
+}
+
+/// One entry of a category's `pairs` list: `[civilian, imposter]`, or
+/// `[civilian, imposter, hint]` to give category-hint imposters something narrower
+/// than the category name. Tagging a difficulty takes the longer form
+/// `{ words: [civilian, imposter], difficulty: hard }`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "RawWordPair")]
+pub struct WordPair {
+    pub first: String,
+    pub second: String,
+    pub hint: Option<String>,
+    pub difficulty: Difficulty,
+}
+
+/// The two ways a pair can be written in words.yaml
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawWordPair {
+    Words(Vec<String>),
+    Tagged {
+        words: Vec<String>,
+        #[serde(default)]
+        difficulty: Difficulty,
+    },
+}
+
+impl TryFrom<RawWordPair> for WordPair {
+    type Error = String;
+
+    fn try_from(raw: RawWordPair) -> Result<Self, Self::Error> {
+        let (words, difficulty) = match raw {
+            RawWordPair::Words(words) => (words, Difficulty::default()),
+            RawWordPair::Tagged { words, difficulty } => (words, difficulty),
+        };
+        let mut words = words.into_iter();
+        match (words.next(), words.next(), words.next(), words.next()) {
+            (Some(first), Some(second), hint, None) => Ok(WordPair { first, second, hint, difficulty }),
+            _ => Err("a word pair needs two words and an optional hint".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WordPack {
+    pub name: String,
+    pub icon: String,
+    pub categories: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct WordList {
     pub categories: Vec<WordCategory>,
+    #[serde(default)]
+    pub packs: Vec<WordPack>,
+}
+
+
+// Location scenario structures
+#[derive(Debug, Deserialize)]
+pub struct Scenario {
+    pub location: String,
+    pub icon: String,
+    pub roles: Vec<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ScenarioList {
+    pub scenarios: Vec<Scenario>,
+}
@@ -1,21 +1,495 @@
-use crate::views::game::types::{GameCard, CardType, WordList, WordCategory};
+use crate::views::game::flagged_pairs::{pair_key, FlaggedPairs};
+use crate::views::game::i18n::{ui_text, Lang};
+use crate::views::game::types::{A11yConfig, GameCard, CardType, Difficulty, DisplayMode, GameMode, ImposterMode, GameScreen, Player, RecentPair, ScenarioList, Settings, Theme, TieBreakMode, WordList, WordCategory, WordPack, WordPair};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::Mutex;
 
-// Include the YAML file at compile time
+// Include the YAML files at compile time
 const WORDS_YAML: &str = include_str!("../../../words.yaml");
+const SCENARIOS_YAML: &str = include_str!("../../../scenarios.yaml");
 
-/// Generate a random starting index using getrandom
+/// Attempts at the OS random source before falling back to the clock
+const RANDOM_RETRIES: usize = 3;
+
+thread_local! {
+    /// Generator state once `seed_random` has been called on this thread
+    static SEEDED_STATE: std::cell::Cell<Option<u64>> = const { std::cell::Cell::new(None) };
+}
+
+/// Make every later draw on this thread reproducible from `seed`, e.g. for the
+/// simulator's `--seed`; None goes back to the OS source. Games in the app never call this.
+pub fn seed_random(seed: Option<u64>) {
+    SEEDED_STATE.with(|state| state.set(seed));
+}
+
+/// 64 random bits from the OS (or browser) random source, or from the seeded
+/// generator after `seed_random`.
+/// If the OS source keeps failing, fall back to a clock-seeded generator instead of
+/// handing back zeros, which would make player 0 the imposter every round.
+pub fn random_u64() -> u64 {
+    if let Some(state) = SEEDED_STATE.with(|state| state.get()) {
+        let next = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        SEEDED_STATE.with(|cell| cell.set(Some(next)));
+        return split_mix(next);
+    }
+    let mut buf = [0u8; 8];
+    for _ in 0..RANDOM_RETRIES {
+        if getrandom::getrandom(&mut buf).is_ok() {
+            return u64::from_le_bytes(buf);
+        }
+    }
+    fallback_random_u64()
+}
+
+/// Clock- and counter-seeded SplitMix64. Not cryptographic, but unbiased enough
+/// for dealing cards, and never returns the same value twice in a row.
+fn fallback_random_u64() -> u64 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let seed = (now_ms() as u64) ^ COUNTER.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed);
+    split_mix(seed.wrapping_add(0x9E37_79B9_7F4A_7C15))
+}
+
+/// SplitMix64 output step
+fn split_mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Generate a random starting index
 /// Used for randomizing which player goes first
 pub fn get_random_starting_index(max: usize) -> usize {
     if max == 0 {
         return 0;
     }
-    let mut buf = [0u8; 4];
-    getrandom::getrandom(&mut buf).unwrap_or_default();
-    let num = u32::from_le_bytes(buf);
-    (num as usize) % max
+    (random_u64() % max as u64) as usize
+}
+
+/// Current wall-clock time in milliseconds
+pub fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs_f64() * 1000.0)
+            .unwrap_or_default()
+    }
+}
+
+/// Wait for the given number of milliseconds without blocking the UI
+pub async fn sleep_ms(ms: u32) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        gloo_timers::future::TimeoutFuture::new(ms).await;
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::time::sleep(std::time::Duration::from_millis(ms as u64)).await;
+    }
+}
+
+/// Format a number of seconds as mm:ss
+pub fn format_mm_ss(seconds: u32) -> String {
+    format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Give a freshly mounted element keyboard focus so Enter/Space work without clicking.
+/// Only moves focus once; Tab still reaches every other control.
+pub fn focus_on_mount(evt: dioxus::prelude::MountedEvent) {
+    dioxus::prelude::spawn(async move {
+        let _ = evt.set_focus(true).await;
+    });
+}
+
+/// Classes for the root container. Every screen sits inside it, so theme and
+/// display aids reach all of them without each screen knowing.
+pub fn root_class(theme: Theme, accessibility: A11yConfig, display_mode: DisplayMode) -> String {
+    let mut class = String::from("game-container");
+    if theme == Theme::Dark {
+        class.push_str(" theme-dark");
+    }
+    if display_mode == DisplayMode::Tv {
+        class.push_str(" tv-mode");
+    }
+    if accessibility.large_text {
+        class.push_str(" large-text");
+    }
+    if accessibility.high_contrast {
+        class.push_str(" high-contrast");
+    }
+    class
+}
+
+/// Seats evicted this round in the order they went. The deciding eviction never
+/// reaches `history`, so it's whoever else is out, last.
+pub fn eviction_order(players: &[Player], history: &[usize]) -> Vec<usize> {
+    let mut order = history.to_vec();
+    order.extend((0..players.len()).filter(|seat| players[*seat].is_eliminated && !history.contains(seat)));
+    order
+}
+
+/// Shuffle a slice in place (Fisher-Yates)
+pub fn shuffle<T>(items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        let j = get_random_starting_index(i + 1);
+        items.swap(i, j);
+    }
+}
+
+/// Draw up to `count` distinct words from `pool`, skipping anything in `exclude`.
+/// Comparison is case-insensitive and ignores surrounding whitespace. When the
+/// pool is too small the result is simply shorter than `count`, so callers can
+/// scale down (e.g. offer fewer decoys) instead of looping or panicking.
+pub fn pick_distinct_words(pool: &[String], exclude: &[String], count: usize) -> Vec<String> {
+    let normalize = |word: &str| word.trim().to_lowercase();
+    let mut seen: Vec<String> = exclude.iter().map(|w| normalize(w)).collect();
+    let mut candidates = Vec::new();
+    for word in pool {
+        let key = normalize(word);
+        if key.is_empty() || seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+        candidates.push(word.trim().to_string());
+    }
+    shuffle(&mut candidates);
+    candidates.truncate(count);
+    candidates
+}
+
+/// Check whether `typed` starts with the same letter as `name` (case-insensitive)
+pub fn matches_name_initial(name: &str, typed: &str) -> bool {
+    let first = |s: &str| s.trim().chars().next().map(|c| c.to_lowercase().collect::<String>());
+    match (first(name), first(typed)) {
+        (Some(expected), Some(given)) => expected == given,
+        _ => false,
+    }
+}
+
+/// How many players have already looked at their card during the hand-off.
+/// Skipped players only count once they come back, and a player revisiting from
+/// the hand-off screen is the first of the stragglers still to look.
+pub fn cards_seen(current_player_index: usize, total_players: usize, skipped: usize, revisiting: bool) -> usize {
+    let passed = if revisiting { total_players } else { current_player_index.min(total_players) };
+    passed.saturating_sub(skipped)
+}
+
+/// Canonical form of a player name: any Unicode whitespace (e.g. the ideographic space)
+/// is trimmed and inner runs collapse to one space; control and zero-width characters go.
+/// Joiners inside emoji sequences are kept.
+pub fn normalize_name(name: &str) -> String {
+    let visible: String = name.chars()
+        .filter(|&c| (c.is_whitespace() || !c.is_control()) && !matches!(c, '\u{200B}' | '\u{2060}' | '\u{FEFF}'))
+        .collect();
+    visible.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Indices of names that collide with another name (ignoring case and spacing)
+pub fn duplicate_name_indices(names: &[String]) -> Vec<usize> {
+    let keys: Vec<String> = names.iter().map(|n| normalize_name(n).to_lowercase()).collect();
+    (0..keys.len())
+        .filter(|&i| !keys[i].is_empty() && keys.iter().enumerate().any(|(j, k)| j != i && *k == keys[i]))
+        .collect()
+}
+
+/// Number repeated names so every player is distinct: "Alex", "Alex 2", "Alex 3".
+/// Names typed with a number already keep it, and the repeats skip past it.
+pub fn with_unique_suffixes(names: &[String]) -> Vec<String> {
+    let normalized: Vec<String> = names.iter().map(|name| normalize_name(name)).collect();
+    let mut taken: Vec<String> = Vec::new();
+    // First claim every name that stays as typed, so no number handed out below can clash with one
+    let first_use: Vec<bool> = normalized.iter()
+        .map(|name| {
+            let key = name.to_lowercase();
+            let first = !taken.contains(&key);
+            if first {
+                taken.push(key);
+            }
+            first
+        })
+        .collect();
+    normalized.iter()
+        .zip(first_use)
+        .map(|(base, first)| {
+            if first || base.is_empty() {
+                return base.clone();
+            }
+            let mut n = 2;
+            let mut candidate = format!("{} {}", base, n);
+            while taken.contains(&candidate.to_lowercase()) {
+                n += 1;
+                candidate = format!("{} {}", base, n);
+            }
+            taken.push(candidate.to_lowercase());
+            candidate
+        })
+        .collect()
+}
+
+/// The name `players[index]` would take when renamed to `name`, by the same rules as
+/// setup: normalized, not empty, and not already someone else's. `None` if it's not allowed.
+pub fn valid_rename(players: &[Player], index: usize, name: &str) -> Option<String> {
+    let name = normalize_name(name);
+    let mut names: Vec<String> = players.iter().map(|p| p.name.clone()).collect();
+    let seat = names.get_mut(index)?;
+    *seat = name.clone();
+    (!name.is_empty() && !duplicate_name_indices(&names).contains(&index)).then_some(name)
+}
+
+/// The names for the first `count` seats, padded with blanks. Names typed for seats
+/// beyond the current count stay in the list, so growing the count back restores them.
+pub fn seat_names(names: &[String], count: usize) -> Vec<String> {
+    let mut seats: Vec<String> = names.iter().take(count).cloned().collect();
+    seats.resize(count, String::new());
+    seats
+}
+
+/// Smallest and largest supported player counts
+pub const MIN_PLAYERS: usize = 3;
+pub const MAX_PLAYERS: usize = 20;
+
+/// Groups bigger than this get denser setup and voting layouts so everyone fits on screen
+pub const LARGE_GROUP: usize = 10;
+
+/// In a solo practice game this seat is the real player; the app passes every other card
+pub const SOLO_SEAT: usize = 0;
+
+/// How the typed player count reads while someone is still editing it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlayerCountEntry {
+    /// A supported count, safe to resize the name list to
+    Valid(usize),
+    /// A number outside the supported range, with the nearest count it will become
+    Clamped(usize),
+    /// Empty or not a number yet
+    Invalid,
+}
+
+/// Read the player count field without jumping to a default on half-typed input
+pub fn read_player_count(input: &str) -> PlayerCountEntry {
+    match input.trim().parse::<usize>() {
+        Ok(count) if (MIN_PLAYERS..=MAX_PLAYERS).contains(&count) => PlayerCountEntry::Valid(count),
+        Ok(count) => PlayerCountEntry::Clamped(count.clamp(MIN_PLAYERS, MAX_PLAYERS)),
+        Err(_) => PlayerCountEntry::Invalid,
+    }
+}
+
+/// Accent colors handed out to players in seat order, one per seat up to `MAX_PLAYERS`
+pub const PLAYER_COLORS: [&str; MAX_PLAYERS] = [
+    "#e03131", "#1971c2", "#2f9e44", "#f08c00", "#9c36b5",
+    "#0c8599", "#e64980", "#5c940d", "#6741d9", "#495057",
+    "#c2255c", "#1864ab", "#087f5b", "#d9480f", "#862e9c",
+    "#0b7285", "#a61e4d", "#2b8a3e", "#364fc7", "#868e96",
+];
+
+/// Color for the player in the given seat
+pub fn player_color(index: usize) -> String {
+    PLAYER_COLORS[index % PLAYER_COLORS.len()].to_string()
+}
+
+/// First palette color no current player is using, for someone joining mid-session
+pub fn next_free_color(players: &[Player]) -> String {
+    PLAYER_COLORS.iter()
+        .find(|color| !players.iter().any(|p| p.color == **color))
+        .map(|color| color.to_string())
+        .unwrap_or_else(|| player_color(players.len()))
+}
+
+/// Give players without a color (e.g. from older saves) one that isn't taken
+pub fn assign_missing_colors(players: &mut [Player]) {
+    for i in 0..players.len() {
+        if players[i].color.is_empty() {
+            players[i].color = next_free_color(players);
+        }
+    }
+}
+
+/// Fewest players left standing at which the imposter can be declared the winner
+pub const MIN_IMPOSTER_WIN_THRESHOLD: usize = 2;
+
+/// The imposter-wins threshold actually used for a game of `player_count`: at least
+/// 2 and below the count left after one eviction, so the first eviction can't end the
+/// game outright (only a table of three is too small for that)
+pub fn imposter_win_threshold(settings: &Settings, player_count: usize) -> usize {
+    let highest = player_count.saturating_sub(2).max(MIN_IMPOSTER_WIN_THRESHOLD);
+    settings.imposter_win_threshold.clamp(MIN_IMPOSTER_WIN_THRESHOLD, highest)
+}
+
+/// Civilian points for catching the imposter
+pub const CIVILIAN_WIN_POINTS: i32 = 10;
+
+/// Imposter points for outlasting the civilians
+pub const IMPOSTER_WIN_POINTS: i32 = 20;
+
+/// Bonus on top of `CIVILIAN_WIN_POINTS` for catching the imposter on the given vote
+/// of the round (1 = first vote); it shrinks with every vote and bottoms out at zero
+pub fn speed_bonus(vote_number: usize) -> i32 {
+    (15 - vote_number as i32 * 3).max(0)
+}
+
+/// Speed bonus earned this round, given how many evictions came before the imposter's
+pub fn round_speed_bonus(settings: &Settings, earlier_evictions: usize) -> i32 {
+    if settings.speed_bonus {
+        speed_bonus(earlier_evictions + 1)
+    } else {
+        0
+    }
+}
+
+/// Extra civilian points for winning the single-vote challenge
+pub const SINGLE_VOTE_BONUS: i32 = 10;
+
+/// Challenge bonus on top of a civilian win, when the round was a single-vote challenge
+pub fn challenge_bonus(settings: &Settings) -> i32 {
+    if settings.single_vote_challenge { SINGLE_VOTE_BONUS } else { 0 }
+}
+
+/// Whether `turn_order` is a usable shuffled order for `total_players` seats
+fn is_valid_turn_order(turn_order: &[usize], total_players: usize) -> bool {
+    let mut seats = turn_order.to_vec();
+    seats.sort_unstable();
+    seats.len() == total_players && seats.iter().enumerate().all(|(i, &seat)| i == seat)
+}
+
+/// A freshly shuffled seat order for a random-pass-order round,
+/// or an empty order (seating order) when the setting is off
+pub fn new_turn_order(total_players: usize, settings: &Settings) -> Vec<usize> {
+    if !settings.shuffle_pass_order {
+        return Vec::new();
+    }
+    let mut order: Vec<usize> = (0..total_players).collect();
+    shuffle(&mut order);
+    order
+}
+
+/// Seats in the order the device is passed for cards: the round's shuffled
+/// `turn_order` when it still fits the roster, otherwise around the table from
+/// the starting player. Entries index straight into `players` and `cards`.
+pub fn pass_order(total_players: usize, starting_player_index: usize, turn_order: &[usize]) -> Vec<usize> {
+    if is_valid_turn_order(turn_order, total_players) {
+        return turn_order.to_vec();
+    }
+    (0..total_players)
+        .map(|i| (starting_player_index + i) % total_players)
+        .collect()
+}
+
+/// Active players in discussion order: the round's shuffled order if it has one,
+/// otherwise rotated by the round's starting player
+pub fn active_player_order(players: &[Player], starting_player_index: usize, turn_order: &[usize]) -> Vec<usize> {
+    if is_valid_turn_order(turn_order, players.len()) {
+        return turn_order.iter().copied().filter(|&seat| !players[seat].is_eliminated).collect();
+    }
+    let active: Vec<usize> = players.iter()
+        .enumerate()
+        .filter(|(_, p)| !p.is_eliminated)
+        .map(|(i, _)| i)
+        .collect();
+    if active.is_empty() {
+        return active;
+    }
+    let start_idx = starting_player_index % players.len();
+    (0..active.len())
+        .map(|i| active[(start_idx + i) % active.len()])
+        .collect()
+}
+
+/// Count secret ballots as (player index, votes), most votes first and ties by seat
+pub fn tally_votes(votes: &[usize]) -> Vec<(usize, u32)> {
+    let mut counts: HashMap<usize, u32> = HashMap::new();
+    for &vote in votes {
+        *counts.entry(vote).or_default() += 1;
+    }
+    let mut tally: Vec<(usize, u32)> = counts.into_iter().collect();
+    tally.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    tally
+}
+
+/// Player indices sharing the most votes in a tally, lowest index first
+pub fn vote_leaders(tally: &[(usize, u32)]) -> Vec<usize> {
+    let top = tally.iter().map(|&(_, count)| count).max().unwrap_or(0);
+    let mut leaders: Vec<usize> = tally.iter()
+        .filter(|&&(_, count)| count == top)
+        .map(|&(player, _)| player)
+        .collect();
+    leaders.sort_unstable();
+    leaders
+}
+
+/// Revotes a tie gets before nobody is evicted instead; the same players tying
+/// again and again would otherwise keep the table voting forever
+pub const MAX_REVOTES: u32 = 2;
+
+/// How a tie is broken after `revotes` revotes have already tied
+pub fn tie_break_mode(settings: &Settings, revotes: u32) -> TieBreakMode {
+    if revotes >= MAX_REVOTES { TieBreakMode::NoElimination } else { settings.tie_break }
+}
+
+/// The screen a new round starts on: category selection, or straight to
+/// dealing when the round's words don't come from a chosen category
+pub fn round_start_screen(settings: &Settings) -> GameScreen {
+    if settings.random_category_per_round
+        || settings.game_mode == GameMode::Location
+        || !settings.custom_pairs.is_empty()
+    {
+        GameScreen::CardView { current_player_index: 0 }
+    } else {
+        GameScreen::CategorySelection
+    }
+}
+
+/// Skips the group may still take before a vote has to evict someone
+pub fn skips_remaining(settings: &Settings, consecutive_skips: usize) -> usize {
+    settings.max_consecutive_skips.saturating_sub(consecutive_skips)
+}
+
+/// Short spoken summary of a screen for the screen-reader live region.
+/// Only says what the screen itself shows, so it never gives away a role.
+pub fn screen_announcement(screen: &GameScreen, players: &[Player], starting_player_index: usize, turn_order: &[usize], lang: Lang) -> String {
+    let t = ui_text(lang);
+    let name_at = |index: usize| players.get(index).map(|p| p.name.clone()).unwrap_or_default();
+    let remaining = players.iter().filter(|p| !p.is_eliminated).count();
+    match screen {
+        GameScreen::Setup => t.announce_setup.to_string(),
+        GameScreen::CategorySelection => t.announce_category_selection.to_string(),
+        GameScreen::CategoryReveal { category_name, .. } => (t.announce_category)(category_name),
+        GameScreen::CardView { current_player_index } if *current_player_index >= players.len() => {
+            t.announce_cards_done.to_string()
+        }
+        GameScreen::CardView { current_player_index } => {
+            let seat = pass_order(players.len(), starting_player_index, turn_order)[*current_player_index];
+            (t.announce_card_turn)(&name_at(seat))
+        }
+        GameScreen::Voting => (t.announce_voting)(remaining),
+        GameScreen::SecretVoting { current_voter_index, .. } => {
+            (t.announce_secret_ballot)((current_voter_index + 1).min(remaining), remaining)
+        }
+        GameScreen::VoteReveal { .. } => t.announce_vote_reveal.to_string(),
+        GameScreen::TieBreak { tied_indices, .. } => (t.announce_tie)(tied_indices.len()),
+        GameScreen::Elimination { eliminated_index, .. } => (t.announce_evicted)(&name_at(*eliminated_index)),
+        GameScreen::ImposterGuess { .. } => t.announce_imposter_guess.to_string(),
+        GameScreen::RoundEnd { game_over: true, .. } => t.announce_game_over.to_string(),
+        GameScreen::RoundEnd { imposter_found: true, .. } => t.announce_imposter_caught.to_string(),
+        GameScreen::RoundEnd { .. } => t.announce_imposter_escaped.to_string(),
+        GameScreen::GameScore => t.announce_scoreboard.to_string(),
+        GameScreen::Stats => t.announce_stats.to_string(),
+        GameScreen::RoundHistory => t.announce_round_history.to_string(),
+        GameScreen::Replay { .. } => t.announce_replay.to_string(),
+        GameScreen::EditPlayers => t.announce_edit_players.to_string(),
+    }
+}
+
+/// Pick a random category index for the round from the selected pack
+pub fn random_category_index(word_pack: Option<&str>) -> usize {
+    let indices = category_indices_for_pack(word_pack);
+    indices.get(get_random_starting_index(indices.len())).copied().unwrap_or(0)
 }
 
 /// Load word categories from YAML
@@ -28,33 +502,117 @@ pub fn get_all_categories() -> Vec<WordCategory> {
     load_word_categories().categories
 }
 
-/// Helper function to generate cards for the round with a specific category
-pub fn generate_cards_for_category(player_count: usize, category_index: usize) -> (Vec<GameCard>, usize, String, String) {
-    use getrandom::getrandom;
-    
+/// Get the built-in themed word packs
+pub fn get_word_packs() -> Vec<WordPack> {
+    load_word_categories().packs
+}
+
+/// Indices (into `get_all_categories`) of the playable categories in a pack.
+/// Unknown packs, or packs without any word pairs, fall back to the full mixed list.
+pub fn category_indices_for_pack(word_pack: Option<&str>) -> Vec<usize> {
+    let word_list = load_word_categories();
+    let playable = |i: &usize| !word_list.categories[*i].pairs.is_empty();
+
+    if let Some(pack) = word_pack.and_then(|name| word_list.packs.iter().find(|p| p.name == name)) {
+        let indices: Vec<usize> = (0..word_list.categories.len())
+            .filter(|i| pack.categories.contains(&word_list.categories[*i].name))
+            .filter(playable)
+            .collect();
+        if !indices.is_empty() {
+            return indices;
+        }
+    }
+    (0..word_list.categories.len()).filter(playable).collect()
+}
+
+/// List key used for custom pairs so they never share state with a built-in category
+pub const CUSTOM_PAIRS_KEY: usize = usize::MAX;
+
+/// How many of the latest word pairs a session steers clear of, so a long night
+/// doesn't keep circling the same few
+pub const RECENT_PAIR_MEMORY: usize = 6;
+
+/// Note a dealt pair in the session's recent pairs, forgetting the oldest past `RECENT_PAIR_MEMORY`
+pub fn remember_pair(recent_pairs: &mut Vec<RecentPair>, pair: RecentPair) {
+    recent_pairs.push(pair);
+    if recent_pairs.len() > RECENT_PAIR_MEMORY {
+        recent_pairs.drain(..recent_pairs.len() - RECENT_PAIR_MEMORY);
+    }
+}
+
+/// Positions in `pairs` of the given difficulty tier, or of every pair when no tier
+/// is chosen or the category has none of that tier
+pub fn pairs_for_difficulty(pairs: &[WordPair], difficulty: Option<Difficulty>) -> Vec<usize> {
+    let tier: Vec<usize> = (0..pairs.len())
+        .filter(|&i| difficulty.is_none_or(|d| pairs[i].difficulty == d))
+        .collect();
+    if tier.is_empty() { (0..pairs.len()).collect() } else { tier }
+}
+
+/// `candidates` less the pairs flagged on this device. When every one of them is
+/// flagged they all stay in, so a fully flagged list still deals.
+pub fn without_flagged(pairs: &[(String, String)], candidates: Vec<usize>, flagged: &FlaggedPairs) -> Vec<usize> {
+    let kept: Vec<usize> = candidates.iter()
+        .copied()
+        .filter(|&i| !flagged.contains(&pair_key(&pairs[i].0, &pairs[i].1)))
+        .collect();
+    if kept.is_empty() { candidates } else { kept }
+}
+
+/// Helper function to generate cards for the round with a specific category.
+/// Returns (cards, imposter index, category name, category icon, (category index, pair index)).
+/// `imposter_weights` biases who gets the imposter card (see `pick_imposter_seat`).
+pub fn generate_cards_for_category(player_count: usize, category_index: usize, difficulty: Option<Difficulty>, recent_pairs: &[RecentPair], imposter_weights: &[u32], flagged: &FlaggedPairs) -> (Vec<GameCard>, usize, String, String, RecentPair) {
     let word_list = load_word_categories();
     
-    // Use the selected category
-    let category = &word_list.categories[category_index % word_list.categories.len()];
-    
-    // Get random bytes for word pair selection
-    let mut buf_word = [0u8; 8];
-    let _ = getrandom(&mut buf_word);
-    let random_word = u64::from_le_bytes(buf_word);
+    // Use the selected category, falling back to the mixed list if it has no pairs
+    let category_index = if word_list.categories[category_index % word_list.categories.len()].pairs.is_empty() {
+        category_indices_for_pack(None).first().copied().unwrap_or(0)
+    } else {
+        category_index % word_list.categories.len()
+    };
+    let category = &word_list.categories[category_index];
+    let pairs: Vec<(String, String)> = category.pairs.iter().map(|p| (p.first.clone(), p.second.clone())).collect();
+    let candidates = without_flagged(&pairs, pairs_for_difficulty(&category.pairs, difficulty), flagged);
+    let (mut cards, imposter_idx, pair_index) = deal_word_pair(player_count, &pairs, &candidates, category_index, recent_pairs, imposter_weights);
+    // Only a pair's own hint is given; the category name would be shown anyway
+    if let Some(card) = cards.get_mut(imposter_idx) {
+        card.hint = category.pairs[pair_index].hint.clone();
+    }
     
-    // Get SEPARATE random bytes for imposter selection (ensures true randomness)
-    let mut buf_imposter = [0u8; 8];
-    let _ = getrandom(&mut buf_imposter);
-    let random_imposter = u64::from_le_bytes(buf_imposter);
+    (cards, imposter_idx, category.name.clone(), category.icon.clone(), (category_index, pair_index))
+}
+
+/// Generate cards from the players' own word pairs
+pub fn generate_cards_for_custom_pairs(player_count: usize, pairs: &[(String, String)], recent_pairs: &[RecentPair], imposter_weights: &[u32], flagged: &FlaggedPairs, lang: Lang) -> (Vec<GameCard>, usize, String, String, RecentPair) {
+    let candidates = without_flagged(pairs, (0..pairs.len()).collect(), flagged);
+    let (cards, imposter_idx, pair_index) = deal_word_pair(player_count, pairs, &candidates, CUSTOM_PAIRS_KEY, recent_pairs, imposter_weights);
+    (cards, imposter_idx, ui_text(lang).custom_category.to_string(), "✍️".to_string(), (CUSTOM_PAIRS_KEY, pair_index))
+}
+
+/// Pick a random pair from the `candidates` positions of `pairs` and deal it: one imposter
+/// card, the rest civilians. `list_key` identifies the word list so repeated pairs
+/// can swap roles. The latest `recent_pairs` from this list are skipped (other lists'
+/// positions mean other pairs), but never so many that no candidate is left: at most
+/// one fewer than there are candidates.
+/// Tables below `MIN_PLAYERS` never reach a deal, but don't panic either: one player
+/// gets just the imposter card, and no players get no cards (with index 0).
+fn deal_word_pair(player_count: usize, pairs: &[(String, String)], candidates: &[usize], list_key: usize, recent_pairs: &[RecentPair], imposter_weights: &[u32]) -> (Vec<GameCard>, usize, usize) {
+    // Random value for word pair selection
+    let random_word = random_u64();
     
-    // Select random word pair from the chosen category
-    let pair_index = (random_word as usize) % category.pairs.len();
+    // Select random word pair from the candidates that haven't come up lately
+    let from_this_list: Vec<usize> = recent_pairs.iter().filter(|(key, _)| *key == list_key).map(|&(_, index)| index).collect();
+    let window = RECENT_PAIR_MEMORY.min(candidates.len().saturating_sub(1));
+    let avoided = &from_this_list[from_this_list.len().saturating_sub(window)..];
+    let fresh: Vec<usize> = candidates.iter().copied().filter(|i| !avoided.contains(i)).collect();
+    let pair_index = fresh[(random_word as usize) % fresh.len()];
 
     // Track orientation per pair so repeats swap roles each time
     static PAIR_ORIENTATION: Lazy<Mutex<HashMap<(usize, usize), bool>>> = Lazy::new(|| Mutex::new(HashMap::new()));
     let mut orientations = PAIR_ORIENTATION.lock().unwrap_or_else(|e| e.into_inner());
     let flip = orientations
-        .entry((category_index, pair_index))
+        .entry((list_key, pair_index))
         .or_insert_with(|| {
             // Initial orientation randomized
             (random_word & 1) == 1
@@ -64,13 +622,13 @@ pub fn generate_cards_for_category(player_count: usize, category_index: usize) -
     *flip = !*flip;
 
     let (normal_word, imposter_word) = if flip_val {
-        (&category.pairs[pair_index].0, &category.pairs[pair_index].1)
+        (&pairs[pair_index].0, &pairs[pair_index].1)
     } else {
-        (&category.pairs[pair_index].1, &category.pairs[pair_index].0)
+        (&pairs[pair_index].1, &pairs[pair_index].0)
     };
     
-    // Select random imposter index (using separate random value)
-    let imposter_idx = (random_imposter as usize) % player_count;
+    // Select the imposter with its own random draw
+    let imposter_idx = pick_imposter_seat(player_count, imposter_weights);
     
     let mut cards = Vec::new();
     for i in 0..player_count {
@@ -78,14 +636,805 @@ pub fn generate_cards_for_category(player_count: usize, category_index: usize) -
             cards.push(GameCard {
                 card_type: CardType::Imposter,
                 word: imposter_word.clone(),
+                role: None,
+                hint: None,
             });
         } else {
             cards.push(GameCard {
                 card_type: CardType::Normal,
                 word: normal_word.clone(),
+                role: None,
+                hint: None,
             });
         }
     }
     
-    (cards, imposter_idx, category.name.clone(), category.icon.clone())
+    (cards, imposter_idx, pair_index)
+}
+
+/// Fair-rotation weights: each seat weighs one more than the number of rounds it
+/// trails the most-picked player by, so whoever has been imposter least is the
+/// likeliest pick but nobody is ever ruled out (and nobody can be sure who it is).
+pub fn imposter_weights(times_imposter: &[i32], player_count: usize) -> Vec<u32> {
+    let count = |seat: usize| times_imposter.get(seat).copied().unwrap_or(0).max(0) as u32;
+    let most = (0..player_count).map(count).max().unwrap_or(0);
+    (0..player_count).map(|seat| most - count(seat) + 1).collect()
+}
+
+/// Fair-rotation counts for an edited roster: `seats` gives each new seat's old seat,
+/// or None for a newcomer, who starts from zero
+pub fn reseat_imposter_counts(times_imposter: &[i32], seats: &[Option<usize>]) -> Vec<i32> {
+    seats.iter()
+        .map(|seat| seat.and_then(|old| times_imposter.get(old)).copied().unwrap_or(0))
+        .collect()
+}
+
+/// Random imposter seat, drawn in proportion to `weights` (one per seat);
+/// empty or mismatched weights mean every seat is equally likely
+pub fn pick_imposter_seat(player_count: usize, weights: &[u32]) -> usize {
+    seat_for_roll(random_u64(), player_count, weights)
+}
+
+/// The seat a given random `roll` lands on, in proportion to `weights` as in
+/// `pick_imposter_seat`; the same roll always gives the same seat. An empty table gives 0.
+pub fn seat_for_roll(roll: u64, player_count: usize, weights: &[u32]) -> usize {
+    let total: u64 = weights.iter().map(|&w| w as u64).sum();
+    if player_count == 0 {
+        return 0;
+    }
+    if weights.len() != player_count || total == 0 {
+        return (roll % player_count as u64) as usize;
+    }
+    let mut roll = roll % total;
+    for (seat, &weight) in weights.iter().enumerate() {
+        if roll < weight as u64 {
+            return seat;
+        }
+        roll -= weight as u64;
+    }
+    player_count - 1
+}
+
+/// Hex digits of a commitment shown on screen; the whole hash is kept for the check
+pub const COMMITMENT_CODE_LEN: usize = 16;
+
+/// Commitment to a blind deal's seed: the SHA-256 of the seed written in decimal, in hex.
+/// Its start is shown before the first reveal and the seed at round end, so anyone can
+/// check the deal wasn't changed in between (`printf %s <seed> | sha256sum`).
+pub fn seed_commitment(seed: u64) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(seed.to_string().as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The part of a commitment shown to the table
+pub fn commitment_code(commitment: &str) -> &str {
+    commitment.get(..COMMITMENT_CODE_LEN).unwrap_or(commitment)
+}
+
+/// Apply the imposter-card setting to a freshly dealt word-pair round.
+/// No-word games blank the imposter's word; category-hint games blank it but keep
+/// the hint, or fall back to a blank card when the pair has none (custom pairs and
+/// built-in pairs written without one).
+pub fn apply_imposter_mode(cards: &mut [GameCard], mode: ImposterMode) {
+    for card in cards.iter_mut().filter(|c| c.card_type == CardType::Imposter) {
+        match mode {
+            ImposterMode::SimilarWord => card.hint = None,
+            ImposterMode::NoWord => {
+                card.word.clear();
+                card.hint = None;
+            }
+            ImposterMode::CategoryHint => card.word.clear(),
+        }
+    }
+}
+
+/// Twist rounds: give the imposter a word from a random pair in `pool` sharing no word
+/// with the ones dealt, so it has nothing to do with the civilians' word.
+/// Returns false, leaving the cards as dealt, when no pair in the pool qualifies.
+pub fn twist_imposter_word(cards: &mut [GameCard], pool: &[(String, String)]) -> bool {
+    let dealt: Vec<String> = cards.iter().map(|c| c.word.clone()).collect();
+    // A pair with one dealt word would hand the imposter the other half of a close match
+    let unrelated = |word: &str| pick_distinct_words(&[word.to_string()], &dealt, 1).len() == 1;
+    let words: Vec<String> = pool.iter()
+        .filter(|(first, second)| unrelated(first) && unrelated(second))
+        .flat_map(|(first, second)| [first.clone(), second.clone()])
+        .collect();
+    let Some(card) = cards.iter_mut().find(|c| c.card_type == CardType::Imposter) else {
+        return false;
+    };
+    let Some(word) = pick_distinct_words(&words, &dealt, 1).pop() else {
+        return false;
+    };
+    card.word = word;
+    true
+}
+
+/// Every built-in word pair, across all categories, as (first, second)
+pub fn all_builtin_pairs() -> Vec<(String, String)> {
+    load_word_categories().categories.into_iter()
+        .flat_map(|category| category.pairs)
+        .map(|pair| (pair.first, pair.second))
+        .collect()
+}
+
+/// A custom pairs line that couldn't be read
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CustomPairError {
+    NotAPair { line: usize }, // Anything but two non-empty words separated by one comma; 1-based
+}
+
+impl CustomPairError {
+    pub fn message(self, lang: Lang) -> String {
+        match self {
+            CustomPairError::NotAPair { line } => (ui_text(lang).custom_pair_line_error)(line),
+        }
+    }
+}
+
+/// Parse custom word pairs, one `word, word` pair per line.
+/// Blank lines are skipped; malformed lines are reported without discarding the valid ones.
+pub fn parse_custom_pairs(text: &str) -> (Vec<(String, String)>, Vec<CustomPairError>) {
+    let mut pairs = Vec::new();
+    let mut errors = Vec::new();
+    for (line_number, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let tokens: Vec<&str> = line.split(',').map(str::trim).collect();
+        match tokens.as_slice() {
+            [first, second] if !first.is_empty() && !second.is_empty() => {
+                pairs.push((first.to_string(), second.to_string()));
+            }
+            _ => errors.push(CustomPairError::NotAPair { line: line_number + 1 }),
+        }
+    }
+    (pairs, errors)
+}
+
+/// Load location scenarios from YAML
+fn load_scenarios() -> ScenarioList {
+    serde_yaml::from_str(SCENARIOS_YAML).expect("Failed to parse scenarios.yaml")
+}
+
+/// Deal a Spyfall-style location round: every civilian gets the location and a
+/// role, the imposter (spy) gets an empty card. Returns the location name and icon.
+pub fn generate_cards_for_scenario(player_count: usize, imposter_weights: &[u32]) -> (Vec<GameCard>, usize, String, String) {
+    let scenario_list = load_scenarios();
+    let scenario = &scenario_list.scenarios[get_random_starting_index(scenario_list.scenarios.len())];
+    let imposter_idx = pick_imposter_seat(player_count, imposter_weights);
+
+    // Hand out roles in random order, cycling if there are more players than roles
+    let mut roles = scenario.roles.clone();
+    shuffle(&mut roles);
+
+    let cards = (0..player_count)
+        .map(|i| {
+            if i == imposter_idx {
+                GameCard {
+                    card_type: CardType::Imposter,
+                    word: String::new(),
+                    role: None,
+                    hint: None,
+                }
+            } else {
+                GameCard {
+                    card_type: CardType::Normal,
+                    word: scenario.location.clone(),
+                    role: roles.get(i % roles.len().max(1)).cloned(),
+                    hint: None,
+                }
+            }
+        })
+        .collect();
+
+    (cards, imposter_idx, scenario.location.clone(), scenario.icon.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::game::types::{named_players, player, players};
+
+    fn custom(indices: &[usize]) -> Vec<RecentPair> {
+        indices.iter().map(|&index| (CUSTOM_PAIRS_KEY, index)).collect()
+    }
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn picks_requested_number_of_distinct_words() {
+        let pool = words(&["Cat", "Dog", "Horse", "Cow", "Sheep"]);
+        let picked = pick_distinct_words(&pool, &[], 3);
+        assert_eq!(picked.len(), 3);
+        for word in &picked {
+            assert!(pool.contains(word));
+            assert_eq!(picked.iter().filter(|w| *w == word).count(), 1);
+        }
+    }
+
+    #[test]
+    fn a_seeded_thread_repeats_its_draws() {
+        seed_random(Some(42));
+        let first: Vec<u64> = (0..5).map(|_| random_u64()).collect();
+        seed_random(Some(42));
+        let again: Vec<u64> = (0..5).map(|_| random_u64()).collect();
+        seed_random(None);
+        assert_eq!(first, again);
+        assert!(first.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn fair_rotation_favours_whoever_has_been_imposter_least() {
+        assert_eq!(imposter_weights(&[3, 3, 0], 3), vec![1, 1, 4]);
+        // Seats added since the counts were kept start from zero
+        assert_eq!(imposter_weights(&[1], 3), vec![1, 2, 2]);
+        assert_eq!(imposter_weights(&[], 3), vec![1, 1, 1]);
+
+        // Only one seat with any weight can only ever be picked
+        for _ in 0..50 {
+            assert_eq!(pick_imposter_seat(3, &[0, 5, 0]), 1);
+        }
+        let picks = (0..400).filter(|_| pick_imposter_seat(3, &[1, 1, 4]) == 2).count();
+        assert!(picks > 200, "least-picked seat chosen only {picks} times of 400");
+        assert!(pick_imposter_seat(3, &[]) < 3);
+    }
+
+    #[test]
+    fn the_deciding_eviction_comes_last() {
+        let mut players = players(5);
+        for seat in [0, 3, 4] {
+            players[seat].is_eliminated = true;
+        }
+        assert_eq!(eviction_order(&players, &[4, 0]), vec![4, 0, 3]);
+        assert_eq!(eviction_order(&players[..3], &[]), vec![0]);
+    }
+
+    #[test]
+    fn shrinking_the_count_keeps_the_later_names() {
+        let typed = words(&["Ana", "Ben", "Cy", "Dee", "Eve"]);
+        assert_eq!(seat_names(&typed, 3), words(&["Ana", "Ben", "Cy"]));
+        assert_eq!(seat_names(&typed, 5), typed);
+        assert_eq!(seat_names(&typed, 6), words(&["Ana", "Ben", "Cy", "Dee", "Eve", ""]));
+    }
+
+    #[test]
+    fn only_supported_counts_are_valid_player_counts() {
+        assert_eq!(read_player_count("5"), PlayerCountEntry::Valid(5));
+        assert_eq!(read_player_count(" 12 "), PlayerCountEntry::Valid(12));
+        // On the way to typing 12, the 1 is just out of range - not a reason to drop names
+        assert_eq!(read_player_count("1"), PlayerCountEntry::Clamped(MIN_PLAYERS));
+        assert_eq!(read_player_count("99"), PlayerCountEntry::Clamped(MAX_PLAYERS));
+        assert_eq!(read_player_count(""), PlayerCountEntry::Invalid);
+        assert_eq!(read_player_count("abc"), PlayerCountEntry::Invalid);
+    }
+
+    #[test]
+    fn display_aids_stack_on_the_root_class() {
+        assert_eq!(root_class(Theme::Light, A11yConfig::default(), DisplayMode::Phone), "game-container");
+        let both = A11yConfig { large_text: true, high_contrast: true };
+        assert_eq!(root_class(Theme::Dark, both, DisplayMode::Phone), "game-container theme-dark large-text high-contrast");
+        assert_eq!(root_class(Theme::Light, A11yConfig::default(), DisplayMode::Tv), "game-container tv-mode");
+    }
+
+    #[test]
+    fn undersized_pool_returns_what_it_can() {
+        let pool = words(&["Coffee", "Tea"]);
+        let picked = pick_distinct_words(&pool, &words(&["Coffee"]), 5);
+        assert_eq!(picked, words(&["Tea"]));
+    }
+
+    #[test]
+    fn duplicates_and_exclusions_are_case_insensitive() {
+        let pool = words(&["Sea", "sea ", "OCEAN", "Lake", ""]);
+        let mut picked = pick_distinct_words(&pool, &words(&["ocean"]), 10);
+        picked.sort();
+        assert_eq!(picked, words(&["Lake", "Sea"]));
+    }
+
+    #[test]
+    fn location_round_gives_spy_nothing_and_civilians_roles() {
+        for player_count in [3, 8, 12] {
+            let (cards, imposter_idx, location, _) = generate_cards_for_scenario(player_count, &[]);
+            assert_eq!(cards.len(), player_count);
+            assert!(imposter_idx < player_count);
+            for (i, card) in cards.iter().enumerate() {
+                if i == imposter_idx {
+                    assert_eq!(card.card_type, CardType::Imposter);
+                    assert!(card.word.is_empty() && card.role.is_none());
+                } else {
+                    assert_eq!(card.word, location);
+                    assert!(card.role.is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn word_packs_filter_categories_and_fall_back_to_mixed() {
+        let categories = get_all_categories();
+        let all = category_indices_for_pack(None);
+        assert_eq!(all.len(), categories.len());
+
+        let food = category_indices_for_pack(Some("Food"));
+        assert!(!food.is_empty() && food.len() < all.len());
+        assert!(food.iter().all(|&i| categories[i].name == "Food & Drinks"));
+
+        assert_eq!(category_indices_for_pack(Some("No Such Pack")), all);
+    }
+
+    #[test]
+    fn custom_pairs_parse_valid_lines_and_report_bad_ones() {
+        let text = "Coffee, Tea\n\n  Cat ,Dog  \nJustOneWord\nA, B, C\n, Empty\n";
+        let (pairs, errors) = parse_custom_pairs(text);
+        assert_eq!(
+            pairs,
+            vec![
+                ("Coffee".to_string(), "Tea".to_string()),
+                ("Cat".to_string(), "Dog".to_string()),
+            ]
+        );
+        let lines: Vec<usize> = errors.iter().map(|&CustomPairError::NotAPair { line }| line).collect();
+        assert_eq!(lines, vec![4, 5, 6]);
+        assert!(errors[0].message(Lang::English).starts_with("Line 4"));
+        assert!(errors[0].message(Lang::Spanish).starts_with("Línea 4"));
+    }
+
+    #[test]
+    fn announcements_follow_the_screen() {
+        let mut players = players(4);
+        players[3].is_eliminated = true;
+
+        assert_eq!(screen_announcement(&GameScreen::Voting, &players, 0, &[], Lang::English), "Voting. 3 players remaining.");
+        assert_eq!(
+            screen_announcement(&GameScreen::CardView { current_player_index: 1 }, &players, 3, &[], Lang::English),
+            "Cards. Pass the device to P0."
+        );
+        assert_eq!(
+            screen_announcement(&GameScreen::Elimination { eliminated_index: 2, was_imposter: true }, &players, 0, &[], Lang::English),
+            "P2 was evicted."
+        );
+    }
+
+    #[test]
+    fn win_threshold_stays_winnable() {
+        let mut settings = Settings::default();
+        assert_eq!(imposter_win_threshold(&settings, 6), 2);
+
+        settings.imposter_win_threshold = 3;
+        assert_eq!(imposter_win_threshold(&settings, 6), 3);
+        assert_eq!(imposter_win_threshold(&settings, 3), 2);
+
+        settings.imposter_win_threshold = 0;
+        assert_eq!(imposter_win_threshold(&settings, 6), 2);
+        settings.imposter_win_threshold = 9;
+        assert_eq!(imposter_win_threshold(&settings, 6), 4);
+        // One below the count would make the first eviction the last vote
+        settings.imposter_win_threshold = 5;
+        assert_eq!(imposter_win_threshold(&settings, 6), 4);
+
+        // A full table still defaults to playing down to two, and any pick leaves a vote to win
+        assert_eq!(imposter_win_threshold(&Settings::default(), MAX_PLAYERS), 2);
+        settings.imposter_win_threshold = 50;
+        assert_eq!(imposter_win_threshold(&settings, MAX_PLAYERS), MAX_PLAYERS - 2);
+    }
+
+    #[test]
+    fn a_full_table_gets_distinct_colors_and_one_imposter() {
+        let colors: std::collections::HashSet<String> = (0..MAX_PLAYERS).map(player_color).collect();
+        assert_eq!(colors.len(), MAX_PLAYERS);
+
+        let (cards, imposter, _, _, _) = generate_cards_for_category(MAX_PLAYERS, 0, None, &[], &[], &FlaggedPairs::new());
+        assert_eq!(cards.len(), MAX_PLAYERS);
+        assert_eq!(cards.iter().filter(|c| c.card_type == CardType::Imposter).count(), 1);
+        assert_eq!(cards[imposter].card_type, CardType::Imposter);
+
+        let (cards, _, _, _) = generate_cards_for_scenario(MAX_PLAYERS, &[]);
+        assert!(cards.iter().filter(|c| c.card_type == CardType::Normal).all(|c| c.role.is_some()));
+    }
+
+    #[test]
+    fn every_deal_is_one_imposter_among_civilians_sharing_a_word() {
+        let pairs = vec![("Sun".to_string(), "Moon".to_string()), ("Cat".to_string(), "Dog".to_string())];
+        for player_count in MIN_PLAYERS..=MAX_PLAYERS {
+            for _ in 0..10 {
+                let (category_cards, category_imposter, ..) = generate_cards_for_category(player_count, 0, None, &[], &[], &FlaggedPairs::new());
+                let (custom_cards, custom_imposter, ..) = generate_cards_for_custom_pairs(player_count, &pairs, &[], &[], &FlaggedPairs::new(), Lang::English);
+                for (cards, imposter) in [(category_cards, category_imposter), (custom_cards, custom_imposter)] {
+                    assert_eq!(cards.len(), player_count);
+                    assert!(imposter < player_count);
+                    assert_eq!(cards.iter().filter(|c| c.card_type == CardType::Imposter).count(), 1);
+                    assert_eq!(cards[imposter].card_type, CardType::Imposter);
+                    let civilian_word = &cards[(imposter + 1) % player_count].word;
+                    assert!(cards.iter().enumerate().all(|(i, c)| i == imposter || &c.word == civilian_word));
+                    assert_ne!(&cards[imposter].word, civilian_word);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_seat_is_about_as_likely_to_be_the_imposter() {
+        // A randomness source stuck on one value would put every imposter on the same seat
+        const DEALS: usize = 4000;
+        let pairs = vec![("Sun".to_string(), "Moon".to_string())];
+        let mut per_seat = [0usize; 4];
+        for _ in 0..DEALS {
+            let (_, imposter, ..) = generate_cards_for_custom_pairs(4, &pairs, &[], &[], &FlaggedPairs::new(), Lang::English);
+            per_seat[imposter] += 1;
+        }
+        // 1000 expected per seat, with a standard deviation of about 27
+        assert!(per_seat.iter().all(|&n| (850..=1150).contains(&n)), "uneven imposter seats: {per_seat:?}");
+    }
+
+    #[test]
+    fn tables_too_small_to_play_still_deal_safely() {
+        let pairs = vec![("Sun".to_string(), "Moon".to_string())];
+        let (cards, imposter, ..) = generate_cards_for_custom_pairs(1, &pairs, &[], &[], &FlaggedPairs::new(), Lang::English);
+        assert_eq!((cards.len(), imposter), (1, 0));
+        assert_eq!(cards[0].card_type, CardType::Imposter);
+        let (cards, imposter, ..) = generate_cards_for_custom_pairs(0, &pairs, &[], &[], &FlaggedPairs::new(), Lang::English);
+        assert!(cards.is_empty());
+        assert_eq!(imposter, 0);
+        assert_eq!(seat_for_roll(u64::MAX, 0, &[]), 0);
+        let (cards, _, _, _) = generate_cards_for_scenario(1, &[]);
+        assert_eq!(cards.len(), 1);
+    }
+
+    #[test]
+    fn names_are_normalized_before_use() {
+        assert_eq!(normalize_name(" \u{3000}Alex "), "Alex");
+        assert_eq!(normalize_name("Ana \u{3000}\t Maria"), "Ana Maria");
+        assert_eq!(normalize_name("🎭"), "🎭");
+        assert_eq!(normalize_name("👨\u{200D}⚕️ Sam"), "👨\u{200D}⚕️ Sam");
+        assert_eq!(normalize_name("\u{200B}\u{3000}\u{FEFF}"), "");
+        assert_eq!(normalize_name("Al\u{7}ex"), "Alex");
+
+        let names = vec![" \u{3000}Alex ".to_string(), "alex".to_string(), "🎭".to_string()];
+        assert_eq!(duplicate_name_indices(&names), vec![0, 1]);
+        assert_eq!(with_unique_suffixes(&names), vec!["Alex", "alex 2", "🎭"]);
+    }
+
+    #[test]
+    fn colors_stay_unique_when_players_join() {
+        let mut players = players(3);
+        players.remove(1);
+        let newcomer = next_free_color(&players);
+        assert_eq!(newcomer, PLAYER_COLORS[1]);
+
+        players.push(player("New"));
+        assign_missing_colors(&mut players);
+        assert_eq!(players[2].color, PLAYER_COLORS[1]);
+    }
+
+    #[test]
+    fn speed_bonus_shrinks_each_vote() {
+        assert_eq!(speed_bonus(1), 12);
+        assert_eq!(speed_bonus(2), 9);
+        assert_eq!(speed_bonus(4), 3);
+        assert_eq!(speed_bonus(5), 0);
+        assert_eq!(speed_bonus(9), 0);
+
+        let mut settings = Settings::default();
+        assert_eq!(round_speed_bonus(&settings, 0), 12);
+        settings.speed_bonus = false;
+        assert_eq!(round_speed_bonus(&settings, 0), 0);
+    }
+
+    #[test]
+    fn duplicate_names_are_found_case_insensitively() {
+        let names: Vec<String> = ["Alex", "Sam", " alex ", "", ""].iter().map(|s| s.to_string()).collect();
+        assert_eq!(duplicate_name_indices(&names), vec![0, 2]);
+    }
+
+    #[test]
+    fn renames_follow_the_setup_name_rules() {
+        let players = named_players(&["Alex", "Dana"]);
+        assert_eq!(valid_rename(&players, 0, "  Alexa "), Some("Alexa".to_string()));
+        // Fixing only the case of your own name is fine, taking someone else's isn't
+        assert_eq!(valid_rename(&players, 0, "alex"), Some("alex".to_string()));
+        assert_eq!(valid_rename(&players, 0, "DANA"), None);
+        assert_eq!(valid_rename(&players, 0, " \u{200B} "), None);
+        assert_eq!(valid_rename(&players, 5, "Sam"), None);
+    }
+
+    #[test]
+    fn duplicate_names_get_numbered() {
+        let names: Vec<String> = ["Alex", "alex", "Sam", "Alex 2", "Alex"].iter().map(|s| s.to_string()).collect();
+        let unique = with_unique_suffixes(&names);
+        // "Alex 2" was typed, so the repeats number around it
+        assert_eq!(unique, vec!["Alex", "alex 3", "Sam", "Alex 2", "Alex 4"]);
+        assert!(duplicate_name_indices(&unique).is_empty());
+
+        let names: Vec<String> = ["Alex", "Alex 2", "Alex"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(with_unique_suffixes(&names), vec!["Alex", "Alex 2", "Alex 3"]);
+    }
+
+    #[test]
+    fn vote_leaders_finds_single_winner_and_ties() {
+        assert_eq!(tally_votes(&[2, 0, 2, 1]), vec![(2, 2), (0, 1), (1, 1)]);
+        assert_eq!(vote_leaders(&tally_votes(&[2, 0, 2, 1])), vec![2]);
+        assert_eq!(vote_leaders(&tally_votes(&[3, 1, 1, 3])), vec![1, 3]);
+        assert_eq!(vote_leaders(&tally_votes(&[0, 1, 2])), vec![0, 1, 2]);
+        assert!(tally_votes(&[]).is_empty() && vote_leaders(&[]).is_empty());
+    }
+
+    #[test]
+    fn ties_stop_being_revoted_after_the_limit() {
+        let settings = Settings::default();
+        assert_eq!(settings.tie_break, TieBreakMode::Revote);
+        for revotes in 0..MAX_REVOTES {
+            assert_eq!(tie_break_mode(&settings, revotes), TieBreakMode::Revote);
+        }
+        assert_eq!(tie_break_mode(&settings, MAX_REVOTES), TieBreakMode::NoElimination);
+
+        let no_elimination = Settings { tie_break: TieBreakMode::NoElimination, ..Settings::default() };
+        assert_eq!(tie_break_mode(&no_elimination, 0), TieBreakMode::NoElimination);
+    }
+
+    #[test]
+    fn active_player_order_skips_eliminated_and_rotates() {
+        let mut players = players(4);
+        players[1].is_eliminated = true;
+        assert_eq!(active_player_order(&players, 0, &[]), vec![0, 2, 3]);
+        assert_eq!(active_player_order(&players, 2, &[]), vec![3, 0, 2]);
+    }
+
+    #[test]
+    fn card_progress_counts_only_real_reveals() {
+        // Third in line with nobody skipped: two have looked
+        assert_eq!(cards_seen(2, 6, 0, false), 2);
+        // One skipped earlier: they haven't looked yet
+        assert_eq!(cards_seen(4, 6, 1, false), 3);
+        // Two stragglers left, one of them back now
+        assert_eq!(cards_seen(1, 6, 2, true), 4);
+        assert_eq!(cards_seen(6, 6, 0, false), 6);
+    }
+
+    #[test]
+    fn shuffled_turn_order_maps_back_to_seats() {
+        let mut players = players(4);
+        let settings = Settings { shuffle_pass_order: true, ..Settings::default() };
+        let order = new_turn_order(4, &settings);
+        let mut seats = order.clone();
+        seats.sort_unstable();
+        assert_eq!(seats, vec![0, 1, 2, 3]);
+        assert!(new_turn_order(4, &Settings::default()).is_empty());
+
+        // Every position in the pass order points at a real seat, so cards[seat] is that player's card
+        let turn_order = vec![2, 0, 3, 1];
+        assert_eq!(pass_order(4, 1, &turn_order), turn_order);
+        players[3].is_eliminated = true;
+        assert_eq!(active_player_order(&players, 1, &turn_order), vec![2, 0, 1]);
+
+        // An order left over from a different roster falls back to seating order
+        assert_eq!(pass_order(3, 1, &turn_order), vec![1, 2, 0]);
+        assert_eq!(pass_order(4, 1, &[]), vec![1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn fallback_randomness_is_not_stuck_at_zero() {
+        let draws: Vec<u64> = (0..64).map(|_| fallback_random_u64()).collect();
+        assert!(draws.windows(2).all(|w| w[0] != w[1]));
+        // Seats drawn from the fallback still land on more than one player
+        let seats: std::collections::HashSet<u64> = draws.iter().map(|d| d % 4).collect();
+        assert!(seats.len() > 1);
+    }
+
+    #[test]
+    fn skips_run_out_after_the_cap() {
+        let settings = Settings::default();
+        assert_eq!(skips_remaining(&settings, 0), 2);
+        assert_eq!(skips_remaining(&settings, 2), 0);
+        assert_eq!(skips_remaining(&settings, 5), 0);
+        let strict = Settings { max_consecutive_skips: 0, ..Settings::default() };
+        assert_eq!(skips_remaining(&strict, 0), 0);
+    }
+
+    #[test]
+    fn timer_formats_as_minutes_and_seconds() {
+        assert_eq!(format_mm_ss(0), "00:00");
+        assert_eq!(format_mm_ss(59), "00:59");
+        assert_eq!(format_mm_ss(120), "02:00");
+        assert_eq!(format_mm_ss(185), "03:05");
+    }
+
+    #[test]
+    fn no_word_mode_blanks_only_the_imposter() {
+        let pairs = vec![("Sun".to_string(), "Moon".to_string())];
+        let (mut cards, imposter_idx, _, _, _) = generate_cards_for_custom_pairs(4, &pairs, &[], &[], &FlaggedPairs::new(), Lang::English);
+        apply_imposter_mode(&mut cards, ImposterMode::NoWord);
+        for (i, card) in cards.iter().enumerate() {
+            assert_eq!(card.word.is_empty(), i == imposter_idx);
+        }
+    }
+
+    #[test]
+    fn category_hint_mode_tells_only_the_imposter() {
+        let categories = get_all_categories();
+        let (mut cards, imposter_idx, _, _, (category_index, pair_index)) = generate_cards_for_category(4, 0, None, &[], &[], &FlaggedPairs::new());
+        apply_imposter_mode(&mut cards, ImposterMode::CategoryHint);
+        for (i, card) in cards.iter().enumerate() {
+            if i == imposter_idx {
+                assert!(card.word.is_empty());
+                assert_eq!(card.hint, categories[category_index].pairs[pair_index].hint);
+            } else {
+                assert!(!card.word.is_empty());
+                assert_eq!(card.hint, None);
+            }
+        }
+
+        // A built-in pair without a hint gets a blank card, not the category name
+        let unhinted = categories.iter().position(|c| !c.pairs.is_empty() && c.pairs.iter().all(|p| p.hint.is_none())).unwrap();
+        let (mut cards, imposter_idx, _, _, _) = generate_cards_for_category(4, unhinted, None, &[], &[], &FlaggedPairs::new());
+        apply_imposter_mode(&mut cards, ImposterMode::CategoryHint);
+        assert!(cards[imposter_idx].word.is_empty());
+        assert_eq!(cards[imposter_idx].hint, None);
+
+        // Custom pairs have no hint, so the imposter just gets a blank card
+        let pairs = vec![("Sun".to_string(), "Moon".to_string())];
+        let (mut cards, imposter_idx, _, _, _) = generate_cards_for_custom_pairs(4, &pairs, &[], &[], &FlaggedPairs::new(), Lang::English);
+        apply_imposter_mode(&mut cards, ImposterMode::CategoryHint);
+        assert!(cards[imposter_idx].word.is_empty());
+        assert_eq!(cards[imposter_idx].hint, None);
+    }
+
+    #[test]
+    fn word_pairs_accept_an_optional_hint() {
+        let yaml = r#"[["Coffee", "Espresso", "Hot drink"], ["Cat", "Lynx"]]"#;
+        let pairs: Vec<WordPair> = serde_yaml::from_str(yaml).expect("pairs should parse");
+        assert_eq!(pairs[0].hint.as_deref(), Some("Hot drink"));
+        assert_eq!((pairs[1].first.as_str(), pairs[1].second.as_str(), pairs[1].hint.as_deref()), ("Cat", "Lynx", None));
+        assert!(serde_yaml::from_str::<Vec<WordPair>>(r#"[["Coffee"]]"#).is_err());
+    }
+
+    #[test]
+    fn word_pairs_can_be_tagged_with_a_difficulty() {
+        let yaml = r#"[["Cat", "Dog"], { words: ["Sea", "Ocean", "Water"], difficulty: hard }, { words: ["Sun", "Rock"], difficulty: easy }]"#;
+        let pairs: Vec<WordPair> = serde_yaml::from_str(yaml).expect("pairs should parse");
+        assert_eq!(pairs[0].difficulty, Difficulty::Medium);
+        assert_eq!((pairs[1].second.as_str(), pairs[1].hint.as_deref(), pairs[1].difficulty), ("Ocean", Some("Water"), Difficulty::Hard));
+        assert!(serde_yaml::from_str::<Vec<WordPair>>(r#"[{ words: ["Sea"], difficulty: hard }]"#).is_err());
+
+        assert_eq!(pairs_for_difficulty(&pairs, Some(Difficulty::Hard)), vec![1]);
+        assert_eq!(pairs_for_difficulty(&pairs, None), vec![0, 1, 2]);
+        // A tier the category doesn't have falls back to every pair
+        assert_eq!(pairs_for_difficulty(&pairs[..1], Some(Difficulty::Easy)), vec![0]);
+    }
+
+    #[test]
+    fn every_category_deals_from_each_tier() {
+        let categories = get_all_categories();
+        for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+            for (index, category) in categories.iter().enumerate() {
+                let (.., (_, pair_index)) = generate_cards_for_category(4, index, Some(difficulty), &[], &[], &FlaggedPairs::new());
+                let tier = pairs_for_difficulty(&category.pairs, Some(difficulty));
+                assert!(tier.contains(&pair_index), "{} dealt outside {difficulty:?}", category.name);
+            }
+        }
+    }
+
+    #[test]
+    fn last_pair_is_not_repeated() {
+        let pairs = vec![
+            ("Sun".to_string(), "Moon".to_string()),
+            ("Cat".to_string(), "Dog".to_string()),
+        ];
+        for _ in 0..50 {
+            let (.., (_, pair_index)) = generate_cards_for_custom_pairs(3, &pairs, &custom(&[0]), &[], &FlaggedPairs::new(), Lang::English);
+            assert_eq!(pair_index, 1);
+        }
+    }
+
+    #[test]
+    fn recent_pairs_are_skipped_while_others_are_left() {
+        let pairs: Vec<(String, String)> = (0..4).map(|i| (format!("A{i}"), format!("B{i}"))).collect();
+        for _ in 0..50 {
+            let (.., (_, pair_index)) = generate_cards_for_custom_pairs(3, &pairs, &custom(&[0, 2, 1]), &[], &FlaggedPairs::new(), Lang::English);
+            assert_eq!(pair_index, 3);
+        }
+        // Only the latest three of four can be held back, so the oldest comes round again
+        for _ in 0..50 {
+            let (.., (_, pair_index)) = generate_cards_for_custom_pairs(3, &pairs, &custom(&[3, 0, 2, 1]), &[], &FlaggedPairs::new(), Lang::English);
+            assert_eq!(pair_index, 3);
+        }
+
+        let mut recent = Vec::new();
+        for pair_index in 0..RECENT_PAIR_MEMORY + 2 {
+            remember_pair(&mut recent, (CUSTOM_PAIRS_KEY, pair_index));
+        }
+        assert_eq!(recent, custom(&(2..RECENT_PAIR_MEMORY + 2).collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn imposter_counts_follow_their_players_when_seat_0_leaves() {
+        // Seats 1 and 2 move up to 0 and 1, and a newcomer takes seat 2
+        let counts = reseat_imposter_counts(&[3, 0, 1], &[Some(1), Some(2), None]);
+        assert_eq!(counts, vec![0, 1, 0]);
+        // A game that never kept counts still gives every seat one
+        assert_eq!(reseat_imposter_counts(&[], &[Some(0), None]), vec![0, 0]);
+    }
+
+    #[test]
+    fn recent_pairs_only_count_against_their_own_list() {
+        let pairs: Vec<(String, String)> = (0..2).map(|i| (format!("A{i}"), format!("B{i}"))).collect();
+        // Pair 0 of a category is a different pair altogether
+        let mut dealt_zero = false;
+        for _ in 0..50 {
+            let (.., (_, pair_index)) = generate_cards_for_custom_pairs(3, &pairs, &[(0, 0)], &[], &FlaggedPairs::new(), Lang::English);
+            dealt_zero |= pair_index == 0;
+        }
+        assert!(dealt_zero, "a category's pair held back a custom pair");
+
+        // Switching category between deals: the last pair of the first one still isn't repeated
+        let categories = category_indices_for_pack(None);
+        let (first, second) = (categories[0], categories[1]);
+        let mut recent = Vec::new();
+        for _ in 0..20 {
+            let (.., last) = generate_cards_for_category(3, first, None, &recent, &[], &FlaggedPairs::new());
+            remember_pair(&mut recent, last);
+            let (.., other) = generate_cards_for_category(3, second, None, &recent, &[], &FlaggedPairs::new());
+            assert_eq!(other.0, second);
+            remember_pair(&mut recent, other);
+            let (.., again) = generate_cards_for_category(3, first, None, &recent, &[], &FlaggedPairs::new());
+            assert_ne!(again, last, "the first category's last pair came straight back");
+            remember_pair(&mut recent, again);
+        }
+    }
+
+    #[test]
+    fn flagged_pairs_are_skipped_unless_all_are_flagged() {
+        let pairs = vec![
+            ("Sun".to_string(), "Moon".to_string()),
+            ("Cat".to_string(), "Dog".to_string()),
+        ];
+        let flagged = FlaggedPairs::from([pair_key("moon", "sun")]);
+        for _ in 0..50 {
+            let (.., (_, pair_index)) = generate_cards_for_custom_pairs(3, &pairs, &[], &[], &flagged, Lang::English);
+            assert_eq!(pair_index, 1);
+        }
+        // Flagging everything still leaves something to deal
+        let everything = FlaggedPairs::from([pair_key("Sun", "Moon"), pair_key("Cat", "Dog")]);
+        assert_eq!(without_flagged(&pairs, vec![0, 1], &everything), vec![0, 1]);
+        let (cards, ..) = generate_cards_for_custom_pairs(3, &pairs, &[], &[], &everything, Lang::English);
+        assert_eq!(cards.len(), 3);
+    }
+
+    #[test]
+    fn single_pair_repeats_instead_of_looping() {
+        let pairs = vec![("Sun".to_string(), "Moon".to_string())];
+        let (.., (_, pair_index)) = generate_cards_for_custom_pairs(3, &pairs, &custom(&[0]), &[], &FlaggedPairs::new(), Lang::English);
+        assert_eq!(pair_index, 0);
+    }
+
+    #[test]
+    fn custom_pairs_are_dealt() {
+        let pairs = vec![("Sun".to_string(), "Moon".to_string())];
+        let (cards, imposter_idx, name, _, _) = generate_cards_for_custom_pairs(4, &pairs, &[], &[], &FlaggedPairs::new(), Lang::English);
+        assert_eq!(name, "Custom Words");
+        let (.., spanish_name, _, _) = generate_cards_for_custom_pairs(4, &pairs, &[], &[], &FlaggedPairs::new(), Lang::Spanish);
+        assert_eq!(spanish_name, "Palabras propias");
+        assert_eq!(cards.len(), 4);
+        let imposter_word = &cards[imposter_idx].word;
+        assert!(imposter_word == "Sun" || imposter_word == "Moon");
+        assert!(cards.iter().enumerate().all(|(i, c)| i == imposter_idx || &c.word != imposter_word));
+    }
+
+    #[test]
+    fn empty_or_fully_excluded_pool_yields_nothing() {
+        assert!(pick_distinct_words(&[], &[], 3).is_empty());
+        let pool = words(&["Sun", "Moon"]);
+        assert!(pick_distinct_words(&pool, &pool, 2).is_empty());
+        assert!(pick_distinct_words(&pool, &[], 0).is_empty());
+    }
+
+    #[test]
+    fn seed_commitments_are_the_sha256_of_the_decimal_seed() {
+        let commitment = seed_commitment(42);
+        // printf %s 42 | sha256sum
+        assert_eq!(commitment, "73475cb40a568e8da8a045ced110137e159f890ac4da883b6b17dc651b3a8049");
+        assert_eq!(commitment_code(&commitment), "73475cb40a568e8d");
+        assert_ne!(seed_commitment(43), commitment);
+    }
 }
@@ -1,4 +1,4 @@
 //! The views module contains the components for all Routes for our app.
 
-mod game;
+pub mod game;
 pub use game::Game;